@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    state::{mint_list_entry::MintListEntry, treasury::Treasury},
+    utils::{
+        constants::{
+            seeds::{MINT_LIST_ENTRY, TREASURY},
+            ANCHOR_DISCRIMINATOR_SIZE,
+        },
+        events::MintAddedToList,
+    },
+};
+
+#[derive(Accounts)]
+pub struct AddToMintList<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the account authorized to manage the mint list, who pays for the mint list entry.
+    #[account(
+      mut,
+      address = treasury.fee_collector
+    )]
+    pub fee_collector: Signer<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              SABLIER ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the mint to add to the mint list.
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Create account: the mint list entry of the mint.
+    #[account(
+      init,
+      payer = fee_collector,
+      space = ANCHOR_DISCRIMINATOR_SIZE + MintListEntry::INIT_SPACE,
+      seeds = [MINT_LIST_ENTRY, mint.key().as_ref()],
+      bump
+    )]
+    pub mint_list_entry: Box<Account<'info, MintListEntry>>,
+
+    /// Read account: the treasury account storing the fee collector.
+    #[account(
+      seeds = [TREASURY],
+      bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    // -------------------------------------------------------------------------- //
+    //                               SYSTEM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// See the documentation for [`fn@crate::sablier_lockup::add_to_mint_list`].
+pub fn handler(ctx: Context<AddToMintList>) -> Result<()> {
+    // Effect: create the mint list entry.
+    ctx.accounts.mint_list_entry.create(ctx.bumps.mint_list_entry, ctx.accounts.mint.key())?;
+
+    // Log the addition to the mint list.
+    emit!(MintAddedToList {
+        fee_collector: ctx.accounts.fee_collector.key(),
+        mint: ctx.accounts.mint.key(),
+    });
+
+    Ok(())
+}
@@ -7,8 +7,12 @@ use anchor_spl::{
 use crate::{
     state::lockup::StreamData,
     utils::{
-        constants::seeds::STREAM_DATA, events::CancelLockupStream, lockup_math::get_streamed_amount,
-        transfer_helper::transfer_tokens, validations::check_cancel,
+        constants::seeds::{STREAM_DATA, TEMPORARY_WSOL},
+        errors::ErrorCode,
+        events::CancelLockupStream,
+        lockup_math::get_streamed_amount,
+        transfer_helper::{transfer_tokens, unwrap_sol},
+        validations::{check_cancel, check_unwrap},
     },
 };
 
@@ -24,7 +28,8 @@ pub struct Cancel<'info> {
     )]
     pub sender: Signer<'info>,
 
-    /// Create if needed account: the deposited token ATA owned by the sender.
+    /// Create if needed account: the deposited token ATA owned by the sender, required unless the refunded wSOL is
+    /// unwrapped.
     #[account(
       init_if_needed,
       payer = sender,
@@ -32,7 +37,7 @@ pub struct Cancel<'info> {
       associated_token::authority = sender,
       associated_token::token_program = deposited_token_program,
     )]
-    pub sender_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub sender_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     // -------------------------------------------------------------------------- //
     //                               STREAM ACCOUNTS                              //
@@ -64,6 +69,19 @@ pub struct Cancel<'info> {
     /// Read account: the mint account for the stream NFT.
     pub stream_nft_mint: Box<InterfaceAccount<'info, Mint>>,
 
+    /// Create account: the temporary wSOL account through which the refunded wSOL is unwrapped, closed during the
+    /// instruction. If provided, the sender is refunded SOL instead of wSOL.
+    #[account(
+      init,
+      payer = sender,
+      seeds = [TEMPORARY_WSOL, stream_nft_mint.key().as_ref()],
+      bump,
+      token::mint = deposited_token_mint,
+      token::authority = stream_data,
+      token::token_program = deposited_token_program,
+    )]
+    pub temporary_wsol_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     // -------------------------------------------------------------------------- //
     //                              PROGRAM ACCOUNTS                              //
     // -------------------------------------------------------------------------- //
@@ -111,17 +129,41 @@ pub fn handler(ctx: Context<Cancel>) -> Result<()> {
     // Effect: update the stream data state.
     ctx.accounts.stream_data.cancel(sender_amount, recipient_amount)?;
 
-    // Interaction: transfer the tokens from the stream ATA to the sender.
-    transfer_tokens(
-        ctx.accounts.stream_data_ata.to_account_info(),
-        ctx.accounts.sender_ata.to_account_info(),
-        ctx.accounts.stream_data.to_account_info(),
-        ctx.accounts.deposited_token_mint.to_account_info(),
-        ctx.accounts.deposited_token_program.to_account_info(),
-        sender_amount,
-        ctx.accounts.deposited_token_mint.decimals,
-        &[&[STREAM_DATA, ctx.accounts.stream_nft_mint.key().as_ref(), &[ctx.accounts.stream_data.bump]]],
-    )?;
+    let stream_nft_mint_key = ctx.accounts.stream_nft_mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[STREAM_DATA, stream_nft_mint_key.as_ref(), &[ctx.accounts.stream_data.bump]]];
+
+    match (&ctx.accounts.temporary_wsol_account, &ctx.accounts.sender_ata) {
+        (Some(temporary_wsol_account), _) => {
+            // Check: the deposited token is wSOL.
+            check_unwrap(ctx.accounts.deposited_token_mint.key())?;
+
+            // Interaction: unwrap the wSOL from the stream ATA and transfer the SOL to the sender.
+            unwrap_sol(
+                ctx.accounts.stream_data_ata.to_account_info(),
+                temporary_wsol_account.to_account_info(),
+                ctx.accounts.stream_data.to_account_info(),
+                ctx.accounts.deposited_token_mint.to_account_info(),
+                ctx.accounts.deposited_token_program.to_account_info(),
+                ctx.accounts.sender.to_account_info(),
+                ctx.accounts.sender.to_account_info(),
+                sender_amount,
+                ctx.accounts.deposited_token_mint.decimals,
+                signer_seeds,
+            )?;
+        }
+        // Interaction: transfer the tokens from the stream ATA to the sender.
+        (None, Some(sender_ata)) => transfer_tokens(
+            ctx.accounts.stream_data_ata.to_account_info(),
+            sender_ata.to_account_info(),
+            ctx.accounts.stream_data.to_account_info(),
+            ctx.accounts.deposited_token_mint.to_account_info(),
+            ctx.accounts.deposited_token_program.to_account_info(),
+            sender_amount,
+            ctx.accounts.deposited_token_mint.decimals,
+            signer_seeds,
+        )?,
+        (None, None) => return Err(ErrorCode::TokenAccountMissing.into()),
+    }
 
     // Log the cancellation.
     emit!(CancelLockupStream {
@@ -1,8 +1,13 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    state::treasury::Treasury,
-    utils::{constants::seeds::TREASURY, events::FeesCollected, validations::check_collect_fees},
+    state::treasury::{FeeShare, Treasury},
+    utils::{
+        constants::{seeds::TREASURY, BASIS_POINTS_SCALE},
+        errors::ErrorCode,
+        events::FeesCollected,
+        validations::{check_collect_fees, check_fee_split_recipients},
+    },
 };
 
 #[derive(Accounts)]
@@ -14,10 +19,10 @@ pub struct CollectFees<'info> {
     #[account(address = treasury.fee_collector)]
     pub fee_collector: Signer<'info>,
 
-    /// Write account: the address that will receive the collected fees.
+    /// Write account: the address that will receive the collected fees, required unless the treasury has a fee split.
     #[account(mut)]
     /// CHECK: May be any account
-    pub fee_recipient: UncheckedAccount<'info>,
+    pub fee_recipient: Option<UncheckedAccount<'info>>,
 
     // -------------------------------------------------------------------------- //
     //                              SABLIER ACCOUNTS                              //
@@ -39,20 +44,86 @@ pub fn handler(ctx: Context<CollectFees>) -> Result<()> {
     // Check: validate the collectable amount.
     check_collect_fees(collectible_amount)?;
 
-    // Interaction: transfer the collect amount from the treasury to the fee recipient.
-    ctx.accounts.treasury.sub_lamports(collectible_amount)?;
-    ctx.accounts.fee_recipient.add_lamports(collectible_amount)?;
+    let fee_split = ctx.accounts.treasury.fee_split.clone();
 
-    // Log the fee collection.
-    emit!(FeesCollected {
-        fee_amount: collectible_amount,
-        fee_collector: ctx.accounts.fee_collector.key(),
-        fee_recipient: ctx.accounts.fee_recipient.key()
-    });
+    // If the treasury has no fee split, the fees are collected to the fee recipient.
+    if fee_split.is_empty() {
+        let fee_recipient = ctx.accounts.fee_recipient.as_ref().ok_or(ErrorCode::FeeRecipientMissing)?;
+
+        // Interaction: transfer the collect amount from the treasury to the fee recipient.
+        ctx.accounts.treasury.sub_lamports(collectible_amount)?;
+        fee_recipient.add_lamports(collectible_amount)?;
+
+        // Log the fee collection.
+        emit!(FeesCollected {
+            fee_amount: collectible_amount,
+            fee_collector: ctx.accounts.fee_collector.key(),
+            fee_recipient: fee_recipient.key(),
+        });
+
+        return Ok(());
+    }
+
+    // Check: the remaining accounts are the fee split recipients.
+    check_fee_split_recipients(&fee_split, ctx.remaining_accounts)?;
+
+    // The owed fees are part of the collectible amount, so only the rest is split among the recipients.
+    let owed_fees = ctx.accounts.treasury.owed_fees.clone();
+    let total_owed_fees: u64 = owed_fees.iter().sum();
+    let fee_amounts = split_fees(collectible_amount.saturating_sub(total_owed_fees), &fee_split);
+
+    let rent = Rent::get()?;
+    let mut new_owed_fees = Vec::with_capacity(fee_split.len());
+    for ((fee_recipient, fee_amount), owed_fee) in ctx.remaining_accounts.iter().zip(fee_amounts).zip(owed_fees) {
+        let fee_amount = fee_amount + owed_fee;
+
+        // The runtime rejects a transfer that leaves the recipient below the rent-exempt minimum, e.g. a small share
+        // sent to an unfunded address. Such a share is owed to the recipient and paid in a later collection.
+        if !rent.is_exempt(fee_recipient.lamports().saturating_add(fee_amount), fee_recipient.data_len()) {
+            new_owed_fees.push(fee_amount);
+            continue;
+        }
+        new_owed_fees.push(0);
+
+        // Interaction: transfer the recipient's share from the treasury to the recipient.
+        ctx.accounts.treasury.sub_lamports(fee_amount)?;
+        fee_recipient.add_lamports(fee_amount)?;
+
+        // Log the fee collection for the recipient.
+        emit!(FeesCollected {
+            fee_amount,
+            fee_collector: ctx.accounts.fee_collector.key(),
+            fee_recipient: fee_recipient.key(),
+        });
+    }
+
+    // Check: at least one recipient has been paid.
+    if new_owed_fees.iter().all(|owed_fee| *owed_fee > 0) {
+        return Err(ErrorCode::FeeSplitSharesBelowRentExempt.into());
+    }
+
+    // Effect: record the fees owed to the recipients that could not be paid.
+    ctx.accounts.treasury.collect_fees(new_owed_fees)?;
 
     Ok(())
 }
 
+/// Splits the collectable amount proportionally to the shares of the fee split. The rounding remainder is added to the
+/// last recipient's amount, so that the amounts sum to the collectable amount.
+fn split_fees(collectible_amount: u64, fee_split: &[FeeShare]) -> Vec<u64> {
+    let mut fee_amounts: Vec<u64> = fee_split
+        .iter()
+        .map(|share| ((collectible_amount as u128) * (share.bps as u128) / (BASIS_POINTS_SCALE as u128)) as u64)
+        .collect();
+
+    let distributed_amount: u64 = fee_amounts.iter().sum();
+    if let Some(last_amount) = fee_amounts.last_mut() {
+        *last_amount += collectible_amount - distributed_amount;
+    }
+
+    fee_amounts
+}
+
 /// Helper function to calculate the collectable amount from an account. As a precaution, we add a buffer to the rent
 /// exemption, ensuring that the account balance will not fall below the rent-exempt minimum. This could otherwise
 /// make the program unusable.
@@ -2,16 +2,18 @@ use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
     metadata::Metadata,
+    token::spl_token::native_mint,
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 use crate::{
-    state::{lockup::*, nft_collection_data::NftCollectionData},
+    state::{lockup::*, nft_collection_data::NftCollectionData, treasury::Treasury},
     utils::{
         constants::{seeds::*, ANCHOR_DISCRIMINATOR_SIZE},
+        errors::ErrorCode,
         events::CreateLockupLinearStream,
         nft,
-        transfer_helper::transfer_tokens,
+        transfer_helper::{transfer_tokens, wrap_sol},
         validations::check_create,
     },
 };
@@ -26,14 +28,15 @@ pub struct CreateWithTimestamps<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
 
-    /// Write account: the creator's ATA for the deposit token.
+    /// Write account: the creator's ATA for the deposit token, which may be omitted if the deposit token is wSOL, in
+    /// which case the deposit is wrapped from the creator's SOL.
     #[account(
       mut,
       associated_token::mint = deposit_token_mint,
       associated_token::authority = creator,
       associated_token::token_program = deposit_token_program
     )]
-    pub creator_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub creator_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     /// Read account: the recipient of the stream.
     /// CHECK: The recipient may be any account
@@ -43,6 +46,17 @@ pub struct CreateWithTimestamps<'info> {
     /// CHECK: The sender may be any account
     pub sender: UncheckedAccount<'info>,
 
+    // -------------------------------------------------------------------------- //
+    //                              SABLIER ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the treasury account storing the pause flag and the mint list mode.
+    #[account(
+      seeds = [TREASURY],
+      bump = treasury.bump,
+      constraint = !treasury.is_paused @ ErrorCode::ProgramIsPaused
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
     // -------------------------------------------------------------------------- //
     //                         STREAM COLLECTION ACCOUNTS                         //
     // -------------------------------------------------------------------------- //
@@ -96,6 +110,14 @@ pub struct CreateWithTimestamps<'info> {
     #[account(mint::token_program = deposit_token_program)]
     pub deposit_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
+    /// Read account: the mint list entry of the deposit token, which exists only if the mint is listed.
+    /// CHECK: The account is only checked for existence, as only this program can create data at this address.
+    #[account(
+      seeds = [MINT_LIST_ENTRY, deposit_token_mint.key().as_ref()],
+      bump
+    )]
+    pub mint_list_entry: UncheckedAccount<'info>,
+
     /// Create account: the mint account for the stream NFT.
     #[account(
       init,
@@ -211,10 +233,18 @@ pub fn handler(
 ) -> Result<()> {
     let deposit_token_mint = &ctx.accounts.deposit_token_mint;
     let creator = &ctx.accounts.creator;
-    let creator_ata = &ctx.accounts.creator_ata;
 
     // Validate parameters
-    check_create(deposit_amount, start_time, cliff_time, end_time, start_unlock_amount, cliff_unlock_amount)?;
+    check_create(
+        deposit_amount,
+        start_time,
+        cliff_time,
+        end_time,
+        start_unlock_amount,
+        cliff_unlock_amount,
+        ctx.accounts.treasury.mint_list_mode,
+        !ctx.accounts.mint_list_entry.data_is_empty(),
+    )?;
 
     // Effect: create the stream data.
     ctx.accounts.stream_data.create(
@@ -251,17 +281,28 @@ pub fn handler(
     // Effect: increment the total supply of the NFT collection.
     ctx.accounts.nft_collection_data.create()?;
 
-    // Interaction: transfer tokens from the sender’s ATA to the StreamData ATA.
-    transfer_tokens(
-        creator_ata.to_account_info(),
-        ctx.accounts.stream_data_ata.to_account_info(),
-        creator.to_account_info(),
-        deposit_token_mint.to_account_info(),
-        ctx.accounts.deposit_token_program.to_account_info(),
-        deposit_amount,
-        deposit_token_mint.decimals,
-        &[],
-    )?;
+    match &ctx.accounts.creator_ata {
+        // Interaction: transfer tokens from the sender’s ATA to the StreamData ATA.
+        Some(creator_ata) => transfer_tokens(
+            creator_ata.to_account_info(),
+            ctx.accounts.stream_data_ata.to_account_info(),
+            creator.to_account_info(),
+            deposit_token_mint.to_account_info(),
+            ctx.accounts.deposit_token_program.to_account_info(),
+            deposit_amount,
+            deposit_token_mint.decimals,
+            &[],
+        )?,
+        // Interaction: wrap the creator's SOL into the StreamData ATA.
+        None if deposit_token_mint.key() == native_mint::ID => wrap_sol(
+            creator.to_account_info(),
+            ctx.accounts.stream_data_ata.to_account_info(),
+            ctx.accounts.deposit_token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            deposit_amount,
+        )?,
+        None => return Err(ErrorCode::TokenAccountMissing.into()),
+    }
 
     // Log the newly created stream.
     emit!(CreateLockupLinearStream {
@@ -1,18 +1,33 @@
 #![allow(ambiguous_glob_reexports)]
+pub mod add_to_mint_list;
 pub mod cancel;
 pub mod collect_fees;
+pub mod collect_token_fees;
 pub mod create_with_durations;
 pub mod create_with_timestamps;
 pub mod initialize;
+pub mod migrate_treasury;
+pub mod pause;
+pub mod remove_from_mint_list;
 pub mod renounce;
+pub mod set_fee_split;
+pub mod set_mint_list_mode;
+pub mod unpause;
 pub mod view;
 pub mod withdraw;
 pub mod withdraw_max;
 
+pub use add_to_mint_list::*;
 pub use cancel::*;
 pub use collect_fees::*;
+pub use collect_token_fees::*;
 pub use create_with_timestamps::*;
 pub use initialize::*;
+pub use migrate_treasury::*;
+pub use pause::*;
+pub use remove_from_mint_list::*;
 pub use renounce::*;
+pub use set_fee_split::*;
+pub use set_mint_list_mode::*;
 pub use view::*;
 pub use withdraw::*;
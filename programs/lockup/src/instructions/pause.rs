@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::treasury::Treasury,
+    utils::{constants::seeds::TREASURY, events::ProgramPaused, validations::check_pause},
+};
+
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the account authorized to pause and unpause the program.
+    #[account(address = treasury.fee_collector)]
+    pub fee_collector: Signer<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              SABLIER ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the treasury account storing the pause flag.
+    #[account(
+      mut,
+      seeds = [TREASURY],
+      bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+}
+
+/// See the documentation for [`fn@crate::sablier_lockup::pause`].
+pub fn handler(ctx: Context<Pause>) -> Result<()> {
+    // Check: validate the pause.
+    check_pause(ctx.accounts.treasury.is_paused)?;
+
+    // Effect: pause the program.
+    ctx.accounts.treasury.pause()?;
+
+    // Log the pause.
+    emit!(ProgramPaused {
+        fee_collector: ctx.accounts.fee_collector.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::treasury::{MintListMode, Treasury},
+    utils::{constants::seeds::TREASURY, events::MintListModeSet},
+};
+
+#[derive(Accounts)]
+pub struct SetMintListMode<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the account authorized to manage the mint list.
+    #[account(address = treasury.fee_collector)]
+    pub fee_collector: Signer<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              SABLIER ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the treasury account storing the mint list mode.
+    #[account(
+      mut,
+      seeds = [TREASURY],
+      bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+}
+
+/// See the documentation for [`fn@crate::sablier_lockup::set_mint_list_mode`].
+pub fn handler(ctx: Context<SetMintListMode>, mint_list_mode: MintListMode) -> Result<()> {
+    // Effect: store the mint list mode in the treasury.
+    ctx.accounts.treasury.set_mint_list_mode(mint_list_mode)?;
+
+    // Log the mint list mode update.
+    emit!(MintListModeSet {
+        fee_collector: ctx.accounts.fee_collector.key(),
+        mint_list_mode,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    instructions::pause,
+    utils::{events::ProgramUnpaused, validations::check_unpause},
+};
+
+/// See the documentation for [`fn@crate::sablier_lockup::unpause`].
+pub fn handler(ctx: Context<pause::Pause>) -> Result<()> {
+    // Check: validate the unpause.
+    check_unpause(ctx.accounts.treasury.is_paused)?;
+
+    // Effect: unpause the program.
+    ctx.accounts.treasury.unpause()?;
+
+    // Log the unpause.
+    emit!(ProgramUnpaused {
+        fee_collector: ctx.accounts.fee_collector.key(),
+    });
+
+    Ok(())
+}
@@ -11,11 +11,12 @@ use crate::{
     state::{lockup::StreamData, treasury::Treasury},
     utils::{
         constants::{seeds::*, WITHDRAWAL_FEE_USD},
+        errors::ErrorCode,
         events::WithdrawFromLockupStream,
         fee_calculation::convert_usd_fee_to_lamports,
         lockup_math::get_withdrawable_amount,
-        transfer_helper::transfer_tokens,
-        validations::check_withdraw,
+        transfer_helper::{transfer_tokens, unwrap_sol},
+        validations::{check_unwrap, check_withdraw},
     },
 };
 
@@ -32,8 +33,9 @@ pub struct Withdraw<'info> {
     /// CHECK: This account must be the Stream's recipient (checked in recipient_stream_nft_ata's constraints)
     pub stream_recipient: UncheckedAccount<'info>,
 
-    /// Read account: the account that will receive the withdrawn tokens.
+    /// Write account: the account that will receive the withdrawn tokens.
     #[account(
+      mut,
       constraint = (
         withdrawal_recipient.key() == stream_recipient.key() ||
         (withdrawal_recipient.key() != stream_recipient.key() &&
@@ -44,7 +46,8 @@ pub struct Withdraw<'info> {
     /// recipient.
     pub withdrawal_recipient: UncheckedAccount<'info>,
 
-    /// Create if needed account: the ATA for deposited tokens owned by withdrawal recipient.
+    /// Create if needed account: the ATA for deposited tokens owned by withdrawal recipient, required unless the
+    /// withdrawn wSOL is unwrapped.
     #[account(
       init_if_needed,
       payer = signer,
@@ -52,7 +55,7 @@ pub struct Withdraw<'info> {
       associated_token::mint = deposited_token_mint,
       associated_token::token_program = deposited_token_program,
     )]
-    pub withdrawal_recipient_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub withdrawal_recipient_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     // -------------------------------------------------------------------------- //
     //                              SABLIER ACCOUNTS                              //
@@ -104,6 +107,19 @@ pub struct Withdraw<'info> {
     /// Read account: the mint account for the stream NFT.
     pub stream_nft_mint: Box<InterfaceAccount<'info, Mint>>,
 
+    /// Create account: the temporary wSOL account through which the withdrawn wSOL is unwrapped, closed during the
+    /// instruction. If provided, the withdrawal recipient receives SOL instead of wSOL.
+    #[account(
+      init,
+      payer = signer,
+      seeds = [TEMPORARY_WSOL, stream_nft_mint.key().as_ref()],
+      bump,
+      token::mint = deposited_token_mint,
+      token::authority = stream_data,
+      token::token_program = deposited_token_program,
+    )]
+    pub temporary_wsol_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     // -------------------------------------------------------------------------- //
     //                               PROGRAM ACCOUNTS                             //
     // -------------------------------------------------------------------------- //
@@ -158,17 +174,41 @@ pub fn handler(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
         ctx.accounts.treasury.to_account_info(),
     )?;
 
-    // Interaction: transfer the tokens from the stream ATA to the recipient.
-    transfer_tokens(
-        ctx.accounts.stream_data_ata.to_account_info(),
-        ctx.accounts.withdrawal_recipient_ata.to_account_info(),
-        ctx.accounts.stream_data.to_account_info(),
-        ctx.accounts.deposited_token_mint.to_account_info(),
-        ctx.accounts.deposited_token_program.to_account_info(),
-        amount,
-        ctx.accounts.deposited_token_mint.decimals,
-        &[&[STREAM_DATA, ctx.accounts.stream_nft_mint.key().as_ref(), &[ctx.accounts.stream_data.bump]]],
-    )?;
+    let stream_nft_mint_key = ctx.accounts.stream_nft_mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[STREAM_DATA, stream_nft_mint_key.as_ref(), &[ctx.accounts.stream_data.bump]]];
+
+    match (&ctx.accounts.temporary_wsol_account, &ctx.accounts.withdrawal_recipient_ata) {
+        (Some(temporary_wsol_account), _) => {
+            // Check: the deposited token is wSOL.
+            check_unwrap(ctx.accounts.deposited_token_mint.key())?;
+
+            // Interaction: unwrap the wSOL from the stream ATA and transfer the SOL to the recipient.
+            unwrap_sol(
+                ctx.accounts.stream_data_ata.to_account_info(),
+                temporary_wsol_account.to_account_info(),
+                ctx.accounts.stream_data.to_account_info(),
+                ctx.accounts.deposited_token_mint.to_account_info(),
+                ctx.accounts.deposited_token_program.to_account_info(),
+                ctx.accounts.withdrawal_recipient.to_account_info(),
+                ctx.accounts.signer.to_account_info(),
+                amount,
+                ctx.accounts.deposited_token_mint.decimals,
+                signer_seeds,
+            )?;
+        }
+        // Interaction: transfer the tokens from the stream ATA to the recipient.
+        (None, Some(withdrawal_recipient_ata)) => transfer_tokens(
+            ctx.accounts.stream_data_ata.to_account_info(),
+            withdrawal_recipient_ata.to_account_info(),
+            ctx.accounts.stream_data.to_account_info(),
+            ctx.accounts.deposited_token_mint.to_account_info(),
+            ctx.accounts.deposited_token_program.to_account_info(),
+            amount,
+            ctx.accounts.deposited_token_mint.decimals,
+            signer_seeds,
+        )?,
+        (None, None) => return Err(ErrorCode::TokenAccountMissing.into()),
+    }
 
     // Log the withdrawal.
     emit!(WithdrawFromLockupStream {
@@ -19,6 +19,27 @@ pub mod sablier_lockup {
     //                         STATE-CHANGING INSTRUCTIONS                        //
     // -------------------------------------------------------------------------- //
 
+    /// Adds a mint to the mint list, which acts as an allowlist or a denylist depending on the treasury's mint list
+    /// mode.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `fee_collector` The transaction signer and the fee collector.
+    /// - `mint` The mint to add to the mint list.
+    ///
+    /// # Notes
+    ///
+    /// - The rent of the mint list entry is paid by the fee collector.
+    /// - Emits a [`crate::utils::events::MintAddedToList`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - `fee_collector` must be the treasury's fee collector.
+    /// - The mint must not be in the mint list yet.
+    pub fn add_to_mint_list(ctx: Context<AddToMintList>) -> Result<()> {
+        instructions::add_to_mint_list::handler(ctx)
+    }
+
     /// Cancels the stream and refunds any remaining tokens to the sender ATA.
     ///
     /// # Accounts Expected
@@ -27,12 +48,16 @@ pub mod sablier_lockup {
     /// - `deposited_token_mint` The mint of the deposited token.
     /// - `stream_nft_mint` The stream NFT mint uniquely identifying the stream.
     /// - `deposited_token_program` The Token Program of the deposited token.
+    /// - `sender_ata` The sender's ATA for the deposited token, to omit if the refunded wSOL is unwrapped.
+    /// - `temporary_wsol_account` The temporary wSOL account to provide to unwrap the refunded wSOL.
     ///
     /// # Notes
     ///
     /// - If there are any tokens left for the recipient to withdraw, the stream is marked as canceled. Otherwise, the
     /// stream is marked as depleted.
     /// - If the sender does not have an ATA for the deposited token, it is created.
+    /// - If `temporary_wsol_account` is provided, the refunded wSOL is unwrapped through it and the sender receives
+    /// SOL. The temporary account is closed during the instruction.
     /// - Emits a [`crate::utils::events::CancelLockupStream`] event.
     ///
     /// # Requirements
@@ -41,29 +66,64 @@ pub mod sablier_lockup {
     /// - The `stream_nft_mint` must exist.
     /// - The stream must be cancelable.
     /// - The stream must be Pending or Streaming.
+    /// - If `temporary_wsol_account` is provided, the deposited token must be wSOL. Otherwise, `sender_ata` must be
+    /// provided.
     pub fn cancel(ctx: Context<Cancel>) -> Result<()> {
         instructions::cancel::handler(ctx)
     }
 
-    /// Collects the fees accumulated in the treasury by transferring them to the fee recipient.
+    /// Collects the fees accumulated in the treasury by transferring them to the fee recipient, or by distributing them
+    /// among the recipients of the treasury's fee split.
     ///
     /// # Accounts Expected
     ///
     /// - `fee_collector` The transaction signer and the fee collector.
-    /// - `fee_recipient` The address receiving the collected fees.
+    /// - `fee_recipient` The address receiving the collected fees, if the treasury has no fee split.
+    /// - `remaining_accounts` If the treasury has a fee split: the writable recipients of the fee split, in order.
     ///
     /// # Notes
     ///
     /// - Leaves a buffer of 0.001 SOL to ensure the account remains rent-exempt after the fee collection.
-    /// - Emits a [`crate::utils::events::FeesCollected`] event.
+    /// - With a fee split, each recipient receives its share of the collected fees, and the rounding remainder goes to
+    /// the last recipient.
+    /// - A recipient's share is not paid if it would leave the recipient below the rent-exempt minimum, e.g. if the
+    /// recipient is an unfunded address. The share is recorded as owed to the recipient in the treasury, and is paid
+    /// along with its next share in a later collection.
+    /// - Emits a [`crate::utils::events::FeesCollected`] event for each paid recipient.
     ///
     /// # Requirements
     ///
     /// - `fee_collector` must be authorized for fee collection.
+    /// - If the treasury has no fee split, `fee_recipient` must be provided. Otherwise, the remaining accounts must
+    /// match the recipients of the fee split, and at least one of them must be paid.
     pub fn collect_fees(ctx: Context<CollectFees>) -> Result<()> {
         instructions::collect_fees::handler(ctx)
     }
 
+    /// Collects the token fees accumulated in the treasury's ATA for a given mint by transferring them to the fee
+    /// recipient's ATA.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `fee_collector` The transaction signer and the fee collector.
+    /// - `fee_recipient` The address receiving the collected fees.
+    /// - `token_mint` The mint of the token to collect.
+    /// - `token_program` The Token Program of the token to collect.
+    ///
+    /// # Notes
+    ///
+    /// - The full balance of the treasury's ATA is collected, e.g. the tokens sent to the treasury by mistake.
+    /// - The fee recipient's ATA is created if needed, paid by the fee collector.
+    /// - Emits a [`crate::utils::events::TokenFeesCollected`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - `fee_collector` must be authorized for fee collection.
+    /// - The treasury's ATA must hold a non-zero balance.
+    pub fn collect_token_fees(ctx: Context<CollectTokenFees>) -> Result<()> {
+        instructions::collect_token_fees::handler(ctx)
+    }
+
     /// Creates a stream by setting the start time to the current timestamp, and the end time to the sum of the
     /// current timestamp and the total duration The stream is funded by the signer and wrapped in a Metaplex NFT.
     ///
@@ -131,6 +191,7 @@ pub mod sablier_lockup {
     /// # Notes
     ///
     /// - The passed sender of the stream doesn't have to be the same as its creator.
+    /// - If the deposit token is wSOL and `creator_ata` is omitted, the deposit is wrapped from the creator's SOL.
     /// - A cliff time of zero means there is no cliff.
     /// - As long as the times are ordered, it is not an error for the start or the cliff time to be in the past.
     /// - The stream recipient is given solely by the ownership of the stream NFT, which is minted to the passed
@@ -144,6 +205,10 @@ pub mod sablier_lockup {
     /// - If set, `cliff_time` must be greater than `start_time` and less than `end_time`.
     /// - The sum of `start_unlock_amount` and `cliff_unlock_amount` must be less than or equal to deposit amount.
     /// - If `cliff_time` is not set, the `cliff_unlock_amount` amount must be zero.
+    /// - `creator_ata` must be provided unless the deposit token is wSOL.
+    /// - The program must not be paused.
+    /// - The deposit token must be in the mint list if the mint list mode is [`state::MintListMode::Allowlist`], and
+    /// must not be in it if the mode is [`state::MintListMode::Denylist`].
     #[allow(clippy::too_many_arguments)]
     pub fn create_with_timestamps_ll(
         ctx: Context<CreateWithTimestamps>,
@@ -190,6 +255,65 @@ pub mod sablier_lockup {
         instructions::initialize::handler(ctx, fee_collector, chainlink_program, chainlink_sol_usd_feed)
     }
 
+    /// Migrates a treasury initialized by a prior version of the program to the current layout, by growing the account
+    /// to fit the fee split, the pause flag and the mint list mode.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `signer` The transaction signer, paying for the additional rent.
+    ///
+    /// # Notes
+    ///
+    /// - The new fields are zero-initialized: an empty fee split, an unpaused program and an open mint list.
+    /// - Anyone can migrate the treasury, as the migration does not change any of its existing fields.
+    /// - Until the treasury is migrated, the instructions that load it fail to deserialize it.
+    ///
+    /// # Requirements
+    ///
+    /// - The treasury must have been initialized, and must not have been migrated already.
+    pub fn migrate_treasury(ctx: Context<MigrateTreasury>) -> Result<()> {
+        instructions::migrate_treasury::handler(ctx)
+    }
+
+    /// Pauses the program, blocking the creation of new streams until it is unpaused.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `fee_collector` The transaction signer and the fee collector.
+    ///
+    /// # Notes
+    ///
+    /// - Cancels, renouncements and withdrawals remain allowed, so that the funds can always exit the streams.
+    /// - Emits a [`crate::utils::events::ProgramPaused`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - `fee_collector` must be the treasury's fee collector.
+    /// - The program must not be paused.
+    pub fn pause(ctx: Context<Pause>) -> Result<()> {
+        instructions::pause::handler(ctx)
+    }
+
+    /// Removes a mint from the mint list.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `fee_collector` The transaction signer and the fee collector.
+    /// - `mint` The mint to remove from the mint list.
+    ///
+    /// # Notes
+    ///
+    /// - The rent of the mint list entry is refunded to the fee collector.
+    /// - Emits a [`crate::utils::events::MintRemovedFromList`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - `fee_collector` must be the treasury's fee collector.
+    /// - The mint must be in the mint list.
+    pub fn remove_from_mint_list(ctx: Context<RemoveFromMintList>) -> Result<()> {
+        instructions::remove_from_mint_list::handler(ctx)
+    }
+
     /// Removes the right of the stream's sender to cancel the stream.
     ///
     /// # Accounts Expected
@@ -204,6 +328,74 @@ pub mod sablier_lockup {
         instructions::renounce::handler(ctx)
     }
 
+    /// Sets the fee split of the treasury, i.e. the recipients among which the collected fees are distributed.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `fee_collector` The transaction signer and the fee collector.
+    ///
+    /// # Parameters
+    ///
+    /// - `fee_split` The recipients and their shares of the collected fees, in basis points. An empty value disables
+    /// the fee split.
+    ///
+    /// # Notes
+    ///
+    /// - The fees owed to the recipients kept in the fee split carry over, while the ones owed to the removed
+    /// recipients return to the collectible amount.
+    /// - Emits a [`crate::utils::events::FeeSplitSet`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - `fee_collector` must be authorized for fee collection.
+    /// - `fee_split` must not have more than 10 recipients.
+    /// - If `fee_split` is not empty, the shares must be non-zero and sum to 10,000 basis points, and the recipients must
+    /// be unique.
+    pub fn set_fee_split(ctx: Context<SetFeeSplit>, fee_split: Vec<state::FeeShare>) -> Result<()> {
+        instructions::set_fee_split::handler(ctx, fee_split)
+    }
+
+    /// Sets the mode of the mint list, which determines the deposit tokens allowed upon the creation of a stream.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `fee_collector` The transaction signer and the fee collector.
+    ///
+    /// # Parameters
+    ///
+    /// - `mint_list_mode` The new mode of the mint list: open to any mint, restricted to the listed mints, or open to
+    /// any mint except the listed ones.
+    ///
+    /// # Notes
+    ///
+    /// - The mode only applies to the creation of new streams, and the existing streams are not affected.
+    /// - Emits a [`crate::utils::events::MintListModeSet`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - `fee_collector` must be the treasury's fee collector.
+    pub fn set_mint_list_mode(ctx: Context<SetMintListMode>, mint_list_mode: state::MintListMode) -> Result<()> {
+        instructions::set_mint_list_mode::handler(ctx, mint_list_mode)
+    }
+
+    /// Unpauses the program, allowing the creation of new streams again.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `fee_collector` The transaction signer and the fee collector.
+    ///
+    /// # Notes
+    ///
+    /// - Emits a [`crate::utils::events::ProgramUnpaused`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - `fee_collector` must be the treasury's fee collector.
+    /// - The program must be paused.
+    pub fn unpause(ctx: Context<Pause>) -> Result<()> {
+        instructions::unpause::handler(ctx)
+    }
+
     /// Withdraws the provided amount of tokens from the stream data ATA to the provided account.
     ///
     /// # Accounts Expected
@@ -216,6 +408,9 @@ pub mod sablier_lockup {
     /// - `nft_token_program` The Token Program of the NFT.
     /// - `chainlink_program`: The Chainlink program used to retrieve on-chain price feeds.
     /// - `chainlink_sol_usd_feed`: The account providing the SOL/USD price feed data.
+    /// - `withdrawal_recipient_ata` The withdrawal recipient's ATA for the deposited token, to omit if the withdrawn
+    /// wSOL is unwrapped.
+    /// - `temporary_wsol_account` The temporary wSOL account to provide to unwrap the withdrawn wSOL.
     ///
     /// # Parameters
     ///
@@ -224,6 +419,9 @@ pub mod sablier_lockup {
     /// # Notes
     ///
     /// - If the withdrawal recipient does not have an ATA for the deposited token, one is created.
+    /// - If `temporary_wsol_account` is provided, the withdrawn wSOL is unwrapped through it and the withdrawal
+    /// recipient receives SOL. The temporary account is closed during the instruction, and its rent is refunded to the
+    /// signer.
     /// - Emits [`crate::utils::events::WithdrawFromLockupStream`] event.
     ///
     /// # Requirements
@@ -233,6 +431,8 @@ pub mod sablier_lockup {
     /// - `amount` must be greater than zero and must not exceed the withdrawable amount.
     /// - The stream must not be Depleted.
     /// - `chainlink_program` and `chainlink_sol_usd_feed` must match the ones stored in the treasury.
+    /// - If `temporary_wsol_account` is provided, the deposited token must be wSOL. Otherwise,
+    /// `withdrawal_recipient_ata` must be provided.
     pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
         instructions::withdraw::handler(ctx, amount)
     }
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+/// Marks a mint as listed in the mint list, which acts as an allowlist or a denylist depending on the treasury's
+/// [`crate::state::treasury::MintListMode`].
+#[account]
+#[derive(InitSpace)]
+pub struct MintListEntry {
+    pub bump: u8,
+    pub mint: Pubkey,
+}
+
+impl MintListEntry {
+    /// State update for the [`fn@crate::sablier_lockup::add_to_mint_list`] instruction.
+    pub fn create(&mut self, bump: u8, mint: Pubkey) -> Result<()> {
+        self.bump = bump;
+        self.mint = mint;
+
+        Ok(())
+    }
+}
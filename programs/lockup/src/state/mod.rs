@@ -1,4 +1,5 @@
 pub mod lockup;
+pub mod mint_list_entry;
 pub mod nft_collection_data;
 pub mod treasury;
 
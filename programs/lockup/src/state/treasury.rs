@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::utils::constants::MAX_FEE_SPLIT_RECIPIENTS;
+
 #[account]
 #[derive(InitSpace)]
 pub struct Treasury {
@@ -7,9 +9,43 @@ pub struct Treasury {
     pub fee_collector: Pubkey,
     pub chainlink_program: Pubkey,
     pub chainlink_sol_usd_feed: Pubkey,
+    #[max_len(MAX_FEE_SPLIT_RECIPIENTS)]
+    pub fee_split: Vec<FeeShare>,
+    /// The fees owed to the recipients of the fee split, in the same order, whose shares could not be paid without
+    /// leaving them below the rent-exempt minimum.
+    #[max_len(MAX_FEE_SPLIT_RECIPIENTS)]
+    pub owed_fees: Vec<u64>,
+    pub is_paused: bool,
+    pub mint_list_mode: MintListMode,
+}
+
+/// The share of the collected fees distributed to a recipient.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub struct FeeShare {
+    pub bps: u16,
+    pub recipient: Pubkey,
+}
+
+/// The way the mint list is enforced upon the creation of a stream.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace, PartialEq, Eq)]
+pub enum MintListMode {
+    /// Any mint can be deposited, and the mint list is ignored.
+    #[default]
+    Open,
+    /// Only the mints in the mint list can be deposited.
+    Allowlist,
+    /// Any mint can be deposited, except the ones in the mint list.
+    Denylist,
 }
 
 impl Treasury {
+    /// State update for the [`fn@crate::sablier_lockup::collect_fees`] instruction.
+    pub fn collect_fees(&mut self, owed_fees: Vec<u64>) -> Result<()> {
+        self.owed_fees = owed_fees;
+
+        Ok(())
+    }
+
     /// State update for the [`fn@crate::sablier_lockup::initialize`] instruction.
     pub fn initialize(
         &mut self,
@@ -25,4 +61,44 @@ impl Treasury {
 
         Ok(())
     }
+
+    /// State update for the [`fn@crate::sablier_lockup::pause`] instruction.
+    pub fn pause(&mut self) -> Result<()> {
+        self.is_paused = true;
+
+        Ok(())
+    }
+
+    /// State update for the [`fn@crate::sablier_lockup::set_fee_split`] instruction.
+    pub fn set_fee_split(&mut self, fee_split: Vec<FeeShare>) -> Result<()> {
+        // The fees owed to the recipients kept in the fee split carry over, while the ones owed to the removed
+        // recipients return to the collectible amount.
+        self.owed_fees = fee_split
+            .iter()
+            .map(|share| {
+                self.fee_split
+                    .iter()
+                    .zip(&self.owed_fees)
+                    .find(|(previous_share, _)| previous_share.recipient == share.recipient)
+                    .map_or(0, |(_, owed_fee)| *owed_fee)
+            })
+            .collect();
+        self.fee_split = fee_split;
+
+        Ok(())
+    }
+
+    /// State update for the [`fn@crate::sablier_lockup::set_mint_list_mode`] instruction.
+    pub fn set_mint_list_mode(&mut self, mint_list_mode: MintListMode) -> Result<()> {
+        self.mint_list_mode = mint_list_mode;
+
+        Ok(())
+    }
+
+    /// State update for the [`fn@crate::sablier_lockup::unpause`] instruction.
+    pub fn unpause(&mut self) -> Result<()> {
+        self.is_paused = false;
+
+        Ok(())
+    }
 }
@@ -1,17 +1,25 @@
 pub const ANCHOR_DISCRIMINATOR_SIZE: usize = 8;
+// The number of basis points in 100%.
+pub const BASIS_POINTS_SCALE: u64 = 10_000;
+// The maximum number of recipients among which the collected fees can be split.
+pub const MAX_FEE_SPLIT_RECIPIENTS: usize = 10;
 // The fee amount in USD, denominated in Chainlink's 8-decimal format for USD prices, where 1e8 is $1.
 // Currently set to 0.
 pub const WITHDRAWAL_FEE_USD: u64 = 0;
+// The size of the treasury account prior to the addition of the fee split, the pause and the mint list.
+pub const LEGACY_TREASURY_SIZE: usize = ANCHOR_DISCRIMINATOR_SIZE + 1 + 3 * 32;
 pub const LAMPORTS_PER_SOL: u64 = 1e9 as u64; // 1 billion lamports in 1 SOL
 
 // Account Seeds
 pub mod seeds {
     pub const EDITION: &[u8] = b"edition";
     pub const METADATA: &[u8] = b"metadata";
+    pub const MINT_LIST_ENTRY: &[u8] = b"mint_list_entry";
     pub const NFT_COLLECTION_DATA: &[u8] = b"nft_collection_data";
     pub const NFT_COLLECTION_MINT: &[u8] = b"nft_collection_mint";
     pub const STREAM_DATA: &[u8] = b"stream_data";
     pub const STREAM_NFT_MINT: &[u8] = b"stream_nft_mint";
+    pub const TEMPORARY_WSOL: &[u8] = b"temporary_wsol";
     pub const TREASURY: &[u8] = b"treasury";
 }
 
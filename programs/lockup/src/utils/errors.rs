@@ -7,6 +7,12 @@ pub enum ErrorCode {
     // -------------------------------------------------------------------------- //
     #[msg("Can't perform the action on a depleted stream!")]
     StreamDepleted,
+    #[msg("The token account required to send or receive the tokens is missing!")]
+    TokenAccountMissing,
+    #[msg("Only wrapped SOL can be unwrapped!")]
+    UnwrapNotSupported,
+    #[msg("The program is paused!")]
+    ProgramIsPaused,
 
     // -------------------------------------------------------------------------- //
     //                                CANCEL STREAM                               //
@@ -23,6 +29,12 @@ pub enum ErrorCode {
     // -------------------------------------------------------------------------- //
     #[msg("Can't collect zero fees!")]
     CantCollectZeroFees,
+    #[msg("The fee recipient is required if the treasury has no fee split!")]
+    FeeRecipientMissing,
+    #[msg("The remaining accounts must be the fee split recipients, in order!")]
+    FeeSplitRecipientsMismatch,
+    #[msg("No fee split recipient can be paid without falling below the rent-exempt minimum!")]
+    FeeSplitSharesBelowRentExempt,
 
     // -------------------------------------------------------------------------- //
     //                                CREATE STREAM                               //
@@ -33,6 +45,8 @@ pub enum ErrorCode {
     CliffTimeZeroUnlockAmountNotZero,
     #[msg("Invalid deposit amount!")]
     DepositAmountZero,
+    #[msg("The deposit token mint is not allowed by the mint list!")]
+    MintNotAllowed,
     #[msg("Start time must be less than cliff time!")]
     StartTimeNotLessThanCliffTime,
     #[msg("Start time must be less than end time!")]
@@ -42,12 +56,36 @@ pub enum ErrorCode {
     #[msg("Unlock amounts sum is greater than deposit amount!")]
     UnlockAmountsSumTooHigh,
 
+    // -------------------------------------------------------------------------- //
+    //                              MIGRATE TREASURY                              //
+    // -------------------------------------------------------------------------- //
+    #[msg("The treasury has already been migrated!")]
+    TreasuryAlreadyMigrated,
+
+    // -------------------------------------------------------------------------- //
+    //                                PAUSE PROGRAM                               //
+    // -------------------------------------------------------------------------- //
+    #[msg("The program is already paused!")]
+    ProgramAlreadyPaused,
+    #[msg("The program is not paused!")]
+    ProgramNotPaused,
+
     // -------------------------------------------------------------------------- //
     //                               RENOUNCE STREAM                              //
     // -------------------------------------------------------------------------- //
     #[msg("Can't renounce a non-cancelable Stream!")]
     StreamAlreadyNonCancelable,
 
+    // -------------------------------------------------------------------------- //
+    //                                SET FEE SPLIT                               //
+    // -------------------------------------------------------------------------- //
+    #[msg("The fee split recipients must be unique!")]
+    FeeSplitDuplicateRecipient,
+    #[msg("The fee split has too many recipients!")]
+    FeeSplitTooManyRecipients,
+    #[msg("The fee split shares must be non-zero and sum to 10,000 basis points!")]
+    InvalidFeeSplitShares,
+
     // -------------------------------------------------------------------------- //
     //                            WITHDRAW FROM STREAM                            //
     // -------------------------------------------------------------------------- //
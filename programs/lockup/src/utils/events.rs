@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::treasury::{FeeShare, MintListMode};
+
 /// Emitted when a stream is canceled.
 #[event]
 pub struct CancelLockupStream {
@@ -29,6 +31,55 @@ pub struct FeesCollected {
     pub fee_recipient: Pubkey,
 }
 
+/// Emitted when the fee collector sets the fee split of the treasury.
+#[event]
+pub struct FeeSplitSet {
+    pub fee_collector: Pubkey,
+    pub fee_split: Vec<FeeShare>,
+}
+
+/// Emitted when the fee collector adds a mint to the mint list.
+#[event]
+pub struct MintAddedToList {
+    pub fee_collector: Pubkey,
+    pub mint: Pubkey,
+}
+
+/// Emitted when the fee collector sets the mode of the mint list.
+#[event]
+pub struct MintListModeSet {
+    pub fee_collector: Pubkey,
+    pub mint_list_mode: MintListMode,
+}
+
+/// Emitted when the fee collector removes a mint from the mint list.
+#[event]
+pub struct MintRemovedFromList {
+    pub fee_collector: Pubkey,
+    pub mint: Pubkey,
+}
+
+/// Emitted when token fees are collected from the treasury's ATA.
+#[event]
+pub struct TokenFeesCollected {
+    pub fee_amount: u64,
+    pub fee_collector: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub token_mint: Pubkey,
+}
+
+/// Emitted when the fee collector pauses the program.
+#[event]
+pub struct ProgramPaused {
+    pub fee_collector: Pubkey,
+}
+
+/// Emitted when the fee collector unpauses the program.
+#[event]
+pub struct ProgramUnpaused {
+    pub fee_collector: Pubkey,
+}
+
 /// Emitted when a sender gives up the right to cancel a stream.
 #[event]
 pub struct RenounceLockupStream {
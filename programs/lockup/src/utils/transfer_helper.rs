@@ -1,5 +1,10 @@
-use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{transfer_checked, TransferChecked};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction::transfer},
+};
+use anchor_spl::token_interface::{
+    close_account, sync_native, transfer_checked, CloseAccount, SyncNative, TransferChecked,
+};
 
 /// Helper function to transfer tokens.
 #[allow(clippy::too_many_arguments)]
@@ -28,3 +33,78 @@ pub fn transfer_tokens<'info>(
 
     Ok(())
 }
+
+/// Helper function to wrap SOL by transferring lamports to a wSOL token account and syncing its balance.
+pub fn wrap_sol<'info>(
+    from: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    // Transfer the lamports to the wSOL token account
+    let transfer_ix = transfer(&from.key(), &to.key(), amount);
+    invoke(&transfer_ix, &[from, to.clone(), system_program])?;
+
+    // Sync the token balance with the lamports held by the account
+    let cpi_ctx = CpiContext::new(
+        token_program,
+        SyncNative {
+            account: to,
+        },
+    );
+
+    sync_native(cpi_ctx)?;
+
+    Ok(())
+}
+
+/// Helper function to unwrap wSOL held by a program account and deliver the lamports to the destination.
+///
+/// The wSOL is moved into a temporary token account owned by `authority`, which is then closed into `authority`. The
+/// unwrapped amount is forwarded to the destination, and the rent of the temporary account is refunded to its payer.
+#[allow(clippy::too_many_arguments)]
+pub fn unwrap_sol<'info>(
+    from: AccountInfo<'info>,
+    temporary_wsol_account: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+    rent_payer: AccountInfo<'info>,
+    amount: u64,
+    decimals: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    // The temporary account only holds its rent before the transfer.
+    let rent = temporary_wsol_account.lamports();
+
+    // Move the wSOL to the temporary account
+    transfer_tokens(
+        from,
+        temporary_wsol_account.clone(),
+        authority.clone(),
+        mint,
+        token_program.clone(),
+        amount,
+        decimals,
+        signer_seeds,
+    )?;
+
+    // Close the temporary account, which credits its lamports to the authority
+    let close_ix = CloseAccount {
+        account: temporary_wsol_account,
+        destination: authority.clone(),
+        authority: authority.clone(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program, close_ix, signer_seeds);
+
+    close_account(cpi_ctx)?;
+
+    // Forward the unwrapped lamports to the destination and refund the rent to its payer
+    authority.sub_lamports(amount.checked_add(rent).expect("Unwrapped lamports overflow"))?;
+    destination.add_lamports(amount)?;
+    rent_payer.add_lamports(rent)?;
+
+    Ok(())
+}
@@ -1,6 +1,13 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
 
-use crate::utils::errors::ErrorCode;
+use crate::{
+    state::treasury::{FeeShare, MintListMode, Treasury},
+    utils::{
+        constants::{BASIS_POINTS_SCALE, LEGACY_TREASURY_SIZE, MAX_FEE_SPLIT_RECIPIENTS},
+        errors::ErrorCode,
+    },
+};
 
 /// Validate the cancellation of a stream.
 pub fn check_cancel(
@@ -40,7 +47,73 @@ pub fn check_collect_fees(collectible_amount: u64) -> Result<()> {
     Ok(())
 }
 
+/// Validate the recipients of a fee split, passed via the remaining accounts.
+pub fn check_fee_split_recipients(fee_split: &[FeeShare], remaining_accounts: &[AccountInfo]) -> Result<()> {
+    // Check: the remaining accounts are the fee split recipients, in the same order.
+    if remaining_accounts.len() != fee_split.len() ||
+        fee_split.iter().zip(remaining_accounts).any(|(share, account)| share.recipient != account.key())
+    {
+        return Err(ErrorCode::FeeSplitRecipientsMismatch.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the migration of the treasury to the current layout.
+pub fn check_migrate_treasury(treasury_data: &[u8]) -> Result<()> {
+    // Check: the account is a treasury.
+    if !treasury_data.starts_with(Treasury::DISCRIMINATOR) {
+        return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+
+    // Check: the treasury has the layout prior to the fee split, the pause and the mint list.
+    if treasury_data.len() != LEGACY_TREASURY_SIZE {
+        return Err(ErrorCode::TreasuryAlreadyMigrated.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the pause of the program.
+pub fn check_pause(is_paused: bool) -> Result<()> {
+    // Check: the program is not already paused.
+    if is_paused {
+        return Err(ErrorCode::ProgramAlreadyPaused.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the fee split configuration of the treasury.
+pub fn check_set_fee_split(fee_split: &[FeeShare]) -> Result<()> {
+    // Check: the number of recipients does not exceed the maximum allowed.
+    if fee_split.len() > MAX_FEE_SPLIT_RECIPIENTS {
+        return Err(ErrorCode::FeeSplitTooManyRecipients.into());
+    }
+
+    // An empty fee split disables the split, so that the fees are collected to a single recipient.
+    if fee_split.is_empty() {
+        return Ok(());
+    }
+
+    // Check: the shares are non-zero and sum to 100%.
+    let total_bps: u64 = fee_split.iter().map(|share| share.bps as u64).sum();
+    if fee_split.iter().any(|share| share.bps == 0) || total_bps != BASIS_POINTS_SCALE {
+        return Err(ErrorCode::InvalidFeeSplitShares.into());
+    }
+
+    // Check: the recipients are unique.
+    for (i, share) in fee_split.iter().enumerate() {
+        if fee_split[..i].iter().any(|other| other.recipient == share.recipient) {
+            return Err(ErrorCode::FeeSplitDuplicateRecipient.into());
+        }
+    }
+
+    Ok(())
+}
+
 /// Validate the parameters for creating a Stream.
+#[allow(clippy::too_many_arguments)]
 pub fn check_create(
     deposit_amount: u64,
     start_time: u64,
@@ -48,7 +121,17 @@ pub fn check_create(
     end_time: u64,
     start_unlock_amount: u64,
     cliff_unlock_amount: u64,
+    mint_list_mode: MintListMode,
+    is_mint_listed: bool,
 ) -> Result<()> {
+    // Check: the deposit token mint is allowed by the mint list.
+    match mint_list_mode {
+        MintListMode::Open => {}
+        MintListMode::Allowlist if !is_mint_listed => return Err(ErrorCode::MintNotAllowed.into()),
+        MintListMode::Denylist if is_mint_listed => return Err(ErrorCode::MintNotAllowed.into()),
+        _ => {}
+    }
+
     // Check: the deposit amount is not zero.
     if deposit_amount == 0 {
         return Err(ErrorCode::DepositAmountZero.into());
@@ -101,6 +184,26 @@ pub fn check_renounce(is_cancelable: bool, deposited_amount: u64, streamed_amoun
     Ok(())
 }
 
+/// Validate the unpause of the program.
+pub fn check_unpause(is_paused: bool) -> Result<()> {
+    // Check: the program is paused.
+    if !is_paused {
+        return Err(ErrorCode::ProgramNotPaused.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the unwrapping of the deposited token.
+pub fn check_unwrap(deposited_token_mint: Pubkey) -> Result<()> {
+    // Check: the deposited token is wSOL.
+    if deposited_token_mint != native_mint::ID {
+        return Err(ErrorCode::UnwrapNotSupported.into());
+    }
+
+    Ok(())
+}
+
 /// Validate a withdrawal from a stream.
 pub fn check_withdraw(is_depleted: bool, amount: u64, withdrawable_amount: u64) -> Result<()> {
     // Check: the stream is not depleted.
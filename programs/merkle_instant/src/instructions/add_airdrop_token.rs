@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    state::Campaign,
+    utils::{
+        constants::seeds::{MINT_LIST_ENTRY, TREASURY},
+        events,
+        validations::{check_add_airdrop_token, check_mint_list, check_not_paused},
+    },
+};
+
+#[derive(Accounts)]
+pub struct AddAirdropToken<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the campaign creator who pays for the campaign's ATA.
+    #[account(
+      mut,
+      address = campaign.creator,
+    )]
+    pub campaign_creator: Signer<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              SABLIER ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the treasury account storing the pause flag and the mint list mode, which may not be initialized
+    /// yet.
+    /// CHECK: The treasury is deserialized in the handler, if it has been initialized.
+    #[account(
+      seeds = [TREASURY],
+      bump
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              CAMPAIGN ACCOUNTS                             //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the mint account of the airdrop token to add.
+    #[account(mint::token_program = airdrop_token_program)]
+    pub airdrop_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Read account: the mint list entry of the airdrop token to add, which exists only if the mint is listed.
+    /// CHECK: The account is only checked for existence, as only this program can create data at this address.
+    #[account(
+      seeds = [MINT_LIST_ENTRY, airdrop_token_mint.key().as_ref()],
+      bump
+    )]
+    pub mint_list_entry: UncheckedAccount<'info>,
+
+    /// Write account: the account storing the campaign data.
+    #[account(mut)]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    /// Create account: the campaign's ATA for the airdrop token to add.
+    #[account(
+      init,
+      payer = campaign_creator,
+      associated_token::mint = airdrop_token_mint,
+      associated_token::authority = campaign,
+      associated_token::token_program = airdrop_token_program
+    )]
+    pub campaign_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              PROGRAM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the Token program of the airdrop token to add.
+    pub airdrop_token_program: Interface<'info, TokenInterface>,
+
+    /// Program account: the Associated Token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    // -------------------------------------------------------------------------- //
+    //                               SYSTEM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::add_airdrop_token`].
+pub fn handler(ctx: Context<AddAirdropToken>) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let airdrop_token_mint = ctx.accounts.airdrop_token_mint.key();
+
+    // Check: the program is not paused.
+    check_not_paused(&ctx.accounts.treasury)?;
+
+    // Check: the airdrop token is allowed by the mint list.
+    check_mint_list(&ctx.accounts.treasury, &ctx.accounts.mint_list_entry)?;
+
+    // Check: validate the addition of the airdrop token.
+    check_add_airdrop_token(
+        &campaign.additional_airdrop_token_mints,
+        campaign.airdrop_token_mint,
+        campaign.campaign_start_time,
+        campaign.claim_mode,
+        campaign.is_native,
+        campaign.leaf_format,
+        airdrop_token_mint,
+        campaign.token_claim_fee,
+    )?;
+
+    // Effect: add the airdrop token to the campaign.
+    campaign.add_airdrop_token(airdrop_token_mint)?;
+
+    // Log the addition of the airdrop token.
+    emit!(events::AddAirdropToken {
+        campaign: campaign.key(),
+        campaign_creator: ctx.accounts.campaign_creator.key(),
+        token_mint: airdrop_token_mint,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    instructions::close_campaign::{close_additional_campaign_atas, close_claim_bitmaps},
+    state::{Campaign, CampaignTombstone},
+    utils::{
+        constants::{seeds::CAMPAIGN_TOMBSTONE, ANCHOR_DISCRIMINATOR_SIZE},
+        errors::ErrorCode,
+        events,
+        transfer_helper::{close_token_account, transfer_tokens},
+        validations::check_cancel_campaign,
+    },
+};
+
+#[derive(Accounts)]
+pub struct CancelCampaign<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the campaign creator who will receive the refund and the rent of the closed accounts.
+    #[account(
+      mut,
+      address = campaign.creator,
+    )]
+    pub campaign_creator: Signer<'info>,
+
+    /// Create if needed account: the campaign creator's ATA for the main airdrop token.
+    #[account(
+      init_if_needed,
+      payer = campaign_creator,
+      associated_token::mint = airdrop_token_mint,
+      associated_token::authority = campaign_creator,
+      associated_token::token_program = airdrop_token_program
+    )]
+    pub campaign_creator_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              CAMPAIGN ACCOUNTS                             //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the mint account of the main airdrop token.
+    #[account(address = campaign.airdrop_token_mint)]
+    pub airdrop_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Write account: the account storing the campaign data, closed at the end of the instruction.
+    #[account(
+      mut,
+      close = campaign_creator,
+      constraint = !campaign.is_native @ ErrorCode::CampaignIsNative,
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    /// Write account: the campaign's ATA for the main airdrop token, swept and closed during the instruction.
+    #[account(
+      mut,
+      associated_token::mint = airdrop_token_mint,
+      associated_token::authority = campaign,
+      associated_token::token_program = airdrop_token_program
+    )]
+    pub campaign_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Create account: the tombstone retiring the campaign address, so that no campaign can be created at it again.
+    #[account(
+      init,
+      payer = campaign_creator,
+      space = ANCHOR_DISCRIMINATOR_SIZE + CampaignTombstone::INIT_SPACE,
+      seeds = [CAMPAIGN_TOMBSTONE, campaign.key().as_ref()],
+      bump,
+    )]
+    pub campaign_tombstone: Box<Account<'info, CampaignTombstone>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              PROGRAM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the Token program of the main airdrop token.
+    pub airdrop_token_program: Interface<'info, TokenInterface>,
+
+    /// Program account: the Associated Token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    // -------------------------------------------------------------------------- //
+    //                               SYSTEM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::cancel_campaign`].
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CancelCampaign<'info>>) -> Result<()> {
+    let campaign = ctx.accounts.campaign.clone();
+    let airdrop_token_mint = ctx.accounts.airdrop_token_mint.clone();
+    let campaign_creator = ctx.accounts.campaign_creator.to_account_info();
+    let campaign_seeds = campaign.signer_seeds();
+    let campaign_seeds: Vec<&[u8]> = campaign_seeds.iter().map(Vec::as_slice).collect();
+    let signer_seeds: &[&[&[u8]]] = &[&campaign_seeds];
+
+    // Check: validate the campaign cancellation.
+    check_cancel_campaign(campaign.campaign_start_time, campaign.claim_count, campaign.first_claim_time)?;
+
+    // Check: the campaign's ATA and its Token program are provided for each additional airdrop token.
+    let additional_accounts_count = campaign.additional_airdrop_token_mints.len() * 2;
+    if ctx.remaining_accounts.len() < additional_accounts_count {
+        return Err(ErrorCode::InvalidRemainingAccounts.into());
+    }
+    let (additional_accounts, claim_bitmaps) = ctx.remaining_accounts.split_at(additional_accounts_count);
+
+    // Interaction: close the campaign's ATAs for the additional airdrop tokens.
+    close_additional_campaign_atas(&campaign, additional_accounts, campaign_creator.clone(), signer_seeds)?;
+
+    // Effect: close the claim bitmaps of the campaign, if any.
+    close_claim_bitmaps(campaign.key(), claim_bitmaps, campaign_creator.clone())?;
+
+    // Interaction: refund the full balance of the campaign's ATA to the campaign creator.
+    let refunded_amount = ctx.accounts.campaign_ata.amount;
+    if refunded_amount > 0 {
+        transfer_tokens(
+            ctx.accounts.campaign_ata.to_account_info(),
+            ctx.accounts.campaign_creator_ata.to_account_info(),
+            campaign.to_account_info(),
+            airdrop_token_mint.to_account_info(),
+            ctx.accounts.airdrop_token_program.to_account_info(),
+            refunded_amount,
+            airdrop_token_mint.decimals,
+            signer_seeds,
+        )?;
+    }
+
+    // Interaction: close the campaign's ATA and refund its rent to the campaign creator.
+    close_token_account(
+        ctx.accounts.campaign_ata.to_account_info(),
+        campaign_creator.clone(),
+        campaign.to_account_info(),
+        ctx.accounts.airdrop_token_program.to_account_info(),
+        signer_seeds,
+    )?;
+
+    // Effect: retire the campaign address.
+    ctx.accounts.campaign_tombstone.create(ctx.bumps.campaign_tombstone)?;
+
+    // Log the campaign cancellation.
+    emit!(events::CampaignCanceled {
+        campaign: campaign.key(),
+        campaign_creator: campaign_creator.key(),
+        closed_claim_bitmaps: claim_bitmaps.len() as u32,
+        refunded_amount,
+        token_mint: airdrop_token_mint.key(),
+    });
+
+    Ok(())
+}
@@ -1,20 +1,15 @@
-use anchor_lang::{
-    prelude::*,
-    solana_program::{program::invoke, system_instruction::transfer},
-};
+use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 use crate::{
-    state::{Campaign, ClaimReceipt, Treasury},
+    state::{Campaign, ClaimBitmap, ClaimReceipt, Treasury},
     utils::{
-        constants::{seeds::*, ANCHOR_DISCRIMINATOR_SIZE, CLAIM_FEE_USD},
-        events,
-        fee_calculation::convert_usd_fee_to_lamports,
-        transfer_helper::transfer_tokens,
-        validations::check_claim,
+        claim_helper::{claim_core, ClaimAccounts},
+        constants::{seeds::*, ANCHOR_DISCRIMINATOR_SIZE, CLAIMS_PER_BITMAP},
+        errors::ErrorCode,
     },
 };
 
@@ -49,10 +44,22 @@ pub struct Claim<'info> {
     #[account(
       mut,
       seeds = [TREASURY],
-      bump = treasury.bump
+      bump = treasury.bump,
+      constraint = !treasury.is_paused @ ErrorCode::ProgramIsPaused
     )]
     pub treasury: Box<Account<'info, Treasury>>,
 
+    /// Create if needed account: the treasury's ATA for the airdrop token, required if the campaign charges the claim
+    /// fee in the airdrop token.
+    #[account(
+      init_if_needed,
+      payer = claimer,
+      associated_token::mint = airdrop_token_mint,
+      associated_token::authority = treasury,
+      associated_token::token_program = airdrop_token_program
+    )]
+    pub treasury_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     // -------------------------------------------------------------------------- //
     //                              CAMPAIGN ACCOUNTS                             //
     // -------------------------------------------------------------------------- //
@@ -61,7 +68,11 @@ pub struct Claim<'info> {
     pub airdrop_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     /// Write account: the account storing the campaign data.
-    #[account(mut)]
+    #[account(
+      mut,
+      constraint = campaign.additional_airdrop_token_mints.is_empty() @ ErrorCode::MultiTokenClaimRequired,
+      constraint = !campaign.is_native @ ErrorCode::CampaignIsNative,
+    )]
     pub campaign: Box<Account<'info, Campaign>>,
 
     /// Write account: the campaign's ATA for the airdrop token.
@@ -73,7 +84,7 @@ pub struct Claim<'info> {
     )]
     pub campaign_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// Create account: the claim receipt.
+    /// Create account: the claim receipt, required if the campaign tracks claims with receipts.
     #[account(
       init,
       payer = claimer,
@@ -85,7 +96,19 @@ pub struct Claim<'info> {
       ],
       bump
     )]
-    pub claim_receipt: Box<Account<'info, ClaimReceipt>>,
+    pub claim_receipt: Option<Box<Account<'info, ClaimReceipt>>>,
+
+    /// Write account: the claim bitmap covering the index, required if the campaign tracks claims in bitmaps.
+    #[account(
+      mut,
+      seeds = [
+        CLAIM_BITMAP,
+        campaign.key().as_ref(),
+        (index / CLAIMS_PER_BITMAP).to_le_bytes().as_ref(),
+      ],
+      bump
+    )]
+    pub claim_bitmap: Option<AccountLoader<'info, ClaimBitmap>>,
 
     // -------------------------------------------------------------------------- //
     //                              PROGRAM ACCOUNTS                              //
@@ -115,82 +138,29 @@ pub struct Claim<'info> {
 
 /// See the documentation for [`fn@crate::sablier_merkle_instant::claim`].
 pub fn handler(ctx: Context<Claim>, index: u32, amount: u64, merkle_proof: Vec<[u8; 32]>) -> Result<()> {
-    let campaign = ctx.accounts.campaign.clone();
-    let airdrop_token_mint = ctx.accounts.airdrop_token_mint.clone();
-    let claimer = ctx.accounts.claimer.clone();
-    let recipient = ctx.accounts.recipient.clone();
-
-    // Check: validate the claim.
-    check_claim(
-        amount,
-        campaign.campaign_start_time,
-        campaign.expiration_time,
+    let accounts = ctx.accounts;
+
+    // Claim the airdrop to the recipient's ATA.
+    claim_core(
+        ClaimAccounts {
+            airdrop_token_mint: &accounts.airdrop_token_mint,
+            airdrop_token_program: accounts.airdrop_token_program.to_account_info(),
+            campaign: &mut accounts.campaign,
+            campaign_ata: accounts.campaign_ata.to_account_info(),
+            chainlink_program: accounts.chainlink_program.to_account_info(),
+            chainlink_sol_usd_feed: accounts.chainlink_sol_usd_feed.to_account_info(),
+            claim_bitmap: &accounts.claim_bitmap,
+            claim_receipt: &mut accounts.claim_receipt,
+            claimer: accounts.claimer.to_account_info(),
+            destination: accounts.recipient.key(),
+            destination_ata: accounts.recipient_ata.to_account_info(),
+            recipient: accounts.recipient.key(),
+            treasury: accounts.treasury.to_account_info(),
+            treasury_ata: accounts.treasury_ata.as_ref().map(|treasury_ata| treasury_ata.to_account_info()),
+        },
         index,
-        merkle_proof,
-        campaign.merkle_root,
-        recipient.key(),
-    )?;
-
-    ctx.accounts.campaign.claim()?;
-
-    // Interaction: charge the claim fee.
-    let fee_in_lamports = charge_claim_fee(
-        ctx.accounts.chainlink_program.to_account_info(),
-        ctx.accounts.chainlink_sol_usd_feed.to_account_info(),
-        ctx.accounts.claimer.to_account_info(),
-        ctx.accounts.treasury.to_account_info(),
-    )?;
-
-    // Interaction: transfer tokens from the campaign's ATA to the recipient's ATA.
-    transfer_tokens(
-        ctx.accounts.campaign_ata.to_account_info(),
-        ctx.accounts.recipient_ata.to_account_info(),
-        campaign.to_account_info(),
-        airdrop_token_mint.to_account_info(),
-        ctx.accounts.airdrop_token_program.to_account_info(),
-        amount,
-        airdrop_token_mint.decimals,
-        &[&[
-            CAMPAIGN,
-            campaign.creator.key().as_ref(),
-            campaign.merkle_root.as_ref(),
-            campaign.campaign_start_time.to_le_bytes().as_ref(),
-            campaign.expiration_time.to_le_bytes().as_ref(),
-            campaign.name.as_ref(),
-            airdrop_token_mint.key().as_ref(),
-            &[campaign.bump],
-        ]],
-    )?;
-
-    // Log the claim.
-    emit!(events::Claim {
         amount,
-        campaign: campaign.key(),
-        claimer: claimer.key(),
-        claim_receipt: ctx.accounts.claim_receipt.key(),
-        fee_in_lamports,
-        index,
-        recipient: recipient.key(),
-    });
-
-    Ok(())
-}
-
-/// Charges the claim fee in lamports.
-fn charge_claim_fee<'info>(
-    chainlink_program: AccountInfo<'info>,
-    chainlink_sol_usd_feed: AccountInfo<'info>,
-    tx_signer: AccountInfo<'info>,
-    treasury: AccountInfo<'info>,
-) -> Result<u64> {
-    // Calculate the fee in lamports.
-    let fee_in_lamports: u64 = convert_usd_fee_to_lamports(CLAIM_FEE_USD, chainlink_program, chainlink_sol_usd_feed);
-
-    if fee_in_lamports > 0 {
-        // Interaction: transfer the fee from the signer to the treasury.
-        let fee_charging_ix = transfer(&tx_signer.key(), &treasury.key(), fee_in_lamports);
-        invoke(&fee_charging_ix, &[tx_signer, treasury])?;
-    }
-
-    Ok(fee_in_lamports)
+        merkle_proof,
+        ctx.remaining_accounts,
+    )
 }
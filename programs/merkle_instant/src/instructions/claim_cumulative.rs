@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    state::{Campaign, ClaimMode, CumulativeClaim, Treasury},
+    utils::{
+        claim_helper::charge_claim_fee,
+        constants::{seeds::*, ANCHOR_DISCRIMINATOR_SIZE},
+        errors::ErrorCode,
+        events,
+        transfer_helper::transfer_tokens,
+        validations::{check_claim, check_claim_cumulative, check_eligibility},
+    },
+};
+
+#[derive(Accounts)]
+pub struct ClaimCumulative<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the signer of the claim who will pay the claim fee.
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    /// Read account: the recipient of the airdrop.
+    /// CHECK: This account is validated during the Merkle proof verification.
+    pub recipient: UncheckedAccount<'info>,
+
+    /// Create if needed account: the ATA for airdrop token owned by the recipient.
+    #[account(
+      init_if_needed,
+      payer = claimer,
+      associated_token::mint = airdrop_token_mint,
+      associated_token::authority = recipient,
+      associated_token::token_program = airdrop_token_program
+    )]
+    pub recipient_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              SABLIER ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the treasury account that will receive the claim fee.
+    #[account(
+      mut,
+      seeds = [TREASURY],
+      bump = treasury.bump,
+      constraint = !treasury.is_paused @ ErrorCode::ProgramIsPaused
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              CAMPAIGN ACCOUNTS                             //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the mint account of the airdrop token.
+    #[account(address = campaign.airdrop_token_mint)]
+    pub airdrop_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Write account: the account storing the campaign data.
+    #[account(
+      mut,
+      constraint = campaign.claim_mode == ClaimMode::Cumulative @ ErrorCode::CumulativeClaimNotSupported,
+      constraint = !campaign.is_native @ ErrorCode::CampaignIsNative,
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    /// Write account: the campaign's ATA for the airdrop token.
+    #[account(
+      mut,
+      associated_token::mint = airdrop_token_mint,
+      associated_token::authority = campaign,
+      associated_token::token_program = airdrop_token_program
+    )]
+    pub campaign_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Create if needed account: the amount claimed so far by the recipient.
+    #[account(
+      init_if_needed,
+      payer = claimer,
+      space = ANCHOR_DISCRIMINATOR_SIZE + CumulativeClaim::INIT_SPACE,
+      seeds = [
+        CUMULATIVE_CLAIM,
+        campaign.key().as_ref(),
+        recipient.key().as_ref(),
+      ],
+      bump
+    )]
+    pub cumulative_claim: Box<Account<'info, CumulativeClaim>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              PROGRAM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the Token program of the airdrop token.
+    pub airdrop_token_program: Interface<'info, TokenInterface>,
+
+    /// Program account: the Associated Token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// Read account: The Chainlink program used to retrieve on-chain price feeds.
+    /// CHECK: This is the Chainlink program.
+    #[account(address = treasury.chainlink_program)]
+    pub chainlink_program: AccountInfo<'info>,
+
+    /// Read account: The account providing the SOL/USD price feed data.
+    /// CHECK: We're reading data from this Chainlink feed.
+    #[account(address = treasury.chainlink_sol_usd_feed)]
+    pub chainlink_sol_usd_feed: AccountInfo<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                               SYSTEM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::claim_cumulative`].
+pub fn handler(
+    ctx: Context<ClaimCumulative>,
+    index: u32,
+    cumulative_amount: u64,
+    merkle_proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let campaign = ctx.accounts.campaign.clone();
+    let airdrop_token_mint = ctx.accounts.airdrop_token_mint.clone();
+    let claimer = ctx.accounts.claimer.clone();
+    let recipient = ctx.accounts.recipient.clone();
+
+    // Check: validate the claim.
+    check_claim(
+        cumulative_amount,
+        campaign.campaign_start_time,
+        campaign.expiration_time,
+        index,
+        campaign.leaf_format,
+        merkle_proof,
+        campaign.merkle_root,
+        recipient.key(),
+    )?;
+
+    // Check: the recipient holds the required tokens, if the campaign is token-gated.
+    if let Some(eligibility_rule) = &campaign.eligibility_rule {
+        check_eligibility(eligibility_rule, recipient.key(), ctx.remaining_accounts)?;
+    }
+
+    // Check: validate the cumulative claim.
+    check_claim_cumulative(ctx.accounts.cumulative_claim.claimed_amount, cumulative_amount)?;
+
+    // The amount to pay out is the increase of the cumulative amount since the last claim.
+    let amount = cumulative_amount - ctx.accounts.cumulative_claim.claimed_amount;
+
+    // Effect: update the amount claimed so far by the recipient.
+    ctx.accounts.cumulative_claim.claim(amount)?;
+
+    // Effect: update the campaign state.
+    ctx.accounts.campaign.claim(amount)?;
+
+    // Interaction: charge the claim fee.
+    let fee_in_lamports = charge_claim_fee(
+        ctx.accounts.chainlink_program.to_account_info(),
+        ctx.accounts.chainlink_sol_usd_feed.to_account_info(),
+        ctx.accounts.claimer.to_account_info(),
+        ctx.accounts.treasury.to_account_info(),
+    )?;
+
+    let campaign_seeds = campaign.signer_seeds();
+    let campaign_seeds: Vec<&[u8]> = campaign_seeds.iter().map(Vec::as_slice).collect();
+    let signer_seeds: &[&[&[u8]]] = &[&campaign_seeds];
+
+    // Interaction: transfer tokens from the campaign's ATA to the recipient's ATA.
+    transfer_tokens(
+        ctx.accounts.campaign_ata.to_account_info(),
+        ctx.accounts.recipient_ata.to_account_info(),
+        campaign.to_account_info(),
+        airdrop_token_mint.to_account_info(),
+        ctx.accounts.airdrop_token_program.to_account_info(),
+        amount,
+        airdrop_token_mint.decimals,
+        signer_seeds,
+    )?;
+
+    // Log the claim.
+    emit!(events::Claim {
+        amount,
+        campaign: campaign.key(),
+        claimer: claimer.key(),
+        claim_receipt: ctx.accounts.cumulative_claim.key(),
+        destination: recipient.key(),
+        fee_in_lamports,
+        fee_in_tokens: 0,
+        index,
+        recipient: recipient.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,279 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{create_idempotent, get_associated_token_address_with_program_id, AssociatedToken, Create},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    state::{Campaign, ClaimBitmap, ClaimReceipt, Treasury},
+    utils::{
+        claim_helper::{charge_claim_fee, record_claim},
+        constants::{seeds::*, ANCHOR_DISCRIMINATOR_SIZE, CLAIMS_PER_BITMAP},
+        errors::ErrorCode,
+        events,
+        transfer_helper::transfer_tokens,
+        validations::{check_claim_multi_token, check_eligibility, eligibility_accounts_count},
+    },
+};
+
+/// The number of remaining accounts expected for each additional airdrop token: the mint, the campaign's ATA, the
+/// recipient's ATA and the Token program.
+const ACCOUNTS_PER_ADDITIONAL_TOKEN: usize = 4;
+
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct ClaimMultiToken<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the signer of the claim who will pay the claim fee.
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    /// Read account: the recipient of the airdrop.
+    /// CHECK: This account is validated during the Merkle proof verification.
+    pub recipient: UncheckedAccount<'info>,
+
+    /// Create if needed account: the ATA for the main airdrop token owned by the recipient.
+    #[account(
+      init_if_needed,
+      payer = claimer,
+      associated_token::mint = airdrop_token_mint,
+      associated_token::authority = recipient,
+      associated_token::token_program = airdrop_token_program
+    )]
+    pub recipient_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              SABLIER ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the treasury account that will receive the claim fee.
+    #[account(
+      mut,
+      seeds = [TREASURY],
+      bump = treasury.bump,
+      constraint = !treasury.is_paused @ ErrorCode::ProgramIsPaused
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              CAMPAIGN ACCOUNTS                             //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the mint account of the main airdrop token.
+    #[account(address = campaign.airdrop_token_mint)]
+    pub airdrop_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Write account: the account storing the campaign data.
+    #[account(
+      mut,
+      constraint = !campaign.is_native @ ErrorCode::CampaignIsNative,
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    /// Write account: the campaign's ATA for the main airdrop token.
+    #[account(
+      mut,
+      associated_token::mint = airdrop_token_mint,
+      associated_token::authority = campaign,
+      associated_token::token_program = airdrop_token_program
+    )]
+    pub campaign_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Create account: the claim receipt, required if the campaign tracks claims with receipts.
+    #[account(
+      init,
+      payer = claimer,
+      space = ANCHOR_DISCRIMINATOR_SIZE + ClaimReceipt::INIT_SPACE,
+      seeds = [
+        CLAIM_RECEIPT,
+        campaign.key().as_ref(),
+        index.to_le_bytes().as_ref(),
+      ],
+      bump
+    )]
+    pub claim_receipt: Option<Box<Account<'info, ClaimReceipt>>>,
+
+    /// Write account: the claim bitmap covering the index, required if the campaign tracks claims in bitmaps.
+    #[account(
+      mut,
+      seeds = [
+        CLAIM_BITMAP,
+        campaign.key().as_ref(),
+        (index / CLAIMS_PER_BITMAP).to_le_bytes().as_ref(),
+      ],
+      bump
+    )]
+    pub claim_bitmap: Option<AccountLoader<'info, ClaimBitmap>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              PROGRAM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the Token program of the main airdrop token.
+    pub airdrop_token_program: Interface<'info, TokenInterface>,
+
+    /// Program account: the Associated Token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// Read account: The Chainlink program used to retrieve on-chain price feeds.
+    /// CHECK: This is the Chainlink program.
+    #[account(address = treasury.chainlink_program)]
+    pub chainlink_program: AccountInfo<'info>,
+
+    /// Read account: The account providing the SOL/USD price feed data.
+    /// CHECK: We're reading data from this Chainlink feed.
+    #[account(address = treasury.chainlink_sol_usd_feed)]
+    pub chainlink_sol_usd_feed: AccountInfo<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                               SYSTEM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::claim_multi_token`].
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimMultiToken<'info>>,
+    index: u32,
+    amounts: Vec<u64>,
+    merkle_proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let campaign = ctx.accounts.campaign.clone();
+    let airdrop_token_mint = ctx.accounts.airdrop_token_mint.clone();
+    let claimer = ctx.accounts.claimer.clone();
+    let recipient = ctx.accounts.recipient.clone();
+    let additional_airdrop_token_mints = &campaign.additional_airdrop_token_mints;
+
+    // Check: validate the claim.
+    check_claim_multi_token(
+        additional_airdrop_token_mints.len() + 1,
+        &amounts,
+        campaign.campaign_start_time,
+        campaign.expiration_time,
+        index,
+        merkle_proof,
+        campaign.merkle_root,
+        recipient.key(),
+    )?;
+
+    // Check: the expected accounts are provided for each additional airdrop token, followed by the accounts required
+    // to check the recipient's eligibility.
+    let additional_token_accounts_count = additional_airdrop_token_mints.len() * ACCOUNTS_PER_ADDITIONAL_TOKEN;
+    if ctx.remaining_accounts.len() !=
+        additional_token_accounts_count + eligibility_accounts_count(campaign.eligibility_rule)
+    {
+        return Err(ErrorCode::InvalidRemainingAccounts.into());
+    }
+    let (additional_token_accounts, eligibility_accounts) =
+        ctx.remaining_accounts.split_at(additional_token_accounts_count);
+
+    // Check: the recipient holds the required tokens, if the campaign is token-gated.
+    if let Some(eligibility_rule) = &campaign.eligibility_rule {
+        check_eligibility(eligibility_rule, recipient.key(), eligibility_accounts)?;
+    }
+
+    // Interaction: charge the claim fee, which is recorded in the claim receipt.
+    let fee_in_lamports = charge_claim_fee(
+        ctx.accounts.chainlink_program.to_account_info(),
+        ctx.accounts.chainlink_sol_usd_feed.to_account_info(),
+        ctx.accounts.claimer.to_account_info(),
+        ctx.accounts.treasury.to_account_info(),
+    )?;
+
+    // Effect: mark the index as claimed.
+    let claim_status = record_claim(
+        amounts[0],
+        campaign.claim_mode,
+        claimer.key(),
+        fee_in_lamports,
+        index,
+        recipient.key(),
+        &mut ctx.accounts.claim_receipt,
+        &ctx.accounts.claim_bitmap,
+    )?;
+
+    // Effect: update the campaign state.
+    ctx.accounts.campaign.claim_multi_token(&amounts)?;
+
+    let campaign_seeds = campaign.signer_seeds();
+    let campaign_seeds: Vec<&[u8]> = campaign_seeds.iter().map(Vec::as_slice).collect();
+    let signer_seeds: &[&[&[u8]]] = &[&campaign_seeds];
+
+    // Interaction: transfer the main airdrop token from the campaign's ATA to the recipient's ATA.
+    transfer_tokens(
+        ctx.accounts.campaign_ata.to_account_info(),
+        ctx.accounts.recipient_ata.to_account_info(),
+        campaign.to_account_info(),
+        airdrop_token_mint.to_account_info(),
+        ctx.accounts.airdrop_token_program.to_account_info(),
+        amounts[0],
+        airdrop_token_mint.decimals,
+        signer_seeds,
+    )?;
+
+    for ((mint_key, amount), accounts) in additional_airdrop_token_mints
+        .iter()
+        .zip(amounts.iter().skip(1))
+        .zip(additional_token_accounts.chunks(ACCOUNTS_PER_ADDITIONAL_TOKEN))
+    {
+        let (mint, campaign_ata, recipient_ata, token_program) =
+            (&accounts[0], &accounts[1], &accounts[2], &accounts[3]);
+
+        // Check: the mint is the additional airdrop token, and the token program is a Token program.
+        let mint = InterfaceAccount::<Mint>::try_from(mint)?;
+        let token_program = Interface::<TokenInterface>::try_from(token_program)?;
+        if mint.key() != *mint_key || mint.to_account_info().owner != &token_program.key() {
+            return Err(ErrorCode::InvalidRemainingAccounts.into());
+        }
+
+        // Check: the ATAs are the ones derived from the campaign and the recipient.
+        let expected_campaign_ata =
+            get_associated_token_address_with_program_id(&campaign.key(), mint_key, &token_program.key());
+        let expected_recipient_ata =
+            get_associated_token_address_with_program_id(&recipient.key(), mint_key, &token_program.key());
+        if campaign_ata.key() != expected_campaign_ata || recipient_ata.key() != expected_recipient_ata {
+            return Err(ErrorCode::InvalidRemainingAccounts.into());
+        }
+
+        // Interaction: create the recipient's ATA if needed.
+        create_idempotent(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            Create {
+                payer: claimer.to_account_info(),
+                associated_token: recipient_ata.clone(),
+                authority: recipient.to_account_info(),
+                mint: mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: token_program.to_account_info(),
+            },
+        ))?;
+
+        // Interaction: transfer the additional airdrop token from the campaign's ATA to the recipient's ATA.
+        transfer_tokens(
+            campaign_ata.clone(),
+            recipient_ata.clone(),
+            campaign.to_account_info(),
+            mint.to_account_info(),
+            token_program.to_account_info(),
+            *amount,
+            mint.decimals,
+            signer_seeds,
+        )?;
+    }
+
+    // Log the claim.
+    let mut token_mints = vec![airdrop_token_mint.key()];
+    token_mints.extend(additional_airdrop_token_mints.iter());
+    emit!(events::ClaimMultiToken {
+        amounts,
+        campaign: campaign.key(),
+        claimer: claimer.key(),
+        claim_receipt: claim_status,
+        fee_in_lamports,
+        index,
+        recipient: recipient.key(),
+        token_mints,
+    });
+
+    Ok(())
+}
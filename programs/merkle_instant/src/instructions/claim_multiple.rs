@@ -0,0 +1,261 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{create_idempotent, get_associated_token_address_with_program_id, AssociatedToken, Create},
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    state::{Campaign, Treasury},
+    utils::{
+        claim_helper::{charge_claim_fee, record_claim_unchecked},
+        constants::seeds::*,
+        errors::ErrorCode,
+        events,
+        transfer_helper::transfer_tokens,
+        validations::{check_claim, check_claim_multiple, check_eligibility, eligibility_accounts_count},
+    },
+};
+
+/// A leaf of the Merkle tree to claim, along with its proof of inclusion.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ClaimLeaf {
+    pub index: u32,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub merkle_proof: Vec<[u8; 32]>,
+}
+
+/// A leaf of the Merkle tree to claim, without its proof of inclusion.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ClaimLeafData {
+    pub index: u32,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+/// The number of remaining accounts expected for each leaf: the claim status account, the recipient and the
+/// recipient's ATA. In token-gated campaigns, they are followed by the accounts required to check the recipient's
+/// eligibility.
+pub const ACCOUNTS_PER_LEAF: usize = 3;
+
+/// Returns the number of remaining accounts expected for each leaf of the given campaign.
+pub fn accounts_per_leaf(campaign: &Campaign) -> usize {
+    ACCOUNTS_PER_LEAF + eligibility_accounts_count(campaign.eligibility_rule)
+}
+
+#[derive(Accounts)]
+pub struct ClaimMultiple<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the signer of the claims who will pay the claim fee.
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              SABLIER ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the treasury account that will receive the claim fee.
+    #[account(
+      mut,
+      seeds = [TREASURY],
+      bump = treasury.bump,
+      constraint = !treasury.is_paused @ ErrorCode::ProgramIsPaused
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              CAMPAIGN ACCOUNTS                             //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the mint account of the airdrop token.
+    #[account(address = campaign.airdrop_token_mint)]
+    pub airdrop_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Write account: the account storing the campaign data.
+    #[account(
+      mut,
+      constraint = campaign.additional_airdrop_token_mints.is_empty() @ ErrorCode::MultiTokenClaimRequired,
+      constraint = campaign.token_claim_fee.is_none() @ ErrorCode::TokenClaimFeeNotSupported,
+      constraint = !campaign.is_native @ ErrorCode::CampaignIsNative,
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    /// Write account: the campaign's ATA for the airdrop token.
+    #[account(
+      mut,
+      associated_token::mint = airdrop_token_mint,
+      associated_token::authority = campaign,
+      associated_token::token_program = airdrop_token_program
+    )]
+    pub campaign_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              PROGRAM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the Token program of the airdrop token.
+    pub airdrop_token_program: Interface<'info, TokenInterface>,
+
+    /// Program account: the Associated Token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// Read account: The Chainlink program used to retrieve on-chain price feeds.
+    /// CHECK: This is the Chainlink program.
+    #[account(address = treasury.chainlink_program)]
+    pub chainlink_program: AccountInfo<'info>,
+
+    /// Read account: The account providing the SOL/USD price feed data.
+    /// CHECK: We're reading data from this Chainlink feed.
+    #[account(address = treasury.chainlink_sol_usd_feed)]
+    pub chainlink_sol_usd_feed: AccountInfo<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                               SYSTEM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::claim_multiple`].
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimMultiple<'info>>, leaves: Vec<ClaimLeaf>) -> Result<()> {
+    let campaign = ctx.accounts.campaign.clone();
+
+    // Check: validate the batch of claims.
+    check_claim_multiple(leaves.len(), ctx.remaining_accounts.len(), accounts_per_leaf(&campaign))?;
+
+    let mut leaves_data = Vec::with_capacity(leaves.len());
+    for leaf in leaves {
+        // Check: validate the claim.
+        check_claim(
+            leaf.amount,
+            campaign.campaign_start_time,
+            campaign.expiration_time,
+            leaf.index,
+            campaign.leaf_format,
+            leaf.merkle_proof,
+            campaign.merkle_root,
+            leaf.recipient,
+        )?;
+
+        leaves_data.push(ClaimLeafData {
+            index: leaf.index,
+            recipient: leaf.recipient,
+            amount: leaf.amount,
+        });
+    }
+
+    claim_leaves(ctx, leaves_data)
+}
+
+/// Claims the already proven leaves, using the remaining accounts provided for each leaf.
+pub fn claim_leaves<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimMultiple<'info>>,
+    leaves: Vec<ClaimLeafData>,
+) -> Result<()> {
+    let campaign = ctx.accounts.campaign.clone();
+    let airdrop_token_mint = ctx.accounts.airdrop_token_mint.clone();
+    let airdrop_token_program = ctx.accounts.airdrop_token_program.to_account_info();
+    let claimer = ctx.accounts.claimer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    // Interaction: charge the claim fee once for the whole batch.
+    let fee_in_lamports = charge_claim_fee(
+        ctx.accounts.chainlink_program.to_account_info(),
+        ctx.accounts.chainlink_sol_usd_feed.to_account_info(),
+        claimer.clone(),
+        ctx.accounts.treasury.to_account_info(),
+    )?;
+
+    let campaign_seeds = campaign.signer_seeds();
+    let campaign_seeds: Vec<&[u8]> = campaign_seeds.iter().map(Vec::as_slice).collect();
+    let signer_seeds: &[&[&[u8]]] = &[&campaign_seeds];
+
+    for (position, (leaf, accounts)) in
+        leaves.into_iter().zip(ctx.remaining_accounts.chunks(accounts_per_leaf(&campaign))).enumerate()
+    {
+        let (claim_status, recipient, recipient_ata) = (&accounts[0], &accounts[1], &accounts[2]);
+
+        // Check: the recipient account matches the leaf.
+        if recipient.key() != leaf.recipient {
+            return Err(ErrorCode::InvalidRemainingAccounts.into());
+        }
+
+        // Check: the recipient holds the required tokens, if the campaign is token-gated.
+        if let Some(eligibility_rule) = &campaign.eligibility_rule {
+            check_eligibility(eligibility_rule, leaf.recipient, &accounts[ACCOUNTS_PER_LEAF..])?;
+        }
+
+        // Check: the recipient's ATA is the one derived from the recipient, the mint and the token program.
+        let expected_recipient_ata = get_associated_token_address_with_program_id(
+            &leaf.recipient,
+            &airdrop_token_mint.key(),
+            &airdrop_token_program.key(),
+        );
+        if recipient_ata.key() != expected_recipient_ata {
+            return Err(ErrorCode::InvalidRemainingAccounts.into());
+        }
+
+        // The fee is reported for the first leaf only, as it is charged once for the whole batch.
+        let leaf_fee_in_lamports = if position == 0 {
+            fee_in_lamports
+        } else {
+            0
+        };
+
+        // Effect: mark the index as claimed.
+        let claim_status = record_claim_unchecked(
+            leaf.amount,
+            campaign.key(),
+            campaign.claim_mode,
+            claim_status,
+            &claimer,
+            &system_program,
+            leaf_fee_in_lamports,
+            leaf.index,
+            leaf.recipient,
+            ctx.program_id,
+        )?;
+
+        // Effect: update the campaign state.
+        ctx.accounts.campaign.claim(leaf.amount)?;
+
+        // Interaction: create the recipient's ATA if needed.
+        create_idempotent(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            Create {
+                payer: claimer.clone(),
+                associated_token: recipient_ata.clone(),
+                authority: recipient.clone(),
+                mint: airdrop_token_mint.to_account_info(),
+                system_program: system_program.clone(),
+                token_program: airdrop_token_program.clone(),
+            },
+        ))?;
+
+        // Interaction: transfer tokens from the campaign's ATA to the recipient's ATA.
+        transfer_tokens(
+            ctx.accounts.campaign_ata.to_account_info(),
+            recipient_ata.clone(),
+            campaign.to_account_info(),
+            airdrop_token_mint.to_account_info(),
+            airdrop_token_program.clone(),
+            leaf.amount,
+            airdrop_token_mint.decimals,
+            signer_seeds,
+        )?;
+
+        // Log the claim.
+        emit!(events::Claim {
+            amount: leaf.amount,
+            campaign: campaign.key(),
+            claimer: claimer.key(),
+            claim_receipt: claim_status,
+            destination: leaf.recipient,
+            fee_in_lamports: leaf_fee_in_lamports,
+            fee_in_tokens: 0,
+            index: leaf.index,
+            recipient: leaf.recipient,
+        });
+    }
+
+    Ok(())
+}
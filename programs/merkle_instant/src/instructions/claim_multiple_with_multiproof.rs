@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use super::claim_multiple::{accounts_per_leaf, claim_leaves, ClaimLeafData, ClaimMultiple};
+use crate::utils::validations::{check_claim_multiple, check_claim_multiproof, hash_leaf};
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::claim_multiple_with_multiproof`].
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimMultiple<'info>>,
+    leaves: Vec<ClaimLeafData>,
+    proof: Vec<[u8; 32]>,
+    proof_flags: Vec<bool>,
+) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+
+    // Check: validate the batch of claims.
+    check_claim_multiple(leaves.len(), ctx.remaining_accounts.len(), accounts_per_leaf(&ctx.accounts.campaign))?;
+
+    // Check: validate the multiproof of the leaves.
+    let leaf_hashes: Vec<[u8; 32]> =
+        leaves.iter().map(|leaf| hash_leaf(campaign.leaf_format, leaf.index, leaf.recipient, leaf.amount)).collect();
+    check_claim_multiproof(
+        campaign.campaign_start_time,
+        campaign.expiration_time,
+        campaign.leaf_format,
+        &leaf_hashes,
+        campaign.merkle_root,
+        &proof,
+        &proof_flags,
+    )?;
+
+    claim_leaves(ctx, leaves)
+}
@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    instructions::collect_fees::safe_collectible_amount,
+    state::{Campaign, ClaimBitmap, ClaimReceipt, Treasury},
+    utils::{
+        claim_helper::{charge_claim_fee, record_claim},
+        constants::{seeds::*, ANCHOR_DISCRIMINATOR_SIZE, CLAIMS_PER_BITMAP},
+        errors::ErrorCode,
+        events,
+        validations::{check_claim, check_native_transfer},
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct ClaimNative<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the signer of the claim who will pay the claim fee.
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    /// Write account: the recipient of the airdrop, who receives the SOL.
+    /// CHECK: This account is validated during the Merkle proof verification.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              SABLIER ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the treasury account that will receive the claim fee.
+    #[account(
+      mut,
+      seeds = [TREASURY],
+      bump = treasury.bump,
+      constraint = !treasury.is_paused @ ErrorCode::ProgramIsPaused
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              CAMPAIGN ACCOUNTS                             //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the account storing the campaign data, which also holds the airdropped SOL.
+    #[account(
+      mut,
+      constraint = campaign.is_native @ ErrorCode::CampaignNotNative,
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    /// Create account: the claim receipt, required if the campaign tracks claims with receipts.
+    #[account(
+      init,
+      payer = claimer,
+      space = ANCHOR_DISCRIMINATOR_SIZE + ClaimReceipt::INIT_SPACE,
+      seeds = [
+        CLAIM_RECEIPT,
+        campaign.key().as_ref(),
+        index.to_le_bytes().as_ref(),
+      ],
+      bump
+    )]
+    pub claim_receipt: Option<Box<Account<'info, ClaimReceipt>>>,
+
+    /// Write account: the claim bitmap covering the index, required if the campaign tracks claims in bitmaps.
+    #[account(
+      mut,
+      seeds = [
+        CLAIM_BITMAP,
+        campaign.key().as_ref(),
+        (index / CLAIMS_PER_BITMAP).to_le_bytes().as_ref(),
+      ],
+      bump
+    )]
+    pub claim_bitmap: Option<AccountLoader<'info, ClaimBitmap>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              PROGRAM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Read account: The Chainlink program used to retrieve on-chain price feeds.
+    /// CHECK: This is the Chainlink program.
+    #[account(address = treasury.chainlink_program)]
+    pub chainlink_program: AccountInfo<'info>,
+
+    /// Read account: The account providing the SOL/USD price feed data.
+    /// CHECK: We're reading data from this Chainlink feed.
+    #[account(address = treasury.chainlink_sol_usd_feed)]
+    pub chainlink_sol_usd_feed: AccountInfo<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                               SYSTEM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::claim_native`].
+pub fn handler(ctx: Context<ClaimNative>, index: u32, amount: u64, merkle_proof: Vec<[u8; 32]>) -> Result<()> {
+    let campaign = ctx.accounts.campaign.clone();
+    let claimer = ctx.accounts.claimer.clone();
+    let recipient = ctx.accounts.recipient.clone();
+
+    // Check: validate the claim.
+    check_claim(
+        amount,
+        campaign.campaign_start_time,
+        campaign.expiration_time,
+        index,
+        campaign.leaf_format,
+        merkle_proof,
+        campaign.merkle_root,
+        recipient.key(),
+    )?;
+
+    // Check: the campaign holds enough SOL above its rent-exempt minimum.
+    check_native_transfer(amount, safe_collectible_amount(&campaign.to_account_info())?)?;
+
+    // Interaction: charge the claim fee, which is recorded in the claim receipt.
+    let fee_in_lamports = charge_claim_fee(
+        ctx.accounts.chainlink_program.to_account_info(),
+        ctx.accounts.chainlink_sol_usd_feed.to_account_info(),
+        ctx.accounts.claimer.to_account_info(),
+        ctx.accounts.treasury.to_account_info(),
+    )?;
+
+    // Effect: mark the index as claimed.
+    let claim_status = record_claim(
+        amount,
+        campaign.claim_mode,
+        claimer.key(),
+        fee_in_lamports,
+        index,
+        recipient.key(),
+        &mut ctx.accounts.claim_receipt,
+        &ctx.accounts.claim_bitmap,
+    )?;
+
+    // Effect: update the campaign state.
+    ctx.accounts.campaign.claim(amount)?;
+
+    // Interaction: transfer the SOL from the campaign to the recipient.
+    ctx.accounts.campaign.sub_lamports(amount)?;
+    ctx.accounts.recipient.add_lamports(amount)?;
+
+    // Log the claim.
+    emit!(events::Claim {
+        amount,
+        campaign: campaign.key(),
+        claimer: claimer.key(),
+        claim_receipt: claim_status,
+        destination: recipient.key(),
+        fee_in_lamports,
+        fee_in_tokens: 0,
+        index,
+        recipient: recipient.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,180 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    state::{Campaign, ClaimBitmap, ClaimReceipt, ProofBuffer, Treasury},
+    utils::{
+        claim_helper::{claim_core, ClaimAccounts},
+        constants::{seeds::*, ANCHOR_DISCRIMINATOR_SIZE, CLAIMS_PER_BITMAP},
+        errors::ErrorCode,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct ClaimWithProofAccount<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the signer of the claim who will pay the claim fee.
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    /// Read account: the recipient of the airdrop.
+    /// CHECK: This account is validated during the Merkle proof verification.
+    pub recipient: UncheckedAccount<'info>,
+
+    /// Create if needed account: the ATA for airdrop token owned by the recipient.
+    #[account(
+      init_if_needed,
+      payer = claimer,
+      associated_token::mint = airdrop_token_mint,
+      associated_token::authority = recipient,
+      associated_token::token_program = airdrop_token_program
+    )]
+    pub recipient_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              SABLIER ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the treasury account that will receive the claim fee.
+    #[account(
+      mut,
+      seeds = [TREASURY],
+      bump = treasury.bump,
+      constraint = !treasury.is_paused @ ErrorCode::ProgramIsPaused
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    /// Create if needed account: the treasury's ATA for the airdrop token, required if the campaign charges the claim
+    /// fee in the airdrop token.
+    #[account(
+      init_if_needed,
+      payer = claimer,
+      associated_token::mint = airdrop_token_mint,
+      associated_token::authority = treasury,
+      associated_token::token_program = airdrop_token_program
+    )]
+    pub treasury_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              CAMPAIGN ACCOUNTS                             //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the mint account of the airdrop token.
+    #[account(address = campaign.airdrop_token_mint)]
+    pub airdrop_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Write account: the account storing the campaign data.
+    #[account(
+      mut,
+      constraint = campaign.additional_airdrop_token_mints.is_empty() @ ErrorCode::MultiTokenClaimRequired,
+      constraint = !campaign.is_native @ ErrorCode::CampaignIsNative,
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    /// Write account: the campaign's ATA for the airdrop token.
+    #[account(
+      mut,
+      associated_token::mint = airdrop_token_mint,
+      associated_token::authority = campaign,
+      associated_token::token_program = airdrop_token_program
+    )]
+    pub campaign_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Create account: the claim receipt, required if the campaign tracks claims with receipts.
+    #[account(
+      init,
+      payer = claimer,
+      space = ANCHOR_DISCRIMINATOR_SIZE + ClaimReceipt::INIT_SPACE,
+      seeds = [
+        CLAIM_RECEIPT,
+        campaign.key().as_ref(),
+        index.to_le_bytes().as_ref(),
+      ],
+      bump
+    )]
+    pub claim_receipt: Option<Box<Account<'info, ClaimReceipt>>>,
+
+    /// Write account: the proof buffer storing the Merkle proof, closed at the end of the instruction.
+    #[account(
+      mut,
+      close = claimer,
+      seeds = [
+        PROOF_BUFFER,
+        campaign.key().as_ref(),
+        claimer.key().as_ref(),
+      ],
+      bump
+    )]
+    pub proof_buffer: Box<Account<'info, ProofBuffer>>,
+
+    /// Write account: the claim bitmap covering the index, required if the campaign tracks claims in bitmaps.
+    #[account(
+      mut,
+      seeds = [
+        CLAIM_BITMAP,
+        campaign.key().as_ref(),
+        (index / CLAIMS_PER_BITMAP).to_le_bytes().as_ref(),
+      ],
+      bump
+    )]
+    pub claim_bitmap: Option<AccountLoader<'info, ClaimBitmap>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              PROGRAM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the Token program of the airdrop token.
+    pub airdrop_token_program: Interface<'info, TokenInterface>,
+
+    /// Program account: the Associated Token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// Read account: The Chainlink program used to retrieve on-chain price feeds.
+    /// CHECK: This is the Chainlink program.
+    #[account(address = treasury.chainlink_program)]
+    pub chainlink_program: AccountInfo<'info>,
+
+    /// Read account: The account providing the SOL/USD price feed data.
+    /// CHECK: We're reading data from this Chainlink feed.
+    #[account(address = treasury.chainlink_sol_usd_feed)]
+    pub chainlink_sol_usd_feed: AccountInfo<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                               SYSTEM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::claim_with_proof_account`].
+pub fn handler(ctx: Context<ClaimWithProofAccount>, index: u32, amount: u64) -> Result<()> {
+    let accounts = ctx.accounts;
+    let merkle_proof = accounts.proof_buffer.proof.clone();
+
+    // Claim the airdrop to the recipient's ATA, with the Merkle proof read from the proof buffer.
+    claim_core(
+        ClaimAccounts {
+            airdrop_token_mint: &accounts.airdrop_token_mint,
+            airdrop_token_program: accounts.airdrop_token_program.to_account_info(),
+            campaign: &mut accounts.campaign,
+            campaign_ata: accounts.campaign_ata.to_account_info(),
+            chainlink_program: accounts.chainlink_program.to_account_info(),
+            chainlink_sol_usd_feed: accounts.chainlink_sol_usd_feed.to_account_info(),
+            claim_bitmap: &accounts.claim_bitmap,
+            claim_receipt: &mut accounts.claim_receipt,
+            claimer: accounts.claimer.to_account_info(),
+            destination: accounts.recipient.key(),
+            destination_ata: accounts.recipient_ata.to_account_info(),
+            recipient: accounts.recipient.key(),
+            treasury: accounts.treasury.to_account_info(),
+            treasury_ata: accounts.treasury_ata.as_ref().map(|treasury_ata| treasury_ata.to_account_info()),
+        },
+        index,
+        amount,
+        merkle_proof,
+        ctx.remaining_accounts,
+    )
+}
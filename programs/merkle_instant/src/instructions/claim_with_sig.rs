@@ -0,0 +1,203 @@
+use anchor_lang::{prelude::*, solana_program::sysvar::instructions as instructions_sysvar};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    state::{Campaign, ClaimBitmap, ClaimReceipt, Treasury},
+    utils::{
+        claim_helper::{claim_core, ClaimAccounts},
+        constants::{seeds::*, ANCHOR_DISCRIMINATOR_SIZE, CLAIMS_PER_BITMAP},
+        ed25519::verify_ed25519_signature,
+        errors::ErrorCode,
+        validations::check_claim_signature_expiry,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct ClaimWithSig<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the signer of the claim, e.g. a relayer, who will pay the claim fee.
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    /// Read account: the recipient of the airdrop, who signed the claim authorization.
+    /// CHECK: This account is validated during the Merkle proof and signature verifications.
+    pub recipient: UncheckedAccount<'info>,
+
+    /// Read account: the destination of the airdrop chosen by the recipient.
+    /// CHECK: This account is validated during the signature verification.
+    pub destination: UncheckedAccount<'info>,
+
+    /// Create if needed account: the ATA for airdrop token owned by the destination.
+    #[account(
+      init_if_needed,
+      payer = claimer,
+      associated_token::mint = airdrop_token_mint,
+      associated_token::authority = destination,
+      associated_token::token_program = airdrop_token_program
+    )]
+    pub destination_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              SABLIER ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the treasury account that will receive the claim fee.
+    #[account(
+      mut,
+      seeds = [TREASURY],
+      bump = treasury.bump,
+      constraint = !treasury.is_paused @ ErrorCode::ProgramIsPaused
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    /// Create if needed account: the treasury's ATA for the airdrop token, required if the campaign charges the claim
+    /// fee in the airdrop token.
+    #[account(
+      init_if_needed,
+      payer = claimer,
+      associated_token::mint = airdrop_token_mint,
+      associated_token::authority = treasury,
+      associated_token::token_program = airdrop_token_program
+    )]
+    pub treasury_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              CAMPAIGN ACCOUNTS                             //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the mint account of the airdrop token.
+    #[account(address = campaign.airdrop_token_mint)]
+    pub airdrop_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Write account: the account storing the campaign data.
+    #[account(
+      mut,
+      constraint = campaign.additional_airdrop_token_mints.is_empty() @ ErrorCode::MultiTokenClaimRequired,
+      constraint = !campaign.is_native @ ErrorCode::CampaignIsNative,
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    /// Write account: the campaign's ATA for the airdrop token.
+    #[account(
+      mut,
+      associated_token::mint = airdrop_token_mint,
+      associated_token::authority = campaign,
+      associated_token::token_program = airdrop_token_program
+    )]
+    pub campaign_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Create account: the claim receipt, required if the campaign tracks claims with receipts.
+    #[account(
+      init,
+      payer = claimer,
+      space = ANCHOR_DISCRIMINATOR_SIZE + ClaimReceipt::INIT_SPACE,
+      seeds = [
+        CLAIM_RECEIPT,
+        campaign.key().as_ref(),
+        index.to_le_bytes().as_ref(),
+      ],
+      bump
+    )]
+    pub claim_receipt: Option<Box<Account<'info, ClaimReceipt>>>,
+
+    /// Write account: the claim bitmap covering the index, required if the campaign tracks claims in bitmaps.
+    #[account(
+      mut,
+      seeds = [
+        CLAIM_BITMAP,
+        campaign.key().as_ref(),
+        (index / CLAIMS_PER_BITMAP).to_le_bytes().as_ref(),
+      ],
+      bump
+    )]
+    pub claim_bitmap: Option<AccountLoader<'info, ClaimBitmap>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              PROGRAM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the Token program of the airdrop token.
+    pub airdrop_token_program: Interface<'info, TokenInterface>,
+
+    /// Program account: the Associated Token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// Read account: The Chainlink program used to retrieve on-chain price feeds.
+    /// CHECK: This is the Chainlink program.
+    #[account(address = treasury.chainlink_program)]
+    pub chainlink_program: AccountInfo<'info>,
+
+    /// Read account: The account providing the SOL/USD price feed data.
+    /// CHECK: We're reading data from this Chainlink feed.
+    #[account(address = treasury.chainlink_sol_usd_feed)]
+    pub chainlink_sol_usd_feed: AccountInfo<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                               SYSTEM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the Instructions sysvar, used to introspect the ed25519 signature verification.
+    /// CHECK: The address is checked against the Instructions sysvar ID.
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Program account: the System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::claim_with_sig`].
+pub fn handler(
+    ctx: Context<ClaimWithSig>,
+    index: u32,
+    amount: u64,
+    merkle_proof: Vec<[u8; 32]>,
+    signature_expiry: u64,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+
+    // Check: the recipient's signature has not expired.
+    check_claim_signature_expiry(signature_expiry)?;
+
+    // Check: the recipient has signed the claim authorization.
+    let message = claim_message(accounts.campaign.key(), index, amount, accounts.destination.key(), signature_expiry);
+    verify_ed25519_signature(&accounts.instructions_sysvar, &accounts.recipient.key(), &message)?;
+
+    // Claim the airdrop to the destination's ATA.
+    claim_core(
+        ClaimAccounts {
+            airdrop_token_mint: &accounts.airdrop_token_mint,
+            airdrop_token_program: accounts.airdrop_token_program.to_account_info(),
+            campaign: &mut accounts.campaign,
+            campaign_ata: accounts.campaign_ata.to_account_info(),
+            chainlink_program: accounts.chainlink_program.to_account_info(),
+            chainlink_sol_usd_feed: accounts.chainlink_sol_usd_feed.to_account_info(),
+            claim_bitmap: &accounts.claim_bitmap,
+            claim_receipt: &mut accounts.claim_receipt,
+            claimer: accounts.claimer.to_account_info(),
+            destination: accounts.destination.key(),
+            destination_ata: accounts.destination_ata.to_account_info(),
+            recipient: accounts.recipient.key(),
+            treasury: accounts.treasury.to_account_info(),
+            treasury_ata: accounts.treasury_ata.as_ref().map(|treasury_ata| treasury_ata.to_account_info()),
+        },
+        index,
+        amount,
+        merkle_proof,
+        ctx.remaining_accounts,
+    )
+}
+
+/// Builds the message signed by the recipient to authorize the claim:
+/// `campaign (32 bytes) || index (4 bytes LE) || amount (8 bytes LE) || destination (32 bytes) || expiry (8 bytes LE)`.
+fn claim_message(campaign: Pubkey, index: u32, amount: u64, destination: Pubkey, signature_expiry: u64) -> Vec<u8> {
+    [
+        campaign.as_ref(),
+        &index.to_le_bytes(),
+        &amount.to_le_bytes(),
+        destination.as_ref(),
+        &signature_expiry.to_le_bytes(),
+    ]
+    .concat()
+}
@@ -6,7 +6,7 @@ use anchor_spl::{
 
 use crate::{
     state::Campaign,
-    utils::{constants::seeds::CAMPAIGN, events, transfer_helper::transfer_tokens, validations::check_clawback},
+    utils::{errors::ErrorCode, events, transfer_helper::transfer_tokens, validations::check_clawback},
 };
 
 #[derive(Accounts)]
@@ -38,11 +38,19 @@ pub struct Clawback<'info> {
     // -------------------------------------------------------------------------- //
     //                              CAMPAIGN ACCOUNTS                             //
     // -------------------------------------------------------------------------- //
-    /// Read account: the mint account of the airdrop token.
-    #[account(address = campaign.airdrop_token_mint)]
+    /// Read account: the mint account of the airdrop token to claw back, which can be any token of the campaign.
+    #[account(
+      constraint = campaign.airdrop_token_mint == airdrop_token_mint.key()
+        || campaign.additional_airdrop_token_mints.contains(&airdrop_token_mint.key())
+        @ ErrorCode::InvalidAirdropToken,
+    )]
     pub airdrop_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    /// Read account: the account storing the campaign data.
+    /// Write account: the account storing the campaign data.
+    #[account(
+      mut,
+      constraint = !campaign.is_native @ ErrorCode::CampaignIsNative,
+    )]
     pub campaign: Box<Account<'info, Campaign>>,
 
     /// Write account: the campaign's ATA for the airdrop token.
@@ -76,7 +84,16 @@ pub fn handler(ctx: Context<Clawback>, amount: u64) -> Result<()> {
     let airdrop_token_mint = ctx.accounts.airdrop_token_mint.clone();
 
     // Check: validate the clawback.
-    check_clawback(campaign.expiration_time, campaign.first_claim_time)?;
+    check_clawback(campaign.expiration_time, campaign.first_claim_time, campaign.grace_period)?;
+
+    // Effect: update the campaign state. The statistics only account for the main airdrop token.
+    if airdrop_token_mint.key() == campaign.airdrop_token_mint {
+        ctx.accounts.campaign.clawback(amount)?;
+    }
+
+    let campaign_seeds = campaign.signer_seeds();
+    let campaign_seeds: Vec<&[u8]> = campaign_seeds.iter().map(Vec::as_slice).collect();
+    let signer_seeds: &[&[&[u8]]] = &[&campaign_seeds];
 
     // Interaction: transfer tokens from the Campaign's ATA to the clawback recipient's ATA.
     transfer_tokens(
@@ -87,16 +104,7 @@ pub fn handler(ctx: Context<Clawback>, amount: u64) -> Result<()> {
         ctx.accounts.airdrop_token_program.to_account_info(),
         amount,
         airdrop_token_mint.decimals,
-        &[&[
-            CAMPAIGN,
-            campaign.creator.key().as_ref(),
-            campaign.merkle_root.as_ref(),
-            campaign.campaign_start_time.to_le_bytes().as_ref(),
-            campaign.expiration_time.to_le_bytes().as_ref(),
-            campaign.name.as_ref(),
-            airdrop_token_mint.key().as_ref(),
-            &[campaign.bump],
-        ]],
+        signer_seeds,
     )?;
 
     // Log the clawback.
@@ -104,7 +112,8 @@ pub fn handler(ctx: Context<Clawback>, amount: u64) -> Result<()> {
         amount,
         campaign: campaign.key(),
         campaign_creator: ctx.accounts.campaign_creator.key(),
-        clawback_recipient: ctx.accounts.clawback_recipient.key()
+        clawback_recipient: ctx.accounts.clawback_recipient.key(),
+        token_mint: airdrop_token_mint.key(),
     });
 
     Ok(())
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    instructions::collect_fees::safe_collectible_amount,
+    state::Campaign,
+    utils::{
+        errors::ErrorCode,
+        events,
+        validations::{check_clawback, check_native_transfer},
+    },
+};
+
+#[derive(Accounts)]
+pub struct ClawbackNative<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the campaign creator who will claw back the SOL.
+    #[account(address = campaign.creator)]
+    pub campaign_creator: Signer<'info>,
+
+    /// Write account: the clawback recipient.
+    /// CHECK: This can be any address.
+    #[account(mut)]
+    pub clawback_recipient: UncheckedAccount<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              CAMPAIGN ACCOUNTS                             //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the account storing the campaign data, which also holds the airdropped SOL.
+    #[account(
+      mut,
+      constraint = campaign.is_native @ ErrorCode::CampaignNotNative,
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::clawback_native`].
+pub fn handler(ctx: Context<ClawbackNative>, amount: u64) -> Result<()> {
+    let campaign = ctx.accounts.campaign.clone();
+
+    // Check: validate the clawback.
+    check_clawback(campaign.expiration_time, campaign.first_claim_time, campaign.grace_period)?;
+
+    // Check: the campaign holds enough SOL above its rent-exempt minimum.
+    check_native_transfer(amount, safe_collectible_amount(&campaign.to_account_info())?)?;
+
+    // Effect: update the campaign state.
+    ctx.accounts.campaign.clawback(amount)?;
+
+    // Interaction: transfer the SOL from the campaign to the clawback recipient.
+    ctx.accounts.campaign.sub_lamports(amount)?;
+    ctx.accounts.clawback_recipient.add_lamports(amount)?;
+
+    // Log the clawback.
+    emit!(events::Clawback {
+        amount,
+        campaign: campaign.key(),
+        campaign_creator: ctx.accounts.campaign_creator.key(),
+        clawback_recipient: ctx.accounts.clawback_recipient.key(),
+        token_mint: campaign.airdrop_token_mint,
+    });
+
+    Ok(())
+}
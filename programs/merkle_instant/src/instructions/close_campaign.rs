@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    state::{Campaign, CampaignTombstone, ClaimBitmap},
+    utils::{
+        constants::{seeds::CAMPAIGN_TOMBSTONE, ANCHOR_DISCRIMINATOR_SIZE},
+        errors::ErrorCode,
+        events,
+        transfer_helper::close_token_account,
+        validations::{check_campaign_ata_swept, check_close_campaign},
+    },
+};
+
+#[derive(Accounts)]
+pub struct CloseCampaign<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the campaign creator who will receive the rent of the closed accounts.
+    #[account(
+      mut,
+      address = campaign.creator,
+    )]
+    pub campaign_creator: Signer<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              CAMPAIGN ACCOUNTS                             //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the mint account of the main airdrop token.
+    #[account(address = campaign.airdrop_token_mint)]
+    pub airdrop_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Write account: the account storing the campaign data, closed at the end of the instruction.
+    #[account(
+      mut,
+      close = campaign_creator,
+      constraint = !campaign.is_native @ ErrorCode::CampaignIsNative,
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    /// Write account: the campaign's ATA for the main airdrop token, closed during the instruction.
+    #[account(
+      mut,
+      associated_token::mint = airdrop_token_mint,
+      associated_token::authority = campaign,
+      associated_token::token_program = airdrop_token_program
+    )]
+    pub campaign_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Create account: the tombstone retiring the campaign address, so that no campaign can be created at it again.
+    #[account(
+      init,
+      payer = campaign_creator,
+      space = ANCHOR_DISCRIMINATOR_SIZE + CampaignTombstone::INIT_SPACE,
+      seeds = [CAMPAIGN_TOMBSTONE, campaign.key().as_ref()],
+      bump,
+    )]
+    pub campaign_tombstone: Box<Account<'info, CampaignTombstone>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              PROGRAM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the Token program of the main airdrop token.
+    pub airdrop_token_program: Interface<'info, TokenInterface>,
+
+    // -------------------------------------------------------------------------- //
+    //                               SYSTEM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::close_campaign`].
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CloseCampaign<'info>>) -> Result<()> {
+    let campaign = ctx.accounts.campaign.clone();
+    let campaign_creator = ctx.accounts.campaign_creator.to_account_info();
+    let campaign_seeds = campaign.signer_seeds();
+    let campaign_seeds: Vec<&[u8]> = campaign_seeds.iter().map(Vec::as_slice).collect();
+    let signer_seeds: &[&[&[u8]]] = &[&campaign_seeds];
+
+    // Check: validate the campaign closure.
+    check_close_campaign(ctx.accounts.campaign_ata.amount, campaign.expiration_time)?;
+
+    // Check: the campaign's ATA and its Token program are provided for each additional airdrop token.
+    let additional_accounts_count = campaign.additional_airdrop_token_mints.len() * 2;
+    if ctx.remaining_accounts.len() < additional_accounts_count {
+        return Err(ErrorCode::InvalidRemainingAccounts.into());
+    }
+    let (additional_accounts, claim_bitmaps) = ctx.remaining_accounts.split_at(additional_accounts_count);
+
+    // Interaction: close the campaign's ATAs for the additional airdrop tokens.
+    close_additional_campaign_atas(&campaign, additional_accounts, campaign_creator.clone(), signer_seeds)?;
+
+    // Effect: close the claim bitmaps of the campaign, if any.
+    close_claim_bitmaps(campaign.key(), claim_bitmaps, campaign_creator.clone())?;
+
+    // Interaction: close the campaign's ATA and refund its rent to the campaign creator.
+    close_token_account(
+        ctx.accounts.campaign_ata.to_account_info(),
+        campaign_creator.clone(),
+        campaign.to_account_info(),
+        ctx.accounts.airdrop_token_program.to_account_info(),
+        signer_seeds,
+    )?;
+
+    // Effect: retire the campaign address.
+    ctx.accounts.campaign_tombstone.create(ctx.bumps.campaign_tombstone)?;
+
+    // Log the campaign closure.
+    emit!(events::CloseCampaign {
+        campaign: campaign.key(),
+        campaign_creator: campaign_creator.key(),
+        closed_claim_bitmaps: claim_bitmaps.len() as u32,
+    });
+
+    Ok(())
+}
+
+/// Closes the campaign's ATAs for the additional airdrop tokens, which must have been swept beforehand, and refunds
+/// their rent to the campaign creator. The accounts are expected in pairs of the campaign's ATA and the Token program.
+pub fn close_additional_campaign_atas<'info>(
+    campaign: &Account<'info, Campaign>,
+    additional_accounts: &'info [AccountInfo<'info>],
+    campaign_creator: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    for (mint, accounts) in campaign.additional_airdrop_token_mints.iter().zip(additional_accounts.chunks(2)) {
+        let campaign_ata = InterfaceAccount::<TokenAccount>::try_from(&accounts[0])?;
+        let token_program = Interface::<TokenInterface>::try_from(&accounts[1])?;
+
+        // Check: the ATA is the one derived from the campaign, the mint and the token program.
+        let expected_campaign_ata =
+            get_associated_token_address_with_program_id(&campaign.key(), mint, &token_program.key());
+        if campaign_ata.key() != expected_campaign_ata {
+            return Err(ErrorCode::InvalidRemainingAccounts.into());
+        }
+
+        // Check: the ATA has been swept.
+        check_campaign_ata_swept(campaign_ata.amount)?;
+
+        close_token_account(
+            campaign_ata.to_account_info(),
+            campaign_creator.clone(),
+            campaign.to_account_info(),
+            token_program.to_account_info(),
+            signer_seeds,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Closes the claim bitmaps of a campaign and refunds their rent to the campaign creator.
+pub fn close_claim_bitmaps<'info>(
+    campaign: Pubkey,
+    claim_bitmaps: &'info [AccountInfo<'info>],
+    campaign_creator: AccountInfo<'info>,
+) -> Result<()> {
+    for account in claim_bitmaps.iter() {
+        let claim_bitmap = AccountLoader::<ClaimBitmap>::try_from(account)?;
+
+        // Check: the claim bitmap belongs to the campaign.
+        if claim_bitmap.load()?.campaign != campaign {
+            return Err(ErrorCode::InvalidClaimBitmap.into());
+        }
+
+        claim_bitmap.close(campaign_creator.clone())?;
+    }
+
+    Ok(())
+}
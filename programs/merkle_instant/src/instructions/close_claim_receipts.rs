@@ -0,0 +1,93 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+    state::ClaimReceipt,
+    utils::{
+        constants::seeds::{CLAIM_RECEIPT, TREASURY},
+        errors::ErrorCode,
+        events,
+        validations::check_close_claim_receipts,
+    },
+};
+
+#[derive(Accounts)]
+pub struct CloseClaimReceipts<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the transaction signer.
+    pub signer: Signer<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              CAMPAIGN ACCOUNTS                             //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the address of the closed campaign.
+    /// CHECK: The campaign must have been closed, which is validated in the handler.
+    pub campaign: UncheckedAccount<'info>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::close_claim_receipts`].
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CloseClaimReceipts<'info>>, indexes: Vec<u32>) -> Result<()> {
+    let campaign = ctx.accounts.campaign.key();
+
+    // Check: validate the closure of the claim receipts.
+    check_close_claim_receipts(ctx.accounts.campaign.data_is_empty(), indexes.len(), ctx.remaining_accounts.len())?;
+
+    // The legacy claim receipts do not store the claimer, so their rent is refunded to the treasury instead.
+    let (treasury, _) = Pubkey::find_program_address(&[TREASURY], ctx.program_id);
+
+    for (index, accounts) in indexes.iter().zip(ctx.remaining_accounts.chunks(2)) {
+        let (claim_receipt_info, claimer) = (&accounts[0], &accounts[1]);
+
+        // Check: the claim receipt is the one derived from the campaign and the index.
+        let (expected_claim_receipt, _) =
+            Pubkey::find_program_address(&[CLAIM_RECEIPT, campaign.as_ref(), &index.to_le_bytes()], ctx.program_id);
+        if claim_receipt_info.key() != expected_claim_receipt {
+            return Err(ErrorCode::InvalidClaimReceipt.into());
+        }
+
+        if ClaimReceipt::is_legacy(claim_receipt_info)? {
+            // Check: the rent of the legacy claim receipt is refunded to the treasury.
+            if claimer.key() != treasury {
+                return Err(ErrorCode::InvalidClaimer.into());
+            }
+
+            // Effect: close the legacy claim receipt and refund its rent to the treasury.
+            close_legacy_claim_receipt(claim_receipt_info, claimer)?;
+            continue;
+        }
+
+        let claim_receipt = Account::<ClaimReceipt>::try_from(claim_receipt_info)?;
+
+        // Check: the rent is refunded to the claimer who paid for the claim receipt.
+        if claim_receipt.claimer != claimer.key() {
+            return Err(ErrorCode::InvalidClaimer.into());
+        }
+
+        // Effect: close the claim receipt and refund its rent to the claimer.
+        claim_receipt.close(claimer.clone())?;
+    }
+
+    // Log the closure of the claim receipts.
+    emit!(events::CloseClaimReceipts {
+        campaign,
+        indexes
+    });
+
+    Ok(())
+}
+
+/// Closes a legacy claim receipt, which cannot be deserialized into [`ClaimReceipt`], and refunds its rent.
+fn close_legacy_claim_receipt<'info>(
+    claim_receipt: &AccountInfo<'info>,
+    sol_destination: &AccountInfo<'info>,
+) -> Result<()> {
+    let lamports = claim_receipt.lamports();
+    claim_receipt.sub_lamports(lamports)?;
+    sol_destination.add_lamports(lamports)?;
+
+    claim_receipt.assign(&system_program::ID);
+    claim_receipt.realloc(0, false)?;
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    instructions::close_campaign::close_claim_bitmaps,
+    state::{Campaign, CampaignTombstone},
+    utils::{
+        constants::{seeds::CAMPAIGN_TOMBSTONE, ANCHOR_DISCRIMINATOR_SIZE},
+        errors::ErrorCode,
+        events,
+        validations::check_close_native_campaign,
+    },
+};
+
+#[derive(Accounts)]
+pub struct CloseNativeCampaign<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the campaign creator who will receive the rent of the closed accounts.
+    #[account(
+      mut,
+      address = campaign.creator,
+    )]
+    pub campaign_creator: Signer<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              CAMPAIGN ACCOUNTS                             //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the account storing the campaign data, closed at the end of the instruction.
+    #[account(
+      mut,
+      close = campaign_creator,
+      constraint = campaign.is_native @ ErrorCode::CampaignNotNative,
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    /// Create account: the tombstone retiring the campaign address, so that no campaign can be created at it again.
+    #[account(
+      init,
+      payer = campaign_creator,
+      space = ANCHOR_DISCRIMINATOR_SIZE + CampaignTombstone::INIT_SPACE,
+      seeds = [CAMPAIGN_TOMBSTONE, campaign.key().as_ref()],
+      bump,
+    )]
+    pub campaign_tombstone: Box<Account<'info, CampaignTombstone>>,
+
+    // -------------------------------------------------------------------------- //
+    //                               SYSTEM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::close_native_campaign`].
+pub fn handler<'info>(ctx: Context<'_, '_, 'info, 'info, CloseNativeCampaign<'info>>) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    let campaign_creator = ctx.accounts.campaign_creator.to_account_info();
+
+    // Check: validate the campaign closure.
+    check_close_native_campaign(campaign.expiration_time)?;
+
+    // Effect: close the claim bitmaps of the campaign, if any.
+    close_claim_bitmaps(campaign.key(), ctx.remaining_accounts, campaign_creator.clone())?;
+
+    // Effect: retire the campaign address.
+    ctx.accounts.campaign_tombstone.create(ctx.bumps.campaign_tombstone)?;
+
+    // Log the campaign closure.
+    emit!(events::CloseCampaign {
+        campaign: campaign.key(),
+        campaign_creator: campaign_creator.key(),
+        closed_claim_bitmaps: ctx.remaining_accounts.len() as u32,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::ProofBuffer, utils::constants::seeds::PROOF_BUFFER};
+
+#[derive(Accounts)]
+pub struct CloseProofBuffer<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the claimer who paid for the proof buffer and receives its rent.
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              CAMPAIGN ACCOUNTS                             //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the campaign for which the proof buffer has been written.
+    /// CHECK: The campaign may have been closed, so this account is only used to derive the proof buffer address.
+    pub campaign: UncheckedAccount<'info>,
+
+    /// Write account: the proof buffer of the claimer for the campaign, closed at the end of the instruction.
+    #[account(
+      mut,
+      close = claimer,
+      seeds = [
+        PROOF_BUFFER,
+        campaign.key().as_ref(),
+        claimer.key().as_ref(),
+      ],
+      bump
+    )]
+    pub proof_buffer: Box<Account<'info, ProofBuffer>>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::close_proof_buffer`].
+pub fn handler(_ctx: Context<CloseProofBuffer>) -> Result<()> {
+    // Effect: the proof buffer is closed by Anchor via the `close` constraint.
+    Ok(())
+}
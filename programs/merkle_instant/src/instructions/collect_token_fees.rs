@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    state::Treasury,
+    utils::{constants::seeds::TREASURY, events, transfer_helper::transfer_tokens, validations::check_collect_fees},
+};
+
+#[derive(Accounts)]
+pub struct CollectTokenFees<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the account authorized to collect fees from the treasury, who pays for the fee recipient's ATA.
+    #[account(
+      mut,
+      address = treasury.fee_collector,
+    )]
+    pub fee_collector: Signer<'info>,
+
+    /// Read account: the address that will receive the collected fees.
+    /// CHECK: May be any account
+    pub fee_recipient: UncheckedAccount<'info>,
+
+    /// Create if needed account: the fee recipient's ATA for the token.
+    #[account(
+      init_if_needed,
+      payer = fee_collector,
+      associated_token::mint = token_mint,
+      associated_token::authority = fee_recipient,
+      associated_token::token_program = token_program
+    )]
+    pub fee_recipient_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              SABLIER ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the mint account of the token to collect.
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Read account: the treasury account that owns the token fees.
+    #[account(
+      seeds = [TREASURY],
+      bump = treasury.bump,
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+
+    /// Write account: the treasury's ATA for the token, which holds the fees.
+    #[account(
+      mut,
+      associated_token::mint = token_mint,
+      associated_token::authority = treasury,
+      associated_token::token_program = token_program
+    )]
+    pub treasury_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              PROGRAM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the Token program of the token to collect.
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Program account: the Associated Token program.
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    // -------------------------------------------------------------------------- //
+    //                               SYSTEM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::collect_token_fees`].
+pub fn handler(ctx: Context<CollectTokenFees>) -> Result<()> {
+    let token_mint = ctx.accounts.token_mint.clone();
+
+    // The full balance of the treasury's ATA is collectable, as its rent is held in lamports.
+    let collectible_amount = ctx.accounts.treasury_ata.amount;
+
+    // Check: validate the collectable amount.
+    check_collect_fees(collectible_amount)?;
+
+    // Interaction: transfer the collectable amount from the treasury's ATA to the fee recipient's ATA.
+    transfer_tokens(
+        ctx.accounts.treasury_ata.to_account_info(),
+        ctx.accounts.fee_recipient_ata.to_account_info(),
+        ctx.accounts.treasury.to_account_info(),
+        token_mint.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        collectible_amount,
+        token_mint.decimals,
+        &[&[TREASURY, &[ctx.accounts.treasury.bump]]],
+    )?;
+
+    // Log the token fee collection.
+    emit!(events::TokenFeesCollected {
+        fee_amount: collectible_amount,
+        fee_collector: ctx.accounts.fee_collector.key(),
+        fee_recipient: ctx.accounts.fee_recipient.key(),
+        token_mint: token_mint.key(),
+    });
+
+    Ok(())
+}
@@ -5,18 +5,23 @@ use anchor_spl::{
 };
 
 use crate::{
-    state::Campaign,
+    state::{Campaign, ClaimMode, EligibilityRule, LeafFormat, TokenClaimFee},
     utils::{
-        constants::{seeds::CAMPAIGN, ANCHOR_DISCRIMINATOR_SIZE},
+        constants::{
+            seeds::{CAMPAIGN, CAMPAIGN_TOMBSTONE, MINT_LIST_ENTRY, TREASURY},
+            ANCHOR_DISCRIMINATOR_SIZE,
+        },
+        errors::ErrorCode,
         events,
+        validations::{check_create_campaign, check_mint_list, check_not_paused},
     },
 };
 
 #[derive(Accounts)]
 #[instruction(
-    merkle_root: [u8; 32],
+    _merkle_root: [u8; 32],
     campaign_start_time: u64,
-    expiration_time: u64,
+    _expiration_time: u64,
     name: String,
 )]
 pub struct CreateCampaign<'info> {
@@ -27,6 +32,18 @@ pub struct CreateCampaign<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
 
+    // -------------------------------------------------------------------------- //
+    //                              SABLIER ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the treasury account storing the pause flag and the mint list mode, which may not be initialized
+    /// yet.
+    /// CHECK: The treasury is deserialized in the handler, if it has been initialized.
+    #[account(
+      seeds = [TREASURY],
+      bump
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
     // -------------------------------------------------------------------------- //
     //                              CAMPAIGN ACCOUNTS                             //
     // -------------------------------------------------------------------------- //
@@ -34,6 +51,14 @@ pub struct CreateCampaign<'info> {
     #[account(mint::token_program = airdrop_token_program)]
     pub airdrop_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
+    /// Read account: the mint list entry of the airdrop token, which exists only if the mint is listed.
+    /// CHECK: The account is only checked for existence, as only this program can create data at this address.
+    #[account(
+      seeds = [MINT_LIST_ENTRY, airdrop_token_mint.key().as_ref()],
+      bump
+    )]
+    pub mint_list_entry: UncheckedAccount<'info>,
+
     /// Create account: the account storing the campaign data.
     #[account(
       init,
@@ -42,9 +67,7 @@ pub struct CreateCampaign<'info> {
       seeds = [
         CAMPAIGN,
         creator.key().as_ref(),
-        merkle_root.as_ref(),
         campaign_start_time.to_le_bytes().as_ref(),
-        expiration_time.to_le_bytes().as_ref(),
         name.as_ref(),
         airdrop_token_mint.key().as_ref(),
      ],
@@ -62,6 +85,15 @@ pub struct CreateCampaign<'info> {
     )]
     pub campaign_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    /// Read account: the tombstone left by the closure of a previous campaign at the same address, if any.
+    /// CHECK: The account is only checked for existence, as only this program can create data at this address.
+    #[account(
+      seeds = [CAMPAIGN_TOMBSTONE, campaign.key().as_ref()],
+      bump,
+      constraint = campaign_tombstone.data_is_empty() @ ErrorCode::CampaignAddressRetired,
+    )]
+    pub campaign_tombstone: UncheckedAccount<'info>,
+
     // -------------------------------------------------------------------------- //
     //                              PROGRAM ACCOUNTS                              //
     // -------------------------------------------------------------------------- //
@@ -89,17 +121,38 @@ pub fn handler(
     ipfs_cid: String,
     aggregate_amount: u64,
     recipient_count: u32,
+    claim_mode: ClaimMode,
+    grace_period: u64,
+    leaf_format: LeafFormat,
+    eligibility_rule: Option<EligibilityRule>,
+    token_claim_fee: Option<TokenClaimFee>,
 ) -> Result<()> {
+    // Check: the program is not paused.
+    check_not_paused(&ctx.accounts.treasury)?;
+
+    // Check: the airdrop token is allowed by the mint list.
+    check_mint_list(&ctx.accounts.treasury, &ctx.accounts.mint_list_entry)?;
+
+    // Check: validate the campaign creation.
+    check_create_campaign(claim_mode, grace_period, false, leaf_format, token_claim_fee)?;
+
     // Effect: Initialize the campaign account.
     ctx.accounts.campaign.create(
+        aggregate_amount,
         ctx.accounts.airdrop_token_mint.key(),
         ctx.bumps.campaign,
         campaign_start_time,
+        claim_mode,
         ctx.accounts.creator.key(),
+        eligibility_rule,
         expiration_time,
+        grace_period,
         ipfs_cid.clone(),
+        false,
+        leaf_format,
         merkle_root,
         name.clone(),
+        token_claim_fee,
     )?;
 
     // Log the campaign creation.
@@ -108,11 +161,17 @@ pub fn handler(
         campaign: ctx.accounts.campaign.key(),
         campaign_name: name,
         campaign_start_time,
+        claim_mode,
         creator: ctx.accounts.creator.key(),
+        eligibility_rule,
         expiration_time,
+        grace_period,
         ipfs_cid,
+        is_native: false,
+        leaf_format,
         merkle_root,
         recipient_count,
+        token_claim_fee,
         token_decimals: ctx.accounts.airdrop_token_mint.decimals,
         token_mint: ctx.accounts.airdrop_token_mint.key(),
     });
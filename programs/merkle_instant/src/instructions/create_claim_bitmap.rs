@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Campaign, ClaimBitmap, ClaimMode},
+    utils::{
+        constants::{seeds::CLAIM_BITMAP, ANCHOR_DISCRIMINATOR_SIZE},
+        errors::ErrorCode,
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(chunk_index: u32)]
+pub struct CreateClaimBitmap<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the account paying for the claim bitmap, which can be anyone, e.g. a claimer whose index is not
+    /// covered by an allocated claim bitmap yet.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              CAMPAIGN ACCOUNTS                             //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the account storing the campaign data.
+    #[account(constraint = campaign.claim_mode == ClaimMode::Bitmap @ ErrorCode::ClaimBitmapNotSupported)]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    /// Create account: the claim bitmap covering the given chunk of indexes.
+    #[account(
+      init,
+      payer = payer,
+      space = ANCHOR_DISCRIMINATOR_SIZE + std::mem::size_of::<ClaimBitmap>(),
+      seeds = [
+        CLAIM_BITMAP,
+        campaign.key().as_ref(),
+        chunk_index.to_le_bytes().as_ref(),
+      ],
+      bump
+    )]
+    pub claim_bitmap: AccountLoader<'info, ClaimBitmap>,
+
+    // -------------------------------------------------------------------------- //
+    //                               SYSTEM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::create_claim_bitmap`].
+pub fn handler(ctx: Context<CreateClaimBitmap>, _chunk_index: u32) -> Result<()> {
+    // Effect: initialize the claim bitmap.
+    ctx.accounts.claim_bitmap.load_init()?.create(ctx.accounts.campaign.key())?;
+
+    Ok(())
+}
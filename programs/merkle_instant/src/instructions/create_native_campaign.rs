@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+
+use crate::{
+    state::{Campaign, ClaimMode, LeafFormat},
+    utils::{
+        constants::{
+            seeds::{CAMPAIGN, CAMPAIGN_TOMBSTONE, TREASURY},
+            ANCHOR_DISCRIMINATOR_SIZE,
+        },
+        errors::ErrorCode,
+        events,
+        validations::{check_create_campaign, check_not_paused},
+    },
+};
+
+#[derive(Accounts)]
+#[instruction(
+    _merkle_root: [u8; 32],
+    campaign_start_time: u64,
+    _expiration_time: u64,
+    name: String,
+)]
+pub struct CreateNativeCampaign<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the creator of the campaign.
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              SABLIER ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the treasury account storing the pause flag, which may not be initialized yet.
+    /// CHECK: The treasury is deserialized in the handler, if it has been initialized.
+    #[account(
+      seeds = [TREASURY],
+      bump
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              CAMPAIGN ACCOUNTS                             //
+    // -------------------------------------------------------------------------- //
+    /// Create account: the account storing the campaign data, which also holds the airdropped SOL.
+    #[account(
+      init,
+      payer = creator,
+      space = ANCHOR_DISCRIMINATOR_SIZE + Campaign::INIT_SPACE,
+      seeds = [
+        CAMPAIGN,
+        creator.key().as_ref(),
+        campaign_start_time.to_le_bytes().as_ref(),
+        name.as_ref(),
+        native_mint::ID.as_ref(),
+     ],
+     bump,
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    /// Read account: the tombstone left by the closure of a previous campaign at the same address, if any.
+    /// CHECK: The account is only checked for existence, as only this program can create data at this address.
+    #[account(
+      seeds = [CAMPAIGN_TOMBSTONE, campaign.key().as_ref()],
+      bump,
+      constraint = campaign_tombstone.data_is_empty() @ ErrorCode::CampaignAddressRetired,
+    )]
+    pub campaign_tombstone: UncheckedAccount<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                               SYSTEM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::create_native_campaign`].
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<CreateNativeCampaign>,
+    merkle_root: [u8; 32],
+    campaign_start_time: u64,
+    expiration_time: u64,
+    name: String,
+    ipfs_cid: String,
+    aggregate_amount: u64,
+    recipient_count: u32,
+    claim_mode: ClaimMode,
+    grace_period: u64,
+    leaf_format: LeafFormat,
+) -> Result<()> {
+    // Check: the program is not paused.
+    check_not_paused(&ctx.accounts.treasury)?;
+
+    // Check: validate the campaign creation.
+    check_create_campaign(claim_mode, grace_period, true, leaf_format, None)?;
+
+    // Effect: Initialize the campaign account, using the native mint as the airdrop token.
+    ctx.accounts.campaign.create(
+        aggregate_amount,
+        native_mint::ID,
+        ctx.bumps.campaign,
+        campaign_start_time,
+        claim_mode,
+        ctx.accounts.creator.key(),
+        None,
+        expiration_time,
+        grace_period,
+        ipfs_cid.clone(),
+        true,
+        leaf_format,
+        merkle_root,
+        name.clone(),
+        None,
+    )?;
+
+    // Log the campaign creation.
+    emit!(events::CreateCampaign {
+        aggregate_amount,
+        campaign: ctx.accounts.campaign.key(),
+        campaign_name: name,
+        campaign_start_time,
+        claim_mode,
+        creator: ctx.accounts.creator.key(),
+        eligibility_rule: None,
+        expiration_time,
+        grace_period,
+        ipfs_cid,
+        is_native: true,
+        leaf_format,
+        merkle_root,
+        recipient_count,
+        token_claim_fee: None,
+        token_decimals: native_mint::DECIMALS,
+        token_mint: native_mint::ID,
+    });
+
+    Ok(())
+}
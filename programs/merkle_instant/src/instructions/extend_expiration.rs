@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::Campaign,
+    utils::{events, validations::check_extend_expiration},
+};
+
+#[derive(Accounts)]
+pub struct ExtendExpiration<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the campaign creator who will update the expiration time.
+    #[account(address = campaign.creator)]
+    pub campaign_creator: Signer<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              CAMPAIGN ACCOUNTS                             //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the account storing the campaign data.
+    #[account(mut)]
+    pub campaign: Box<Account<'info, Campaign>>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::extend_expiration`].
+pub fn handler(ctx: Context<ExtendExpiration>, new_expiration_time: u64) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let old_expiration_time = campaign.expiration_time;
+
+    // Check: validate the expiration time update.
+    check_extend_expiration(old_expiration_time, new_expiration_time)?;
+
+    // Effect: update the expiration time.
+    campaign.extend_expiration(new_expiration_time)?;
+
+    // Log the expiration time update.
+    emit!(events::CampaignExpirationExtended {
+        campaign: campaign.key(),
+        campaign_creator: ctx.accounts.campaign_creator.key(),
+        new_expiration_time,
+        old_expiration_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction::transfer},
+};
+
+use crate::{
+    state::{Campaign, LegacyCampaign},
+    utils::{
+        constants::{ANCHOR_DISCRIMINATOR_SIZE, LEGACY_CAMPAIGN_SIZE},
+        validations::check_migrate_campaign,
+    },
+};
+
+#[derive(Accounts)]
+pub struct MigrateCampaign<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the signer paying for the rent of the reallocated campaign.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              CAMPAIGN ACCOUNTS                             //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the campaign account, in the layout prior to the decoupling of its address from the Merkle root
+    /// and the expiration time.
+    /// CHECK: The discriminator and the size are validated in the handler.
+    #[account(
+      mut,
+      owner = crate::ID
+    )]
+    pub campaign: UncheckedAccount<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                               SYSTEM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::migrate_campaign`].
+pub fn handler(ctx: Context<MigrateCampaign>) -> Result<()> {
+    let signer = ctx.accounts.signer.to_account_info();
+    let campaign = ctx.accounts.campaign.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    // Check: validate the migration.
+    check_migrate_campaign(&campaign.try_borrow_data()?)?;
+
+    let legacy_campaign = LegacyCampaign::deserialize(&mut &campaign.try_borrow_data()?[ANCHOR_DISCRIMINATOR_SIZE..])?;
+
+    // Interaction: transfer the additional rent from the signer to the campaign.
+    let space = ANCHOR_DISCRIMINATOR_SIZE + Campaign::INIT_SPACE;
+    let rent = Rent::get()?;
+    let top_up = rent.minimum_balance(space).saturating_sub(rent.minimum_balance(LEGACY_CAMPAIGN_SIZE));
+    invoke(&transfer(signer.key, campaign.key, top_up), &[signer, campaign.clone(), system_program])?;
+
+    // Effect: grow the campaign and write it in the current layout, keeping the seeds of its address.
+    campaign.realloc(space, true)?;
+    Campaign::from(legacy_campaign).try_serialize(&mut &mut campaign.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction::transfer},
+};
+
+use crate::{
+    state::Treasury,
+    utils::{
+        constants::{seeds::TREASURY, ANCHOR_DISCRIMINATOR_SIZE, LEGACY_TREASURY_SIZE},
+        validations::check_migrate_treasury,
+    },
+};
+
+#[derive(Accounts)]
+pub struct MigrateTreasury<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the signer paying for the rent of the reallocated treasury.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              SABLIER ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the treasury account, in the layout prior to the fee split, the pause and the mint list.
+    /// CHECK: The discriminator and the size are validated in the handler.
+    #[account(
+      mut,
+      seeds = [TREASURY],
+      bump
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                               SYSTEM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::migrate_treasury`].
+pub fn handler(ctx: Context<MigrateTreasury>) -> Result<()> {
+    let signer = ctx.accounts.signer.to_account_info();
+    let treasury = ctx.accounts.treasury.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+
+    // Check: validate the migration.
+    check_migrate_treasury(&treasury.try_borrow_data()?)?;
+
+    // Interaction: transfer the additional rent from the signer to the treasury, so that the collectible fees are left
+    // untouched.
+    let space = ANCHOR_DISCRIMINATOR_SIZE + Treasury::INIT_SPACE;
+    let rent = Rent::get()?;
+    let top_up = rent.minimum_balance(space).saturating_sub(rent.minimum_balance(LEGACY_TREASURY_SIZE));
+    invoke(&transfer(signer.key, treasury.key, top_up), &[signer, treasury.clone(), system_program])?;
+
+    // Effect: grow the treasury. The new fields are zero-initialized, i.e. an empty fee split, an unpaused program and
+    // an open mint list.
+    treasury.realloc(space, true)?;
+
+    Ok(())
+}
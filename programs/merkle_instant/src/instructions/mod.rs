@@ -1,14 +1,70 @@
 #![allow(ambiguous_glob_reexports)]
+pub mod add_airdrop_token;
+pub mod add_to_mint_list;
+pub mod cancel_campaign;
 pub mod claim;
+pub mod claim_cumulative;
+pub mod claim_multi_token;
+pub mod claim_multiple;
+pub mod claim_multiple_with_multiproof;
+pub mod claim_native;
+pub mod claim_to;
+pub mod claim_with_proof_account;
+pub mod claim_with_sig;
 pub mod clawback;
+pub mod clawback_native;
+pub mod close_campaign;
+pub mod close_claim_receipts;
+pub mod close_native_campaign;
+pub mod close_proof_buffer;
 pub mod collect_fees;
+pub mod collect_token_fees;
 pub mod create_campaign;
+pub mod create_claim_bitmap;
+pub mod create_native_campaign;
+pub mod extend_expiration;
 pub mod initialize;
+pub mod migrate_campaign;
+pub mod migrate_treasury;
+pub mod pause;
+pub mod remove_from_mint_list;
+pub mod set_fee_split;
+pub mod set_mint_list_mode;
+pub mod unpause;
+pub mod update_merkle_root;
 pub mod view;
+pub mod write_proof_buffer;
 
+pub use add_airdrop_token::*;
+pub use add_to_mint_list::*;
+pub use cancel_campaign::*;
 pub use claim::*;
+pub use claim_cumulative::*;
+pub use claim_multi_token::*;
+pub use claim_multiple::*;
+pub use claim_native::*;
+pub use claim_to::*;
+pub use claim_with_proof_account::*;
+pub use claim_with_sig::*;
 pub use clawback::*;
+pub use clawback_native::*;
+pub use close_campaign::*;
+pub use close_claim_receipts::*;
+pub use close_native_campaign::*;
+pub use close_proof_buffer::*;
 pub use collect_fees::*;
+pub use collect_token_fees::*;
 pub use create_campaign::*;
+pub use create_claim_bitmap::*;
+pub use create_native_campaign::*;
+pub use extend_expiration::*;
 pub use initialize::*;
+pub use migrate_campaign::*;
+pub use migrate_treasury::*;
+pub use pause::*;
+pub use remove_from_mint_list::*;
+pub use set_fee_split::*;
+pub use set_mint_list_mode::*;
+pub use update_merkle_root::*;
 pub use view::*;
+pub use write_proof_buffer::*;
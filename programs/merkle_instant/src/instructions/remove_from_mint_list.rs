@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{MintListEntry, Treasury},
+    utils::{
+        constants::seeds::{MINT_LIST_ENTRY, TREASURY},
+        events,
+    },
+};
+
+#[derive(Accounts)]
+pub struct RemoveFromMintList<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the account authorized to manage the mint list, who receives the rent of the mint list entry.
+    #[account(
+      mut,
+      address = treasury.fee_collector
+    )]
+    pub fee_collector: Signer<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              SABLIER ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the mint to remove from the mint list.
+    /// CHECK: The mint may have been closed, so this account is only used to derive the mint list entry address.
+    pub mint: UncheckedAccount<'info>,
+
+    /// Write account: the mint list entry of the mint, closed at the end of the instruction.
+    #[account(
+      mut,
+      close = fee_collector,
+      seeds = [MINT_LIST_ENTRY, mint.key().as_ref()],
+      bump = mint_list_entry.bump
+    )]
+    pub mint_list_entry: Box<Account<'info, MintListEntry>>,
+
+    /// Read account: the treasury account storing the fee collector.
+    #[account(
+      seeds = [TREASURY],
+      bump = treasury.bump
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::remove_from_mint_list`].
+pub fn handler(ctx: Context<RemoveFromMintList>) -> Result<()> {
+    // Effect: the mint list entry is closed by Anchor via the `close` constraint.
+
+    // Log the removal from the mint list.
+    emit!(events::MintRemovedFromList {
+        fee_collector: ctx.accounts.fee_collector.key(),
+        mint: ctx.accounts.mint.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{FeeShare, Treasury},
+    utils::{constants::seeds::TREASURY, events, validations::check_set_fee_split},
+};
+
+#[derive(Accounts)]
+pub struct SetFeeSplit<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the account authorized to collect fees from the treasury.
+    #[account(address = treasury.fee_collector)]
+    pub fee_collector: Signer<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              SABLIER ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the treasury account storing the fee split.
+    #[account(
+      mut,
+      seeds = [TREASURY],
+      bump = treasury.bump,
+    )]
+    pub treasury: Box<Account<'info, Treasury>>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::set_fee_split`].
+pub fn handler(ctx: Context<SetFeeSplit>, fee_split: Vec<FeeShare>) -> Result<()> {
+    // Check: validate the fee split.
+    check_set_fee_split(&fee_split)?;
+
+    // Effect: store the fee split in the treasury.
+    ctx.accounts.treasury.set_fee_split(fee_split.clone())?;
+
+    // Log the fee split update.
+    emit!(events::FeeSplitSet {
+        fee_collector: ctx.accounts.fee_collector.key(),
+        fee_split,
+    });
+
+    Ok(())
+}
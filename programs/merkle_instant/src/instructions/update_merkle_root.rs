@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::Campaign,
+    utils::{events, validations::check_update_merkle_root},
+};
+
+#[derive(Accounts)]
+pub struct UpdateMerkleRoot<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the campaign creator who will update the Merkle root.
+    #[account(address = campaign.creator)]
+    pub campaign_creator: Signer<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              CAMPAIGN ACCOUNTS                             //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the account storing the campaign data.
+    #[account(mut)]
+    pub campaign: Box<Account<'info, Campaign>>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::update_merkle_root`].
+pub fn handler(ctx: Context<UpdateMerkleRoot>, merkle_root: [u8; 32], ipfs_cid: String) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let old_merkle_root = campaign.merkle_root;
+
+    // Check: validate the Merkle root update.
+    check_update_merkle_root(
+        campaign.campaign_start_time,
+        campaign.claim_mode,
+        campaign.expiration_time,
+        campaign.first_claim_time,
+        &ipfs_cid,
+    )?;
+
+    // Effect: update the Merkle root and the IPFS CID.
+    campaign.update_merkle_root(ipfs_cid.clone(), merkle_root)?;
+
+    // Log the Merkle root update.
+    emit!(events::UpdateMerkleRoot {
+        campaign: campaign.key(),
+        campaign_creator: ctx.accounts.campaign_creator.key(),
+        new_ipfs_cid: ipfs_cid,
+        new_merkle_root: merkle_root,
+        old_merkle_root,
+    });
+
+    Ok(())
+}
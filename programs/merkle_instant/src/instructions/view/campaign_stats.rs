@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+
+use crate::{instructions::collect_fees::safe_collectible_amount, state::Campaign, utils::errors::ErrorCode};
+
+#[derive(Accounts)]
+pub struct CampaignStats<'info> {
+    // -------------------------------------------------------------------------- //
+    //                               CAMPAIGN ACCOUNTS                            //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the mint account of the airdrop token.
+    /// CHECK: The address is validated against the campaign, and native campaigns have no mint account to read.
+    #[account(address = campaign.airdrop_token_mint)]
+    pub airdrop_token_mint: UncheckedAccount<'info>,
+
+    /// Read account: the account storing the campaign data.
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    /// Read account: the campaign's ATA for the airdrop token, required unless the campaign airdrops native SOL.
+    #[account(
+      associated_token::mint = airdrop_token_mint,
+      associated_token::authority = campaign,
+      associated_token::token_program = airdrop_token_program
+    )]
+    pub campaign_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    // -------------------------------------------------------------------------- //
+    //                              PROGRAM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the Token program of the airdrop token.
+    pub airdrop_token_program: Interface<'info, TokenInterface>,
+}
+
+/// The claim statistics of a campaign.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CampaignStatsData {
+    /// The amounts claimed of the additional airdrop tokens, in the order of the campaign's additional mints.
+    pub additional_total_claimed_amounts: Vec<u64>,
+    /// The aggregate amount declared at creation, or `None` if the campaign has been migrated, as it is unknown.
+    pub aggregate_amount: Option<u64>,
+    /// The balance of the campaign's ATA, or the SOL available for claims if the campaign airdrops native SOL.
+    pub campaign_ata_balance: u64,
+    pub claim_count: u32,
+    pub last_claim_time: u64,
+    pub total_claimed_amount: u64,
+    pub total_clawed_back: u64,
+    /// The aggregate amount minus the total claimed amount, or `None` if the campaign has been migrated.
+    pub unclaimed_amount: Option<u64>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::campaign_stats`].
+pub fn handler(ctx: Context<CampaignStats>) -> Result<CampaignStatsData> {
+    let campaign = &ctx.accounts.campaign;
+
+    // Native campaigns hold the airdropped SOL themselves, while SPL campaigns hold the tokens in their ATA.
+    let campaign_ata_balance = if campaign.is_native {
+        safe_collectible_amount(&campaign.to_account_info())?
+    } else {
+        ctx.accounts.campaign_ata.as_ref().ok_or(ErrorCode::CampaignAtaMissing)?.amount
+    };
+
+    // The migrated campaigns, which keep their legacy seeds, did not record the aggregate amount.
+    let aggregate_amount = campaign.legacy_seeds.is_none().then_some(campaign.aggregate_amount);
+
+    Ok(CampaignStatsData {
+        additional_total_claimed_amounts: campaign.additional_total_claimed_amounts.clone(),
+        aggregate_amount,
+        campaign_ata_balance,
+        claim_count: campaign.claim_count,
+        last_claim_time: campaign.last_claim_time,
+        total_claimed_amount: campaign.total_claimed_amount,
+        total_clawed_back: campaign.total_clawed_back,
+        unclaimed_amount: aggregate_amount.map(|amount| amount.saturating_sub(campaign.total_claimed_amount)),
+    })
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::ClaimReceipt, utils::constants::seeds::CLAIM_RECEIPT};
+
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct ClaimReceiptView<'info> {
+    // -------------------------------------------------------------------------- //
+    //                              CAMPAIGN ACCOUNTS                             //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the campaign of the claim receipt.
+    /// CHECK: Only used to derive the claim receipt address, as the receipts outlive the closed campaigns.
+    pub campaign: UncheckedAccount<'info>,
+
+    /// Read account: the claim receipt of the index.
+    /// CHECK: Deserialized in the handler, as the legacy claim receipts hold no claim details.
+    #[account(
+      seeds = [
+        CLAIM_RECEIPT,
+        campaign.key().as_ref(),
+        index.to_le_bytes().as_ref(),
+      ],
+      bump
+    )]
+    pub claim_receipt: UncheckedAccount<'info>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::claim_receipt_view`].
+pub fn handler(ctx: Context<ClaimReceiptView>) -> Result<ClaimReceipt> {
+    let claim_receipt = &ctx.accounts.claim_receipt;
+
+    // The legacy claim receipts only mark the index as claimed, so all the claim details are zero.
+    if ClaimReceipt::is_legacy(claim_receipt)? {
+        return Ok(ClaimReceipt::default());
+    }
+
+    // Check: the claim receipt is owned by the program.
+    if claim_receipt.owner != ctx.program_id {
+        return Err(anchor_lang::error::ErrorCode::AccountNotInitialized.into());
+    }
+
+    ClaimReceipt::try_deserialize(&mut &claim_receipt.try_borrow_data()?[..])
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use super::CampaignView;
+use crate::{
+    state::{ClaimBitmap, ClaimMode},
+    utils::{
+        constants::{seeds::*, CLAIMS_PER_BITMAP},
+        errors::ErrorCode,
+        validations::check_claimed_bitmap_of,
+    },
+};
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::claimed_bitmap_of`].
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CampaignView<'info>>,
+    start_index: u32,
+    count: u32,
+) -> Result<Vec<u8>> {
+    let campaign = &ctx.accounts.campaign;
+
+    // Check: validate the query.
+    check_claimed_bitmap_of(campaign.claim_mode, count, ctx.remaining_accounts.len(), start_index)?;
+
+    let mut claimed_bitmap = vec![0u8; count.div_ceil(8) as usize];
+
+    match campaign.claim_mode {
+        ClaimMode::Receipt => {
+            for (offset, claim_receipt) in (0..count).zip(ctx.remaining_accounts.iter()) {
+                let index_bytes = start_index.checked_add(offset).ok_or(ErrorCode::IndexRangeOverflow)?.to_le_bytes();
+                let (claim_receipt_address, _) = Pubkey::find_program_address(
+                    &[CLAIM_RECEIPT, campaign.key().as_ref(), &index_bytes],
+                    ctx.program_id,
+                );
+
+                // Check: the claim receipt is the one derived from the campaign and the index.
+                if claim_receipt.key() != claim_receipt_address {
+                    return Err(ErrorCode::InvalidClaimReceipt.into());
+                }
+
+                // The index has been claimed if its claim receipt exists.
+                if claim_receipt.owner == ctx.program_id && !claim_receipt.data_is_empty() {
+                    set_bit(&mut claimed_bitmap, offset);
+                }
+            }
+        }
+        ClaimMode::Bitmap => {
+            let first_chunk = start_index / CLAIMS_PER_BITMAP;
+            let mut claim_bitmaps = Vec::with_capacity(ctx.remaining_accounts.len());
+
+            for (chunk_index, account) in (first_chunk..).zip(ctx.remaining_accounts.iter()) {
+                let (claim_bitmap_address, _) = Pubkey::find_program_address(
+                    &[CLAIM_BITMAP, campaign.key().as_ref(), &chunk_index.to_le_bytes()],
+                    ctx.program_id,
+                );
+
+                // Check: the claim bitmaps are the ones covering the range, in order.
+                if account.key() != claim_bitmap_address {
+                    return Err(ErrorCode::InvalidClaimBitmap.into());
+                }
+
+                claim_bitmaps.push(AccountLoader::<ClaimBitmap>::try_from(account)?);
+            }
+
+            for offset in 0..count {
+                let index = start_index.checked_add(offset).ok_or(ErrorCode::IndexRangeOverflow)?;
+                let claim_bitmap = &claim_bitmaps[(index / CLAIMS_PER_BITMAP - first_chunk) as usize];
+                if claim_bitmap.load()?.is_claimed(index) {
+                    set_bit(&mut claimed_bitmap, offset);
+                }
+            }
+        }
+        ClaimMode::Cumulative => return Err(ErrorCode::CumulativeClaimRequired.into()),
+    }
+
+    Ok(claimed_bitmap)
+}
+
+/// Helper function to set the bit of the given offset in a packed bitset, the least significant bit first.
+fn set_bit(bitset: &mut [u8], offset: u32) {
+    bitset[(offset / 8) as usize] |= 1 << (offset % 8);
+}
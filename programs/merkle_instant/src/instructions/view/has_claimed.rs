@@ -1,9 +1,15 @@
 use anchor_lang::prelude::*;
 
-use crate::{state::Campaign, utils::constants::seeds::CLAIM_RECEIPT};
+use crate::{
+    state::{Campaign, ClaimBitmap, ClaimMode},
+    utils::{
+        constants::{seeds::*, CLAIMS_PER_BITMAP},
+        errors::ErrorCode,
+    },
+};
 
 #[derive(Accounts)]
-#[instruction(_index: u32)]
+#[instruction(index: u32)]
 pub struct HasClaimed<'info> {
     // -------------------------------------------------------------------------- //
     //                                CAMPAIGN ACCOUNTS                           //
@@ -11,15 +17,41 @@ pub struct HasClaimed<'info> {
     /// Read account: the account storing the campaign data.
     pub campaign: Box<Account<'info, Campaign>>,
 
-    /// Read account: the claim receipt.
+    /// Read account: the claim receipt, required if the campaign tracks claims with receipts.
     /// CHECK: If it exists, return true, otherwise false.
     #[account(
         seeds = [
             CLAIM_RECEIPT,
             campaign.key().as_ref(),
-            _index.to_le_bytes().as_ref(),
+            index.to_le_bytes().as_ref(),
         ],
         bump
     )]
-    pub claim_receipt: UncheckedAccount<'info>,
+    pub claim_receipt: Option<UncheckedAccount<'info>>,
+
+    /// Read account: the claim bitmap covering the index, required if the campaign tracks claims in bitmaps.
+    #[account(
+        seeds = [
+            CLAIM_BITMAP,
+            campaign.key().as_ref(),
+            (index / CLAIMS_PER_BITMAP).to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub claim_bitmap: Option<AccountLoader<'info, ClaimBitmap>>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::has_claimed`].
+pub fn handler(ctx: Context<HasClaimed>, index: u32) -> Result<bool> {
+    match ctx.accounts.campaign.claim_mode {
+        ClaimMode::Receipt => {
+            let claim_receipt = ctx.accounts.claim_receipt.as_ref().ok_or(ErrorCode::ClaimStatusAccountMissing)?;
+            Ok(!claim_receipt.data_is_empty())
+        }
+        ClaimMode::Bitmap => {
+            let claim_bitmap = ctx.accounts.claim_bitmap.as_ref().ok_or(ErrorCode::ClaimStatusAccountMissing)?;
+            Ok(claim_bitmap.load()?.is_claimed(index))
+        }
+        ClaimMode::Cumulative => Err(ErrorCode::CumulativeClaimRequired.into()),
+    }
 }
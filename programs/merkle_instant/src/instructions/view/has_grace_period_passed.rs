@@ -5,5 +5,6 @@ use crate::utils::validations::has_grace_period_passed;
 
 /// See the documentation for [`fn@crate::sablier_merkle_instant::has_grace_period_passed`].
 pub fn handler(ctx: Context<CampaignView>) -> Result<bool> {
-    has_grace_period_passed(ctx.accounts.campaign.expiration_time)
+    let campaign = &ctx.accounts.campaign;
+    has_grace_period_passed(campaign.first_claim_time, campaign.grace_period)
 }
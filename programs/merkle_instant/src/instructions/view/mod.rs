@@ -1,12 +1,17 @@
+pub mod campaign_stats;
 pub mod campaign_view;
 pub mod claim_fee_in_lamports;
+pub mod claim_receipt_view;
+pub mod claimed_bitmap_of;
 pub mod has_campaign_started;
 pub mod has_claimed;
 pub mod has_expired;
 pub mod has_grace_period_passed;
 pub mod treasury_view;
 
+pub use campaign_stats::*;
 pub use campaign_view::*;
 pub use claim_fee_in_lamports::*;
+pub use claim_receipt_view::*;
 pub use has_claimed::*;
 pub use treasury_view::*;
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{Campaign, ProofBuffer},
+    utils::{
+        constants::{seeds::PROOF_BUFFER, ANCHOR_DISCRIMINATOR_SIZE},
+        validations::check_write_proof_buffer,
+    },
+};
+
+#[derive(Accounts)]
+pub struct WriteProofBuffer<'info> {
+    // -------------------------------------------------------------------------- //
+    //                                USER ACCOUNTS                               //
+    // -------------------------------------------------------------------------- //
+    /// Write account: the claimer who pays for the proof buffer.
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    // -------------------------------------------------------------------------- //
+    //                              CAMPAIGN ACCOUNTS                             //
+    // -------------------------------------------------------------------------- //
+    /// Read account: the account storing the campaign data.
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    /// Create if needed account: the proof buffer of the claimer for the campaign.
+    #[account(
+      init_if_needed,
+      payer = claimer,
+      space = ANCHOR_DISCRIMINATOR_SIZE + ProofBuffer::INIT_SPACE,
+      seeds = [
+        PROOF_BUFFER,
+        campaign.key().as_ref(),
+        claimer.key().as_ref(),
+      ],
+      bump
+    )]
+    pub proof_buffer: Box<Account<'info, ProofBuffer>>,
+
+    // -------------------------------------------------------------------------- //
+    //                               SYSTEM ACCOUNTS                              //
+    // -------------------------------------------------------------------------- //
+    /// Program account: the System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// See the documentation for [`fn@crate::sablier_merkle_instant::write_proof_buffer`].
+pub fn handler(ctx: Context<WriteProofBuffer>, proof_elements: Vec<[u8; 32]>) -> Result<()> {
+    // Check: validate the writing of the proof elements.
+    check_write_proof_buffer(ctx.accounts.proof_buffer.proof.len(), proof_elements.len())?;
+
+    // Effect: append the proof elements to the proof buffer.
+    ctx.accounts.proof_buffer.write(ctx.accounts.campaign.key(), ctx.accounts.claimer.key(), proof_elements)?;
+
+    Ok(())
+}
@@ -19,6 +19,92 @@ pub mod sablier_merkle_instant {
     //                         STATE-CHANGING INSTRUCTIONS                        //
     // -------------------------------------------------------------------------- //
 
+    /// Adds an airdrop token to a campaign, so that the recipients can claim a basket of tokens at once.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `campaign_creator` The transaction signer and the campaign creator.
+    /// - `campaign` The account that stores the campaign details.
+    /// - `airdrop_token_mint` The mint of the airdrop token to add.
+    /// - `airdrop_token_program` The Token Program of the airdrop token to add.
+    ///
+    /// # Notes
+    ///
+    /// - The campaign's ATA for the token is created, and must be funded by the campaign creator afterwards.
+    /// - The leaves of a multi-token campaign carry one amount per airdrop token, in the order of the main airdrop
+    /// token followed by the additional ones, and must be claimed via
+    /// [`fn@crate::sablier_merkle_instant::claim_multi_token`].
+    /// - Emits a [`crate::utils::events::AddAirdropToken`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - The signer must be the actual campaign creator.
+    /// - The campaign must not have started.
+    /// - The campaign must airdrop an SPL token, use the Sablier leaf format and not track cumulative claims.
+    /// - The token must not be airdropped by the campaign yet.
+    /// - The campaign must airdrop fewer than 4 additional tokens.
+    /// - The program must not be paused.
+    /// - The token must be allowed by the mint list.
+    pub fn add_airdrop_token(ctx: Context<AddAirdropToken>) -> Result<()> {
+        instructions::add_airdrop_token::handler(ctx)
+    }
+
+    /// Adds a mint to the mint list, which acts as an allowlist or a denylist depending on the treasury's mint list
+    /// mode.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `fee_collector` The transaction signer and the fee collector.
+    /// - `mint` The mint to add to the mint list.
+    ///
+    /// # Notes
+    ///
+    /// - The rent of the mint list entry is paid by the fee collector.
+    /// - Emits a [`crate::utils::events::MintAddedToList`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - `fee_collector` must be the treasury's fee collector.
+    /// - The mint must not be in the mint list yet.
+    pub fn add_to_mint_list(ctx: Context<AddToMintList>) -> Result<()> {
+        instructions::add_to_mint_list::handler(ctx)
+    }
+
+    /// Cancels a campaign before its start, refunds the airdrop tokens to the campaign creator, and closes the
+    /// campaign along with its ATAs and claim bitmaps.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `campaign_creator` The transaction signer and the campaign creator.
+    /// - `campaign` The account that stores the campaign details.
+    /// - `airdrop_token_mint` The mint of the main airdropped token.
+    /// - `airdrop_token_program` The Token Program of the main airdropped token.
+    /// - `remaining_accounts` For each additional airdrop token, in order: the campaign's ATA and the Token Program of
+    /// the token. Followed by the claim bitmaps of the campaign to close, if any.
+    ///
+    /// # Notes
+    ///
+    /// - The full balance of the campaign's ATA for the main airdrop token is transferred to the campaign creator's
+    /// ATA, created if needed.
+    /// - The rent of the closed accounts is refunded to the campaign creator.
+    /// - A tombstone account, paid by the campaign creator, retires the campaign address, so that no campaign can be
+    /// created at it again and inherit the claim receipts, claim bitmaps and cumulative claims of the closed one.
+    /// - Emits a [`crate::utils::events::CampaignCanceled`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - The signer must be the actual campaign creator.
+    /// - The campaign must not have started.
+    /// - No claim must have been made, including before the migration of a legacy campaign.
+    /// - The campaign must airdrop an SPL token.
+    /// - The remaining accounts must match the additional airdrop tokens as described above, and the following ones
+    /// must be claim bitmaps of the campaign.
+    /// - The campaign's ATAs for the additional airdrop tokens must not hold any tokens, i.e. they must be clawed back
+    /// beforehand.
+    pub fn cancel_campaign<'info>(ctx: Context<'_, '_, 'info, 'info, CancelCampaign<'info>>) -> Result<()> {
+        instructions::cancel_campaign::handler(ctx)
+    }
+
     /// Claims airdrop on behalf of eligible recipient and transfers it to the recipient ATA.
     ///
     /// # Accounts Expected
@@ -28,8 +114,14 @@ pub mod sablier_merkle_instant {
     /// - `recipient` The address of the airdrop recipient.
     /// - `airdrop_token_mint` The mint of the airdropped token.
     /// - `airdrop_token_program` The Token Program of the airdropped token.
+    /// - `claim_receipt` The claim receipt to create, if the campaign tracks claims with receipts.
+    /// - `claim_bitmap` The claim bitmap covering `index`, if the campaign tracks claims in bitmaps.
     /// - `chainlink_program`: The Chainlink program used to retrieve on-chain price feeds.
     /// - `chainlink_sol_usd_feed`: The account providing the SOL/USD price feed data.
+    /// - `treasury_ata` The treasury's ATA for the airdrop token, if the campaign charges the claim fee in the airdrop
+    /// token.
+    /// - `remaining_accounts` If the campaign is token-gated: the recipient's token account holding the required
+    /// token, followed by the metadata account of its mint if the campaign requires an NFT collection.
     ///
     /// # Parameters
     ///
@@ -39,7 +131,11 @@ pub mod sablier_merkle_instant {
     ///
     /// # Notes
     ///
-    /// - Emits a [`crate::utils::events::Claim`] event.
+    /// - Emits a [`crate::utils::events::Claim`] event, whose `claim_receipt` is the claim bitmap if the campaign
+    /// tracks claims in bitmaps, and whose `destination` is the recipient.
+    /// - The eligibility of a token-gated campaign is checked against the recipient's holdings at claim time.
+    /// - If the campaign charges the claim fee in the airdrop token, the fee is deducted from the claimed amount and
+    /// no fee is charged in SOL.
     ///
     /// # Requirements
     ///
@@ -47,19 +143,335 @@ pub mod sablier_merkle_instant {
     ///
     /// - The campaign must not have expired.
     /// - The recipient's airdrop has not been claimed yet.
+    /// - The claim status account required by the campaign's claim mode must be provided.
+    /// - The campaign must airdrop an SPL token, as native SOL must be claimed via
+    /// [`fn@crate::sablier_merkle_instant::claim_native`].
+    /// - The campaign must not track cumulative claims.
+    /// - The campaign must not airdrop additional tokens, which must be claimed via
+    /// [`fn@crate::sablier_merkle_instant::claim_multi_token`].
     /// - The Merkle proof must be valid.
+    /// - If the campaign is token-gated, the recipient's token account must hold at least the minimum amount of the
+    /// required token, or of an NFT that is a verified member of the required collection.
     /// - `chainlink_program` and `chainlink_sol_usd_feed` must match the ones stored in the treasury.
+    /// - The program must not be paused.
     pub fn claim(ctx: Context<Claim>, index: u32, amount: u64, merkle_proof: Vec<[u8; 32]>) -> Result<()> {
         instructions::claim::handler(ctx, index, amount, merkle_proof)
     }
 
+    /// Claims the increase of the cumulative amount allocated to an eligible recipient since their last claim, and
+    /// transfers it to the recipient ATA.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `claimer` The transaction signer.
+    /// - `campaign` The account that stores the campaign details.
+    /// - `recipient` The address of the airdrop recipient.
+    /// - `airdrop_token_mint` The mint of the airdropped token.
+    /// - `airdrop_token_program` The Token Program of the airdropped token.
+    /// - `chainlink_program`: The Chainlink program used to retrieve on-chain price feeds.
+    /// - `chainlink_sol_usd_feed`: The account providing the SOL/USD price feed data.
+    /// - `remaining_accounts` If the campaign is token-gated: the recipient's token account holding the required
+    /// token, followed by the metadata account of its mint if the campaign requires an NFT collection.
+    ///
+    /// # Parameters
+    ///
+    /// - `index` The index of the recipient in the current Merkle tree.
+    /// - `cumulative_amount` The cumulative amount allocated to the recipient in the current Merkle tree.
+    /// - `merkle_proof` The proof of inclusion in the current Merkle tree.
+    ///
+    /// # Notes
+    ///
+    /// - The amount claimed so far by the recipient is tracked in a PDA, created on the first claim and paid by the
+    /// claimer.
+    /// - The campaign creator can post new Merkle roots over time via
+    /// [`fn@crate::sablier_merkle_instant::update_merkle_root`], with increased cumulative amounts.
+    /// - Emits a [`crate::utils::events::Claim`] event, whose `amount` is the amount paid out and whose
+    /// `claim_receipt` is the account tracking the amount claimed so far.
+    ///
+    /// # Requirements
+    ///
+    /// - The campaign must track cumulative claims, and thus airdrop an SPL token.
+    /// - The current time must be greater than or equal to the campaign start time.
+    /// - The campaign must not have expired.
+    /// - `cumulative_amount` must be greater than the amount claimed so far by the recipient.
+    /// - If the campaign is token-gated, the recipient's token account must hold at least the minimum amount of the
+    /// required token, or of an NFT that is a verified member of the required collection.
+    /// - `chainlink_program` and `chainlink_sol_usd_feed` must match the ones stored in the treasury.
+    /// - The program must not be paused.
+    pub fn claim_cumulative(
+        ctx: Context<ClaimCumulative>,
+        index: u32,
+        cumulative_amount: u64,
+        merkle_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::claim_cumulative::handler(ctx, index, cumulative_amount, merkle_proof)
+    }
+
+    /// Claims the airdrops of all the tokens of a multi-token campaign on behalf of eligible recipient, and transfers
+    /// them to the recipient ATAs.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - The same accounts as [`fn@crate::sablier_merkle_instant::claim`], for the main airdrop token.
+    /// - `remaining_accounts` For each additional airdrop token, in order: the mint, the campaign's ATA, the
+    /// recipient's ATA and the Token Program of the token. If the campaign is token-gated, they are followed by the
+    /// accounts required to check the recipient's eligibility, as in [`fn@crate::sablier_merkle_instant::claim`].
+    ///
+    /// # Parameters
+    ///
+    /// - `index` The index of the recipient in the Merkle tree.
+    /// - `amounts` The amounts allocated to the recipient, for the main airdrop token followed by the additional ones.
+    /// - `merkle_proof` The proof of inclusion in the Merkle tree.
+    ///
+    /// # Notes
+    ///
+    /// - The leaf is `keccak(keccak(index as u32 LE ‖ recipient ‖ amounts as u64 LE))`, so that it is the same as the
+    /// leaf of a single-token campaign if the campaign airdrops a single token.
+    /// - The recipient's ATAs are created if needed, paid by the claimer.
+    /// - The campaign statistics account for the main airdrop token, and the amounts claimed of the additional airdrop
+    /// tokens are tracked separately in the campaign.
+    /// - Emits a [`crate::utils::events::ClaimMultiToken`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - The same requirements as [`fn@crate::sablier_merkle_instant::claim`], except that the campaign can airdrop
+    /// additional tokens.
+    /// - `amounts` must contain one amount per airdrop token of the campaign.
+    /// - The remaining accounts must match the additional airdrop tokens as described above.
+    pub fn claim_multi_token<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimMultiToken<'info>>,
+        index: u32,
+        amounts: Vec<u64>,
+        merkle_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::claim_multi_token::handler(ctx, index, amounts, merkle_proof)
+    }
+
+    /// Claims the airdrops of multiple leaves of the Merkle tree, on behalf of their recipients, and transfers them to
+    /// the recipients' ATAs.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `claimer` The transaction signer.
+    /// - `campaign` The account that stores the campaign details.
+    /// - `airdrop_token_mint` The mint of the airdropped token.
+    /// - `airdrop_token_program` The Token Program of the airdropped token.
+    /// - `chainlink_program`: The Chainlink program used to retrieve on-chain price feeds.
+    /// - `chainlink_sol_usd_feed`: The account providing the SOL/USD price feed data.
+    /// - `remaining_accounts` For each leaf, in order: the claim status account (the claim receipt to create, or the
+    /// claim bitmap covering the index), the recipient, and the recipient's ATA. If the campaign is token-gated, they
+    /// are followed by the accounts required to check the recipient's eligibility, as in
+    /// [`fn@crate::sablier_merkle_instant::claim`].
+    ///
+    /// # Parameters
+    ///
+    /// - `leaves` The leaves to claim, each with its index, recipient, amount and Merkle proof.
+    ///
+    /// # Notes
+    ///
+    /// - The claim fee is charged once for the whole batch.
+    /// - The recipients' ATAs are created if needed, paid by the claimer.
+    /// - Emits a [`crate::utils::events::Claim`] event for each leaf, with the fee reported in the first one.
+    ///
+    /// # Requirements
+    ///
+    /// - The same requirements as [`fn@crate::sablier_merkle_instant::claim`], for each leaf.
+    /// - `leaves` must not be empty.
+    /// - The remaining accounts must match the leaves as described above.
+    pub fn claim_multiple<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimMultiple<'info>>,
+        leaves: Vec<ClaimLeaf>,
+    ) -> Result<()> {
+        instructions::claim_multiple::handler(ctx, leaves)
+    }
+
+    /// Claims the airdrops of multiple leaves of the Merkle tree, proven with a single Merkle multiproof, on behalf of
+    /// their recipients, and transfers them to the recipients' ATAs.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - The same accounts as [`fn@crate::sablier_merkle_instant::claim_multiple`].
+    ///
+    /// # Parameters
+    ///
+    /// - `leaves` The leaves to claim, each with its index, recipient and amount, in the order of the multiproof.
+    /// - `proof` The sibling hashes of the multiproof.
+    /// - `proof_flags` The flags indicating, for each hashing step, whether the second node is taken from the leaves
+    /// and the computed hashes, or from `proof`.
+    ///
+    /// # Notes
+    ///
+    /// - The multiproof is compatible with OpenZeppelin's `multiProofVerify`, which avoids hashing the shared upper
+    /// nodes of the tree once per leaf.
+    /// - Emits a [`crate::utils::events::Claim`] event for each leaf, with the fee reported in the first one.
+    ///
+    /// # Requirements
+    ///
+    /// - The same requirements as [`fn@crate::sablier_merkle_instant::claim_multiple`], except that the leaves must
+    /// be proven by the multiproof.
+    pub fn claim_multiple_with_multiproof<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimMultiple<'info>>,
+        leaves: Vec<ClaimLeafData>,
+        proof: Vec<[u8; 32]>,
+        proof_flags: Vec<bool>,
+    ) -> Result<()> {
+        instructions::claim_multiple_with_multiproof::handler(ctx, leaves, proof, proof_flags)
+    }
+
+    /// Claims native SOL airdrop on behalf of eligible recipient and transfers it to the recipient.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `claimer` The transaction signer.
+    /// - `campaign` The account that stores the campaign details and holds the airdropped SOL.
+    /// - `recipient` The address of the airdrop recipient.
+    /// - `claim_receipt` The claim receipt to create, if the campaign tracks claims with receipts.
+    /// - `claim_bitmap` The claim bitmap covering `index`, if the campaign tracks claims in bitmaps.
+    /// - `chainlink_program`: The Chainlink program used to retrieve on-chain price feeds.
+    /// - `chainlink_sol_usd_feed`: The account providing the SOL/USD price feed data.
+    ///
+    /// # Parameters
+    ///
+    /// - `index` The index of the recipient in the Merkle tree.
+    /// - `amount` The amount of lamports allocated to the recipient.
+    /// - `merkle_proof` The proof of inclusion in the Merkle tree.
+    ///
+    /// # Notes
+    ///
+    /// - Emits a [`crate::utils::events::Claim`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - The same requirements as [`fn@crate::sablier_merkle_instant::claim`], except that the campaign must airdrop
+    /// native SOL.
+    /// - The campaign balance, less the rent-exempt minimum and a 0.001 SOL buffer, must cover `amount`.
+    pub fn claim_native(ctx: Context<ClaimNative>, index: u32, amount: u64, merkle_proof: Vec<[u8; 32]>) -> Result<()> {
+        instructions::claim_native::handler(ctx, index, amount, merkle_proof)
+    }
+
+    /// Claims airdrop as the eligible recipient and transfers it to the ATA of the destination chosen by the recipient.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `recipient` The transaction signer and the airdrop recipient.
+    /// - `destination` The address chosen by the recipient to receive the airdrop.
+    /// - `campaign` The account that stores the campaign details.
+    /// - `airdrop_token_mint` The mint of the airdropped token.
+    /// - `airdrop_token_program` The Token Program of the airdropped token.
+    /// - `claim_receipt` The claim receipt to create, if the campaign tracks claims with receipts.
+    /// - `claim_bitmap` The claim bitmap covering `index`, if the campaign tracks claims in bitmaps.
+    /// - `chainlink_program`: The Chainlink program used to retrieve on-chain price feeds.
+    /// - `chainlink_sol_usd_feed`: The account providing the SOL/USD price feed data.
+    /// - `treasury_ata` The treasury's ATA for the airdrop token, if the campaign charges the claim fee in the airdrop
+    /// token.
+    /// - `remaining_accounts` If the campaign is token-gated: the recipient's token account holding the required
+    /// token, followed by the metadata account of its mint if the campaign requires an NFT collection.
+    ///
+    /// # Parameters
+    ///
+    /// - `index` The index of the recipient in the Merkle tree.
+    /// - `amount` The amount allocated to the recipient.
+    /// - `merkle_proof` The proof of inclusion in the Merkle tree.
+    ///
+    /// # Notes
+    ///
+    /// - Emits a [`crate::utils::events::Claim`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - The same requirements as [`fn@crate::sablier_merkle_instant::claim`].
+    /// - The signer must be the recipient of the airdrop.
+    pub fn claim_to(ctx: Context<ClaimTo>, index: u32, amount: u64, merkle_proof: Vec<[u8; 32]>) -> Result<()> {
+        instructions::claim_to::handler(ctx, index, amount, merkle_proof)
+    }
+
+    /// Claims airdrop on behalf of eligible recipient, using the Merkle proof stored in the claimer's proof buffer, and
+    /// transfers it to the recipient ATA.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - The same accounts as [`fn@crate::sablier_merkle_instant::claim`].
+    /// - `proof_buffer` The proof buffer of the claimer for the campaign, closed after the claim.
+    ///
+    /// # Parameters
+    ///
+    /// - `index` The index of the recipient in the Merkle tree.
+    /// - `amount` The amount allocated to the recipient.
+    ///
+    /// # Notes
+    ///
+    /// - Intended for proofs too large to fit in the transaction, which are written beforehand via
+    /// [`fn@crate::sablier_merkle_instant::write_proof_buffer`].
+    /// - The rent of the proof buffer is refunded to the claimer.
+    /// - Emits a [`crate::utils::events::Claim`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - The same requirements as [`fn@crate::sablier_merkle_instant::claim`], with the proof stored in the proof
+    /// buffer.
+    pub fn claim_with_proof_account(ctx: Context<ClaimWithProofAccount>, index: u32, amount: u64) -> Result<()> {
+        instructions::claim_with_proof_account::handler(ctx, index, amount)
+    }
+
+    /// Claims airdrop on behalf of eligible recipient, who authorized the claim with an off-chain signature, and
+    /// transfers it to the destination ATA chosen by the recipient.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `claimer` The transaction signer, e.g. a relayer.
+    /// - `campaign` The account that stores the campaign details.
+    /// - `recipient` The address of the airdrop recipient.
+    /// - `destination` The address chosen by the recipient to receive the airdrop.
+    /// - `airdrop_token_mint` The mint of the airdropped token.
+    /// - `airdrop_token_program` The Token Program of the airdropped token.
+    /// - `claim_receipt` The claim receipt to create, if the campaign tracks claims with receipts.
+    /// - `claim_bitmap` The claim bitmap covering `index`, if the campaign tracks claims in bitmaps.
+    /// - `chainlink_program`: The Chainlink program used to retrieve on-chain price feeds.
+    /// - `chainlink_sol_usd_feed`: The account providing the SOL/USD price feed data.
+    /// - `treasury_ata` The treasury's ATA for the airdrop token, if the campaign charges the claim fee in the airdrop
+    /// token.
+    /// - `instructions_sysvar` The Instructions sysvar.
+    /// - `remaining_accounts` If the campaign is token-gated: the recipient's token account holding the required
+    /// token, followed by the metadata account of its mint if the campaign requires an NFT collection.
+    ///
+    /// # Parameters
+    ///
+    /// - `index` The index of the recipient in the Merkle tree.
+    /// - `amount` The amount allocated to the recipient.
+    /// - `merkle_proof` The proof of inclusion in the Merkle tree.
+    /// - `signature_expiry` The time after which the recipient's signature is no longer valid, in seconds since the
+    /// Unix epoch.
+    ///
+    /// # Notes
+    ///
+    /// - The recipient's signature must be verified by an ed25519 program instruction placed immediately before this
+    /// one in the transaction. The signed message is the concatenation of the campaign address, `index` (u32 LE),
+    /// `amount` (u64 LE), the destination address and `signature_expiry` (u64 LE).
+    /// - The claimer pays the claim fee and the rent of the accounts created.
+    /// - Emits a [`crate::utils::events::Claim`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - The same requirements as [`fn@crate::sablier_merkle_instant::claim`].
+    /// - `signature_expiry` must be greater than or equal to the current time.
+    /// - The preceding instruction must verify the recipient's signature of the message described above.
+    pub fn claim_with_sig(
+        ctx: Context<ClaimWithSig>,
+        index: u32,
+        amount: u64,
+        merkle_proof: Vec<[u8; 32]>,
+        signature_expiry: u64,
+    ) -> Result<()> {
+        instructions::claim_with_sig::handler(ctx, index, amount, merkle_proof, signature_expiry)
+    }
+
     /// Claws back the unclaimed tokens from the campaign.
     ///
     /// # Accounts Expected
     ///
     /// - `campaign` The account that stores the campaign details.
     /// - `campaign_creator` The transaction signer.
-    /// - `airdrop_token_mint` The mint of the airdropped token.
+    /// - `airdrop_token_mint` The mint of the airdropped token to claw back, which can be any token of the campaign.
     /// - `airdrop_token_program` The Token Program of the airdropped token.
     ///
     /// # Parameters
@@ -68,38 +480,207 @@ pub mod sablier_merkle_instant {
     ///
     /// # Notes
     ///
+    /// - The campaign statistics only account for the main airdrop token.
     /// - Emits a [`crate::utils::events::Clawback`] event.
     ///
     /// # Requirements
     ///
     /// - The signer must be the actual campaign creator.
-    /// - No claim must be made, OR the current timestamp must not exceed 7 days after the first claim, OR the campaign
-    /// must be expired.
+    /// - The campaign must airdrop an SPL token, as native SOL must be clawed back via
+    /// [`fn@crate::sablier_merkle_instant::clawback_native`].
+    /// - No claim must be made, OR the current timestamp must not exceed the campaign's grace period after the first
+    /// claim, OR the campaign must be expired.
     pub fn clawback(ctx: Context<Clawback>, amount: u64) -> Result<()> {
         instructions::clawback::handler(ctx, amount)
     }
 
-    /// Collects the fees accumulated in the treasury by transferring them to the fee recipient.
+    /// Claws back the unclaimed SOL from a native SOL campaign.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `campaign_creator` The transaction signer.
+    /// - `clawback_recipient` The address receiving the clawed back SOL.
+    /// - `campaign` The account that stores the campaign details and holds the airdropped SOL.
+    ///
+    /// # Parameters
+    ///
+    /// - `amount` The amount of lamports to claw back.
+    ///
+    /// # Notes
+    ///
+    /// - Emits a [`crate::utils::events::Clawback`] event, whose `token_mint` is the native mint.
+    ///
+    /// # Requirements
+    ///
+    /// - The same requirements as [`fn@crate::sablier_merkle_instant::clawback`], except that the campaign must
+    /// airdrop native SOL.
+    /// - The campaign balance, less the rent-exempt minimum and a 0.001 SOL buffer, must cover `amount`.
+    pub fn clawback_native(ctx: Context<ClawbackNative>, amount: u64) -> Result<()> {
+        instructions::clawback_native::handler(ctx, amount)
+    }
+
+    /// Closes an expired campaign, along with its ATAs and claim bitmaps, and refunds their rent to the campaign
+    /// creator.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `campaign_creator` The transaction signer and the campaign creator.
+    /// - `campaign` The account that stores the campaign details.
+    /// - `airdrop_token_mint` The mint of the main airdropped token.
+    /// - `airdrop_token_program` The Token Program of the main airdropped token.
+    /// - `remaining_accounts` For each additional airdrop token, in order: the campaign's ATA and the Token Program of
+    /// the token. Followed by the claim bitmaps of the campaign to close, if any.
+    ///
+    /// # Notes
+    ///
+    /// - The rent of the claim receipts can be refunded to the claimers afterwards via
+    /// [`fn@crate::sablier_merkle_instant::close_claim_receipts`].
+    /// - A tombstone account, paid by the campaign creator, retires the campaign address, so that no campaign can be
+    /// created at it again and inherit the claim receipts, claim bitmaps and cumulative claims of the closed one.
+    /// - Emits a [`crate::utils::events::CloseCampaign`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - The signer must be the actual campaign creator.
+    /// - The campaign must airdrop an SPL token, as native SOL campaigns must be closed via
+    /// [`fn@crate::sablier_merkle_instant::close_native_campaign`].
+    /// - The campaign must be expired.
+    /// - The campaign's ATAs must not hold any tokens, i.e. the unclaimed tokens must be clawed back beforehand.
+    /// - The remaining accounts must match the additional airdrop tokens as described above, and the following ones
+    /// must be claim bitmaps of the campaign.
+    pub fn close_campaign<'info>(ctx: Context<'_, '_, 'info, 'info, CloseCampaign<'info>>) -> Result<()> {
+        instructions::close_campaign::handler(ctx)
+    }
+
+    /// Closes the claim receipts of a closed campaign and refunds their rent to the claimers who paid for them.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `signer` The transaction signer.
+    /// - `campaign` The address of the closed campaign.
+    /// - `remaining_accounts` For each index, the claim receipt followed by the claimer stored in it, or by the treasury
+    /// for the claim receipts created by the prior version of the program.
+    ///
+    /// # Parameters
+    ///
+    /// - `indexes` The indexes in the Merkle tree whose claim receipts to close.
+    ///
+    /// # Notes
+    ///
+    /// - Anyone can call this instruction.
+    /// - The claim receipts created by the prior version of the program do not store the claimer, so their rent is
+    /// refunded to the treasury.
+    /// - Emits a [`crate::utils::events::CloseClaimReceipts`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - The campaign must have been closed.
+    /// - Each claim receipt must be derived from the campaign and its index.
+    /// - Each claimer must match the one stored in the claim receipt, or be the treasury for the claim receipts created
+    /// by the prior version of the program.
+    pub fn close_claim_receipts<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseClaimReceipts<'info>>,
+        indexes: Vec<u32>,
+    ) -> Result<()> {
+        instructions::close_claim_receipts::handler(ctx, indexes)
+    }
+
+    /// Closes an expired native SOL campaign, along with its claim bitmaps, and transfers its balance and the rent of
+    /// the claim bitmaps to the campaign creator.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `campaign_creator` The transaction signer and the campaign creator.
+    /// - `campaign` The account that stores the campaign details and holds the airdropped SOL.
+    /// - `remaining_accounts` The claim bitmaps of the campaign to close, if any.
+    ///
+    /// # Notes
+    ///
+    /// - The unclaimed SOL is transferred to the campaign creator along with the rent of the campaign, so there is no
+    /// need to claw it back beforehand.
+    /// - A tombstone account, paid by the campaign creator, retires the campaign address, so that no campaign can be
+    /// created at it again and inherit the claim receipts, claim bitmaps and cumulative claims of the closed one.
+    /// - Emits a [`crate::utils::events::CloseCampaign`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - The signer must be the actual campaign creator.
+    /// - The campaign must airdrop native SOL.
+    /// - The campaign must be expired.
+    /// - The remaining accounts must be claim bitmaps of the campaign.
+    pub fn close_native_campaign<'info>(ctx: Context<'_, '_, 'info, 'info, CloseNativeCampaign<'info>>) -> Result<()> {
+        instructions::close_native_campaign::handler(ctx)
+    }
+
+    /// Closes the proof buffer of the claimer for a campaign and refunds its rent, e.g. to discard an incorrect proof.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `claimer` The transaction signer who wrote the proof buffer.
+    /// - `campaign` The address of the campaign for which the proof buffer has been written.
+    ///
+    /// # Requirements
+    ///
+    /// - The proof buffer must exist.
+    pub fn close_proof_buffer(ctx: Context<CloseProofBuffer>) -> Result<()> {
+        instructions::close_proof_buffer::handler(ctx)
+    }
+
+    /// Collects the fees accumulated in the treasury by transferring them to the fee recipient, or by distributing them
+    /// among the recipients of the treasury's fee split.
     ///
     /// # Accounts Expected
     ///
     /// - `fee_collector` The transaction signer and the fee collector.
-    /// - `fee_recipient` The address receiving the collected fees.
+    /// - `fee_recipient` The address receiving the collected fees, if the treasury has no fee split.
+    /// - `remaining_accounts` If the treasury has a fee split: the writable recipients of the fee split, in order.
     ///
     /// # Notes
     ///
     /// - To calculate the "collectable amount", the rent-exempt minimum balance and a 0.001 SOL buffer are deducted
     /// from the treasury SOL balance.
-    /// - Emits a [`crate::utils::events::FeesCollected`] event.
+    /// - With a fee split, each recipient receives its share of the "collectable amount", and the rounding remainder
+    /// goes to the last recipient.
+    /// - A recipient's share is not paid if it would leave the recipient below the rent-exempt minimum, e.g. if the
+    /// recipient is an unfunded address. The share is recorded as owed to the recipient in the treasury, and is paid
+    /// along with its next share in a later collection.
+    /// - Emits a [`crate::utils::events::FeesCollected`] event for each paid recipient.
     ///
     /// # Requirements
     ///
     /// - `fee_collector` must be authorized for fee collection.
     /// - The "collectable amount" must be greater than zero.
+    /// - If the treasury has no fee split, `fee_recipient` must be provided. Otherwise, the remaining accounts must
+    /// match the recipients of the fee split, and at least one of them must be paid.
     pub fn collect_fees(ctx: Context<CollectFees>) -> Result<()> {
         instructions::collect_fees::handler(ctx)
     }
 
+    /// Collects the token fees accumulated in the treasury's ATA for a given mint by transferring them to the fee
+    /// recipient's ATA.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `fee_collector` The transaction signer and the fee collector.
+    /// - `fee_recipient` The address receiving the collected fees.
+    /// - `token_mint` The mint of the token to collect.
+    /// - `token_program` The Token Program of the token to collect.
+    ///
+    /// # Notes
+    ///
+    /// - The full balance of the treasury's ATA is collected, e.g. the claim fees charged in the airdrop token or the
+    /// tokens sent to the treasury by mistake.
+    /// - The fee recipient's ATA is created if needed, paid by the fee collector.
+    /// - Emits a [`crate::utils::events::TokenFeesCollected`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - `fee_collector` must be authorized for fee collection.
+    /// - The treasury's ATA must hold a non-zero balance.
+    pub fn collect_token_fees(ctx: Context<CollectTokenFees>) -> Result<()> {
+        instructions::collect_token_fees::handler(ctx)
+    }
+
     /// Creates a Merkle Instant airdrop campaign.
     ///
     /// # Accounts Expected
@@ -119,10 +700,48 @@ pub mod sablier_merkle_instant {
     /// features that depend upon the IPFS CID.
     /// - `aggregate_amount` The total amount of tokens to be distributed to all recipients.
     /// - `recipient_count` The total number of recipient addresses eligible for the airdrop.
+    /// - `claim_mode` The storage used to track claims: one receipt account per claim, shared bitmap accounts, or one
+    /// account per recipient tracking the amount claimed so far against cumulative amounts.
+    /// - `grace_period` The period after the first claim, in seconds, during which the creator can claw back the
+    /// tokens.
+    /// - `leaf_format` The encoding of the Merkle tree leaves and nodes: Sablier's own, or the one of the Saber or Jito
+    /// merkle-distributor tooling.
+    /// - `eligibility_rule` The token holdings that the recipients must have at claim time, if the campaign is
+    /// token-gated.
+    /// - `token_claim_fee` The claim fee to charge in the airdrop token instead of the claim fee in SOL, either in basis
+    /// points of the claimed amount or as a fixed amount per claim.
     ///
     /// # Notes
     ///
     /// - Emits a [`crate::utils::events::CreateCampaign`] event.
+    /// - `aggregate_amount` is stored in the campaign to report the unclaimed amount in
+    /// [`fn@crate::sablier_merkle_instant::campaign_stats`].
+    /// - The Merkle root and the expiration time are not part of the campaign address, so that they can be updated
+    /// afterwards via [`fn@crate::sablier_merkle_instant::update_merkle_root`] and
+    /// [`fn@crate::sablier_merkle_instant::extend_expiration`]. The campaigns created by a prior version of the
+    /// program keep their address via [`fn@crate::sablier_merkle_instant::migrate_campaign`].
+    /// - If `claim_mode` is [`state::ClaimMode::Bitmap`], the claim bitmaps covering all the indexes in the Merkle
+    /// tree must be allocated via [`fn@crate::sablier_merkle_instant::create_claim_bitmap`]. The creator is expected
+    /// to allocate them in the same transaction as the campaign creation, but any claimer can allocate a missing one.
+    /// - If `claim_mode` is [`state::ClaimMode::Cumulative`], the leaves carry the cumulative amounts allocated to the
+    /// recipients, and the claims must be made via [`fn@crate::sablier_merkle_instant::claim_cumulative`].
+    /// - If `eligibility_rule` is set, every claim instruction checks the recipient's holdings, using the accounts
+    /// provided as remaining accounts.
+    /// - If `token_claim_fee` is set, the fee is deducted from the claimed amount and transferred to the treasury's ATA,
+    /// and the claims cannot be made via [`fn@crate::sablier_merkle_instant::claim_multiple`]. Such campaigns cannot
+    /// airdrop additional tokens.
+    ///
+    /// # Requirements
+    ///
+    /// - `grace_period` must not exceed 90 days.
+    /// - If `leaf_format` is [`state::LeafFormat::Jito`], `claim_mode` must be [`state::ClaimMode::Cumulative`], as
+    /// the index is not part of the leaf and the claims must thus be tracked per recipient.
+    /// - If `token_claim_fee` is set, `claim_mode` must not be [`state::ClaimMode::Cumulative`], and a fee in basis
+    /// points must not exceed 10%.
+    /// - The program must not be paused.
+    /// - The airdrop token must be in the mint list if the mint list mode is [`state::MintListMode::Allowlist`], and
+    /// must not be in it if the mode is [`state::MintListMode::Denylist`].
+    /// - The campaign address must not have been retired by the closure or cancellation of a previous campaign.
     #[allow(clippy::too_many_arguments)]
     pub fn create_campaign(
         ctx: Context<CreateCampaign>,
@@ -133,6 +752,11 @@ pub mod sablier_merkle_instant {
         ipfs_cid: String,
         aggregate_amount: u64,
         recipient_count: u32,
+        claim_mode: state::ClaimMode,
+        grace_period: u64,
+        leaf_format: state::LeafFormat,
+        eligibility_rule: Option<state::EligibilityRule>,
+        token_claim_fee: Option<state::TokenClaimFee>,
     ) -> Result<()> {
         instructions::create_campaign::handler(
             ctx,
@@ -143,9 +767,116 @@ pub mod sablier_merkle_instant {
             ipfs_cid,
             aggregate_amount,
             recipient_count,
+            claim_mode,
+            grace_period,
+            leaf_format,
+            eligibility_rule,
+            token_claim_fee,
+        )
+    }
+
+    /// Allocates the claim bitmap tracking the claims of the indexes in the range `[chunk_index * CLAIMS_PER_BITMAP,
+    /// (chunk_index + 1) * CLAIMS_PER_BITMAP)`.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `payer` The transaction signer, paying for the rent of the claim bitmap.
+    /// - `campaign` The account that stores the campaign details.
+    ///
+    /// # Parameters
+    ///
+    /// - `chunk_index` The index of the chunk of Merkle tree indexes covered by the bitmap.
+    ///
+    /// # Notes
+    ///
+    /// - Anyone can allocate a claim bitmap, so that the claims are not blocked if the campaign creator has not
+    /// allocated the one covering them.
+    /// - The rent of the claim bitmap is refunded to the campaign creator when the campaign is closed or canceled.
+    ///
+    /// # Requirements
+    ///
+    /// - The campaign must track claims in bitmaps.
+    /// - The claim bitmap must not exist already.
+    pub fn create_claim_bitmap(ctx: Context<CreateClaimBitmap>, chunk_index: u32) -> Result<()> {
+        instructions::create_claim_bitmap::handler(ctx, chunk_index)
+    }
+
+    /// Creates a Merkle Instant airdrop campaign distributing native SOL, which is held by the campaign account.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `creator` The transaction signer and the campaign creator.
+    ///
+    /// # Parameters
+    ///
+    /// - The same parameters as [`fn@crate::sablier_merkle_instant::create_campaign`], with the amounts in lamports.
+    ///
+    /// # Notes
+    ///
+    /// - The campaign is funded by transferring SOL to the campaign account, including a 0.001 SOL buffer on top of
+    /// the aggregate amount, as the claims cannot dip into the rent-exempt minimum and the buffer.
+    /// - The native mint is used as the airdrop token mint, in particular in the campaign address.
+    /// - Emits a [`crate::utils::events::CreateCampaign`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - The same requirements as [`fn@crate::sablier_merkle_instant::create_campaign`].
+    /// - `claim_mode` must not be [`state::ClaimMode::Cumulative`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_native_campaign(
+        ctx: Context<CreateNativeCampaign>,
+        merkle_root: [u8; 32],
+        campaign_start_time: u64,
+        expiration_time: u64,
+        name: String,
+        ipfs_cid: String,
+        aggregate_amount: u64,
+        recipient_count: u32,
+        claim_mode: state::ClaimMode,
+        grace_period: u64,
+        leaf_format: state::LeafFormat,
+    ) -> Result<()> {
+        instructions::create_native_campaign::handler(
+            ctx,
+            merkle_root,
+            campaign_start_time,
+            expiration_time,
+            name,
+            ipfs_cid,
+            aggregate_amount,
+            recipient_count,
+            claim_mode,
+            grace_period,
+            leaf_format,
         )
     }
 
+    /// Updates the expiration time of a campaign, e.g. to extend it when the claim rate is low.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `campaign_creator` The transaction signer and the campaign creator.
+    /// - `campaign` The account that stores the campaign details.
+    ///
+    /// # Parameters
+    ///
+    /// - `new_expiration_time` The new time when the campaign expires, in seconds since the Unix epoch. A value of
+    /// zero means the campaign does not expire.
+    ///
+    /// # Notes
+    ///
+    /// - The expiration time can also be shortened, as long as the minimum notice is respected.
+    /// - Emits a [`crate::utils::events::CampaignExpirationExtended`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - The signer must be the actual campaign creator.
+    /// - The campaign must not have expired.
+    /// - `new_expiration_time` must be zero, OR at least 7 days after the current time.
+    pub fn extend_expiration(ctx: Context<ExtendExpiration>, new_expiration_time: u64) -> Result<()> {
+        instructions::extend_expiration::handler(ctx, new_expiration_time)
+    }
+
     /// Initializes the program with the provided fee collector address.
     ///
     /// # Accounts Expected
@@ -166,10 +897,239 @@ pub mod sablier_merkle_instant {
         instructions::initialize::handler(ctx, fee_collector, chainlink_program, chainlink_sol_usd_feed)
     }
 
+    /// Migrates a campaign created by a prior version of the program to the current layout, by growing the account to
+    /// fit the current campaign data, while keeping its address.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `signer` The transaction signer, paying for the additional rent.
+    /// - `campaign` The account that stores the campaign details, in the prior layout.
+    ///
+    /// # Notes
+    ///
+    /// - The campaign address was derived from the Merkle root and the expiration time at creation, which are kept in
+    /// the campaign as its legacy seeds, so that the campaign can still sign the transfers of the airdropped tokens
+    /// once the Merkle root or the expiration time is updated.
+    /// - The campaign keeps the behavior of the prior version of the program: claims tracked with receipts, leaves in
+    /// the Sablier format, and a 7-day grace period. The aggregate amount is unknown, and thus it is reported as such
+    /// by [`fn@crate::sablier_merkle_instant::campaign_stats`]. The claim statistics start from the migration.
+    /// - The claim receipts created by the prior version of the program hold no claim details, but still mark the
+    /// indexes as claimed. Once the campaign is closed, their rent is refunded to the treasury via
+    /// [`fn@crate::sablier_merkle_instant::close_claim_receipts`].
+    /// - Anyone can migrate a campaign, as the migration does not change any of its existing fields.
+    /// - Until the campaign is migrated, the instructions that load it fail to deserialize it.
+    ///
+    /// # Requirements
+    ///
+    /// - The campaign must be owned by the program, and must not have been migrated already.
+    pub fn migrate_campaign(ctx: Context<MigrateCampaign>) -> Result<()> {
+        instructions::migrate_campaign::handler(ctx)
+    }
+
+    /// Migrates a treasury initialized by a prior version of the program to the current layout, by growing the account
+    /// to fit the fee split, the pause flag and the mint list mode.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `signer` The transaction signer, paying for the additional rent.
+    ///
+    /// # Notes
+    ///
+    /// - The new fields are zero-initialized: an empty fee split, an unpaused program and an open mint list.
+    /// - Anyone can migrate the treasury, as the migration does not change any of its existing fields.
+    /// - Until the treasury is migrated, the instructions that load it fail to deserialize it.
+    ///
+    /// # Requirements
+    ///
+    /// - The treasury must have been initialized, and must not have been migrated already.
+    pub fn migrate_treasury(ctx: Context<MigrateTreasury>) -> Result<()> {
+        instructions::migrate_treasury::handler(ctx)
+    }
+
+    /// Pauses the program, blocking the creation of new campaigns and the claims until it is unpaused.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `fee_collector` The transaction signer and the fee collector.
+    ///
+    /// # Notes
+    ///
+    /// - Clawbacks and campaign cancellations and closures remain allowed, so that the funds can always be recovered.
+    /// - Emits a [`crate::utils::events::ProgramPaused`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - `fee_collector` must be the treasury's fee collector.
+    /// - The program must not be paused.
+    pub fn pause(ctx: Context<Pause>) -> Result<()> {
+        instructions::pause::handler(ctx)
+    }
+
+    /// Removes a mint from the mint list.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `fee_collector` The transaction signer and the fee collector.
+    /// - `mint` The mint to remove from the mint list.
+    ///
+    /// # Notes
+    ///
+    /// - The rent of the mint list entry is refunded to the fee collector.
+    /// - Emits a [`crate::utils::events::MintRemovedFromList`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - `fee_collector` must be the treasury's fee collector.
+    /// - The mint must be in the mint list.
+    pub fn remove_from_mint_list(ctx: Context<RemoveFromMintList>) -> Result<()> {
+        instructions::remove_from_mint_list::handler(ctx)
+    }
+
+    /// Sets the fee split of the treasury, i.e. the recipients among which the collected fees are distributed.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `fee_collector` The transaction signer and the fee collector.
+    ///
+    /// # Parameters
+    ///
+    /// - `fee_split` The recipients and their shares of the collected fees, in basis points. An empty value disables
+    /// the fee split.
+    ///
+    /// # Notes
+    ///
+    /// - The fees owed to the recipients kept in the fee split carry over, while the ones owed to the removed
+    /// recipients return to the collectible amount.
+    /// - Emits a [`crate::utils::events::FeeSplitSet`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - `fee_collector` must be authorized for fee collection.
+    /// - `fee_split` must not have more than 10 recipients.
+    /// - If `fee_split` is not empty, the shares must be non-zero and sum to 10,000 basis points, and the recipients must
+    /// be unique.
+    pub fn set_fee_split(ctx: Context<SetFeeSplit>, fee_split: Vec<state::FeeShare>) -> Result<()> {
+        instructions::set_fee_split::handler(ctx, fee_split)
+    }
+
+    /// Sets the mode of the mint list, which determines the airdrop tokens allowed upon the creation of a campaign.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `fee_collector` The transaction signer and the fee collector.
+    ///
+    /// # Parameters
+    ///
+    /// - `mint_list_mode` The new mode of the mint list: open to any mint, restricted to the listed mints, or open to
+    /// any mint except the listed ones.
+    ///
+    /// # Notes
+    ///
+    /// - The mode only applies to the creation of new campaigns, and the existing campaigns are not affected.
+    /// - Emits a [`crate::utils::events::MintListModeSet`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - `fee_collector` must be the treasury's fee collector.
+    pub fn set_mint_list_mode(ctx: Context<SetMintListMode>, mint_list_mode: state::MintListMode) -> Result<()> {
+        instructions::set_mint_list_mode::handler(ctx, mint_list_mode)
+    }
+
+    /// Unpauses the program, allowing the creation of new campaigns and the claims again.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `fee_collector` The transaction signer and the fee collector.
+    ///
+    /// # Notes
+    ///
+    /// - Emits a [`crate::utils::events::ProgramUnpaused`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - `fee_collector` must be the treasury's fee collector.
+    /// - The program must be paused.
+    pub fn unpause(ctx: Context<Pause>) -> Result<()> {
+        instructions::unpause::handler(ctx)
+    }
+
+    /// Updates the Merkle root and the IPFS CID of a campaign, e.g. to correct the list of recipients.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `campaign_creator` The transaction signer and the campaign creator.
+    /// - `campaign` The account that stores the campaign details.
+    ///
+    /// # Parameters
+    ///
+    /// - `merkle_root` The new Merkle root of the claim data.
+    /// - `ipfs_cid` The new content identifier for indexing the campaign on IPFS.
+    ///
+    /// # Notes
+    ///
+    /// - Emits a [`crate::utils::events::UpdateMerkleRoot`] event.
+    ///
+    /// # Requirements
+    ///
+    /// - The signer must be the actual campaign creator.
+    /// - The campaign must not have expired.
+    /// - The campaign must not have started, OR no claim must have been made, unless the campaign tracks cumulative
+    /// claims.
+    /// - `ipfs_cid` must not exceed 59 characters.
+    pub fn update_merkle_root(ctx: Context<UpdateMerkleRoot>, merkle_root: [u8; 32], ipfs_cid: String) -> Result<()> {
+        instructions::update_merkle_root::handler(ctx, merkle_root, ipfs_cid)
+    }
+
+    /// Appends elements of a Merkle proof to the claimer's proof buffer for a campaign, creating it if needed.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `claimer` The transaction signer, who pays for the proof buffer.
+    /// - `campaign` The account that stores the campaign details.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof_elements` The next elements of the Merkle proof, in order.
+    ///
+    /// # Notes
+    ///
+    /// - A proof too large to fit in a single transaction can be written across multiple transactions, and then used
+    /// via [`fn@crate::sablier_merkle_instant::claim_with_proof_account`].
+    ///
+    /// # Requirements
+    ///
+    /// - The total length of the proof must not exceed 32 elements.
+    pub fn write_proof_buffer(ctx: Context<WriteProofBuffer>, proof_elements: Vec<[u8; 32]>) -> Result<()> {
+        instructions::write_proof_buffer::handler(ctx, proof_elements)
+    }
+
     // -------------------------------------------------------------------------- //
     //                           READ-ONLY INSTRUCTIONS                           //
     // -------------------------------------------------------------------------- //
 
+    /// Returns the claim statistics of the campaign.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `campaign` The account that stores the campaign details.
+    /// - `airdrop_token_mint` The mint of the airdropped token.
+    /// - `airdrop_token_program` The Token Program of the airdropped token.
+    /// - `campaign_ata` The campaign's ATA for the airdropped token, required unless the campaign airdrops native SOL.
+    ///
+    /// # Notes
+    ///
+    /// - The unclaimed amount is relative to the aggregate amount declared at creation, and thus it does not account
+    /// for the clawbacks.
+    /// - The campaigns migrated from the prior version of the program did not record the aggregate amount, so both the
+    /// aggregate amount and the unclaimed amount are reported as unknown, i.e. `None`, rather than zero.
+    /// - For native campaigns, the reported balance is the SOL available for claims, i.e. the campaign balance less
+    /// the rent-exempt minimum and a 0.001 SOL buffer.
+    /// - For multi-token campaigns, the statistics cover the main airdrop token, except for the amounts claimed of the
+    /// additional airdrop tokens, which are reported separately.
+    pub fn campaign_stats(ctx: Context<CampaignStats>) -> Result<CampaignStatsData> {
+        instructions::campaign_stats::handler(ctx)
+    }
+
     /// Retrieves the campaign details.
     ///
     /// # Accounts Expected
@@ -189,17 +1149,72 @@ pub mod sablier_merkle_instant {
         instructions::claim_fee_in_lamports::handler(ctx)
     }
 
+    /// Returns the claim statuses of a range of indexes, packed in a bitset.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `campaign` The account that stores the campaign details.
+    /// - `remaining_accounts` If the campaign tracks claims with receipts, the claim receipt of each index in the
+    /// range, in order, whether it exists or not. If the campaign tracks claims in bitmaps, the claim bitmaps covering
+    /// the range, in order.
+    ///
+    /// # Parameters
+    ///
+    /// - `start_index` The first index of the range.
+    /// - `count` The number of indexes in the range.
+    ///
+    /// # Notes
+    ///
+    /// - The bit `i % 8` of the byte `i / 8`, the least significant bit first, is set if the index `start_index + i`
+    /// has been claimed.
+    ///
+    /// # Requirements
+    ///
+    /// - The campaign must not track cumulative claims.
+    /// - `count` must not exceed 8160, so that the bitset fits in the return data.
+    /// - The last index of the range, `start_index + count - 1`, must fit in a `u32`.
+    /// - The remaining accounts must match the range as described above.
+    pub fn claimed_bitmap_of<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CampaignView<'info>>,
+        start_index: u32,
+        count: u32,
+    ) -> Result<Vec<u8>> {
+        instructions::claimed_bitmap_of::handler(ctx, start_index, count)
+    }
+
+    /// Retrieves the details of the claim of the given index, stored in its claim receipt.
+    ///
+    /// # Accounts Expected
+    ///
+    /// - `campaign` The campaign of the claim receipt, which may have been closed.
+    /// - `claim_receipt` The claim receipt of `index`.
+    ///
+    /// # Parameters
+    ///
+    /// - `index` The index of the recipient in the Merkle tree.
+    ///
+    /// # Notes
+    ///
+    /// - Only available for the campaigns tracking claims with receipts.
+    /// - The claim receipts created by the prior version of the program hold no claim details, so all the returned
+    /// fields are zero for them.
+    pub fn claim_receipt_view(ctx: Context<ClaimReceiptView>, _index: u32) -> Result<state::ClaimReceipt> {
+        instructions::claim_receipt_view::handler(ctx)
+    }
+
     /// Returns a flag indicating whether a claim has been made for the given index.
     ///
     /// # Accounts Expected
     ///
     /// - `campaign` The account that stores the campaign details.
+    /// - `claim_receipt` The claim receipt of `index`, if the campaign tracks claims with receipts.
+    /// - `claim_bitmap` The claim bitmap covering `index`, if the campaign tracks claims in bitmaps.
     ///
     /// # Parameters
     ///
-    /// - `_index` The index of the recipient in the Merkle tree.
-    pub fn has_claimed(ctx: Context<HasClaimed>, _index: u32) -> Result<bool> {
-        Ok(!ctx.accounts.claim_receipt.data_is_empty())
+    /// - `index` The index of the recipient in the Merkle tree.
+    pub fn has_claimed(ctx: Context<HasClaimed>, index: u32) -> Result<bool> {
+        instructions::has_claimed::handler(ctx, index)
     }
 
     /// Returns a flag indicating whether the campaign has expired.
@@ -220,7 +1235,7 @@ pub mod sablier_merkle_instant {
     /// # Notes
     ///
     /// - A return value of `false` indicates: No claim has been made yet, OR the current timestamp does not exceed
-    /// seven days after the first claim.
+    /// the campaign's grace period after the first claim.
     pub fn has_grace_period_passed(ctx: Context<CampaignView>) -> Result<bool> {
         instructions::has_grace_period_passed::handler(ctx)
     }
@@ -6,52 +6,264 @@ use crate::utils::{constants::*, time::get_current_time};
 #[account]
 #[derive(InitSpace)]
 pub struct Campaign {
+    #[max_len(MAX_ADDITIONAL_AIRDROP_TOKENS)]
+    pub additional_airdrop_token_mints: Vec<Pubkey>,
+    #[max_len(MAX_ADDITIONAL_AIRDROP_TOKENS)]
+    pub additional_total_claimed_amounts: Vec<u64>,
+    pub aggregate_amount: u64,
     pub airdrop_token_mint: Pubkey,
     pub bump: u8,
     pub campaign_start_time: u64,
+    pub claim_count: u32,
+    pub claim_mode: ClaimMode,
     pub creator: Pubkey,
+    pub eligibility_rule: Option<EligibilityRule>,
     pub expiration_time: u64,
     pub first_claim_time: u64,
+    pub grace_period: u64,
     #[max_len(CAMPAIGN_IPFS_CID_SIZE as usize)]
     pub ipfs_cid: String,
+    pub is_native: bool,
+    pub last_claim_time: u64,
+    pub leaf_format: LeafFormat,
+    pub legacy_seeds: Option<LegacySeeds>,
     pub merkle_root: [u8; 32],
     #[max_len(CAMPAIGN_NAME_SIZE as usize)]
     pub name: String,
+    pub token_claim_fee: Option<TokenClaimFee>,
+    pub total_claimed_amount: u64,
+    pub total_clawed_back: u64,
+}
+
+/// The seeds of the address of a campaign created before the Merkle root and the expiration time were decoupled from
+/// the campaign address, i.e. their values at creation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub struct LegacySeeds {
+    pub expiration_time: u64,
+    pub merkle_root: [u8; 32],
+}
+
+/// The layout of a campaign created before the Merkle root and the expiration time were decoupled from the campaign
+/// address, which is converted to the current layout by [`fn@crate::sablier_merkle_instant::migrate_campaign`].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LegacyCampaign {
+    pub airdrop_token_mint: Pubkey,
+    pub bump: u8,
+    pub campaign_start_time: u64,
+    pub creator: Pubkey,
+    pub expiration_time: u64,
+    pub first_claim_time: u64,
+    pub ipfs_cid: String,
+    pub merkle_root: [u8; 32],
+    pub name: String,
+}
+
+/// The storage used to track which Merkle tree indexes have been claimed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub enum ClaimMode {
+    /// One [`crate::state::ClaimReceipt`] account is created for each claim.
+    Receipt,
+    /// Claims are tracked in fixed-size [`crate::state::ClaimBitmap`] accounts, one bit per index.
+    Bitmap,
+    /// Each leaf carries the cumulative amount allocated to its recipient, and the amount claimed so far by each
+    /// recipient is tracked in a [`crate::state::CumulativeClaim`] account.
+    Cumulative,
+}
+
+/// The token holdings that a recipient must have at claim time to be eligible for the airdrop.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub struct EligibilityRule {
+    /// Whether `key` is a token mint or an NFT collection.
+    pub kind: EligibilityKind,
+    /// The mint of the required token, or the mint of the required NFT collection.
+    pub key: Pubkey,
+    /// The minimum balance of the recipient's token account.
+    pub min_amount: u64,
+}
+
+/// The kind of token required by an [`EligibilityRule`].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub enum EligibilityKind {
+    /// The recipient must hold the token of the given mint.
+    Mint,
+    /// The recipient must hold an NFT that is a verified member of the given collection.
+    Collection,
+}
+
+/// The claim fee charged in the airdrop token, deducted from the claimed amount and transferred to the treasury's ATA.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub enum TokenClaimFee {
+    /// A share of the claimed amount, in basis points.
+    BasisPoints(u16),
+    /// A fixed amount of the airdrop token per claim.
+    Fixed(u64),
+}
+
+/// The encoding of the Merkle tree leaves and nodes, so that campaigns can be created from trees produced by the
+/// tooling of other Solana distributors.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace, PartialEq, Eq)]
+pub enum LeafFormat {
+    /// `keccak(keccak(index as u32 LE ‖ recipient ‖ amount as u64 LE))`, with the pairs hashed with keccak.
+    Sablier,
+    /// `keccak(index as u64 LE ‖ recipient ‖ amount as u64 LE)`, with the pairs hashed with keccak.
+    Saber,
+    /// `sha256(0x00 ‖ sha256(recipient ‖ amount as u64 LE ‖ 0 as u64 LE))`, with the pairs hashed with sha256 and
+    /// prefixed with `0x01`. The index is not part of the leaf.
+    Jito,
 }
 
 impl Campaign {
+    /// State update for the [`fn@crate::sablier_merkle_instant::add_airdrop_token`] instruction.
+    pub fn add_airdrop_token(&mut self, airdrop_token_mint: Pubkey) -> Result<()> {
+        self.additional_airdrop_token_mints.push(airdrop_token_mint);
+        self.additional_total_claimed_amounts.push(0);
+
+        Ok(())
+    }
+
     /// State update for the [`fn@crate::sablier_merkle_instant::claim`] instruction.
-    pub fn claim(&mut self) -> Result<()> {
+    pub fn claim(&mut self, amount: u64) -> Result<()> {
+        let current_time = get_current_time()?;
+
         // Update the first claim time to the current time.
         if self.first_claim_time == 0 {
-            self.first_claim_time = get_current_time()?;
+            self.first_claim_time = current_time;
+        }
+
+        // Update the claim statistics.
+        self.claim_count = self.claim_count.checked_add(1).expect("Claim count overflow");
+        self.last_claim_time = current_time;
+        self.total_claimed_amount = self.total_claimed_amount.checked_add(amount).expect("Claimed amount overflow");
+
+        Ok(())
+    }
+
+    /// State update for the [`fn@crate::sablier_merkle_instant::claim_multi_token`] instruction.
+    pub fn claim_multi_token(&mut self, amounts: &[u64]) -> Result<()> {
+        // Update the claim statistics of the main airdrop token.
+        self.claim(amounts[0])?;
+
+        // Update the amounts claimed of the additional airdrop tokens.
+        for (total_claimed_amount, amount) in self.additional_total_claimed_amounts.iter_mut().zip(&amounts[1..]) {
+            *total_claimed_amount = total_claimed_amount.checked_add(*amount).expect("Claimed amount overflow");
         }
 
         Ok(())
     }
 
+    /// State update for the [`fn@crate::sablier_merkle_instant::clawback`] instruction.
+    pub fn clawback(&mut self, amount: u64) -> Result<()> {
+        self.total_clawed_back = self.total_clawed_back.checked_add(amount).expect("Clawed back amount overflow");
+
+        Ok(())
+    }
+
     /// State update for the [`fn@crate::sablier_merkle_instant::create_campaign`] instruction.
     #[allow(clippy::too_many_arguments)]
     pub fn create(
         &mut self,
+        aggregate_amount: u64,
         airdrop_token_mint: Pubkey,
         bump: u8,
         campaign_start_time: u64,
+        claim_mode: ClaimMode,
         creator: Pubkey,
+        eligibility_rule: Option<EligibilityRule>,
         expiration_time: u64,
+        grace_period: u64,
         ipfs_cid: String,
+        is_native: bool,
+        leaf_format: LeafFormat,
         merkle_root: [u8; 32],
         name: String,
+        token_claim_fee: Option<TokenClaimFee>,
     ) -> Result<()> {
+        self.aggregate_amount = aggregate_amount;
         self.airdrop_token_mint = airdrop_token_mint;
         self.bump = bump;
         self.campaign_start_time = campaign_start_time;
+        self.claim_mode = claim_mode;
         self.creator = creator;
+        self.eligibility_rule = eligibility_rule;
         self.expiration_time = expiration_time;
+        self.grace_period = grace_period;
         self.ipfs_cid = ipfs_cid;
+        self.is_native = is_native;
+        self.leaf_format = leaf_format;
         self.merkle_root = merkle_root;
         self.name = name;
+        self.token_claim_fee = token_claim_fee;
+
+        Ok(())
+    }
+
+    /// State update for the [`fn@crate::sablier_merkle_instant::extend_expiration`] instruction.
+    pub fn extend_expiration(&mut self, new_expiration_time: u64) -> Result<()> {
+        self.expiration_time = new_expiration_time;
 
         Ok(())
     }
+
+    /// Returns the seeds of the campaign address, with which the campaign signs the transfers of the airdropped tokens.
+    /// Migrated campaigns keep the address derived from their Merkle root and expiration time at creation.
+    pub fn signer_seeds(&self) -> Vec<Vec<u8>> {
+        let mut signer_seeds = vec![seeds::CAMPAIGN.to_vec(), self.creator.to_bytes().to_vec()];
+        if let Some(legacy_seeds) = &self.legacy_seeds {
+            signer_seeds.push(legacy_seeds.merkle_root.to_vec());
+        }
+        signer_seeds.push(self.campaign_start_time.to_le_bytes().to_vec());
+        if let Some(legacy_seeds) = &self.legacy_seeds {
+            signer_seeds.push(legacy_seeds.expiration_time.to_le_bytes().to_vec());
+        }
+        signer_seeds.push(self.name.as_bytes().to_vec());
+        signer_seeds.push(self.airdrop_token_mint.to_bytes().to_vec());
+        signer_seeds.push(vec![self.bump]);
+
+        signer_seeds
+    }
+
+    /// State update for the [`fn@crate::sablier_merkle_instant::update_merkle_root`] instruction.
+    pub fn update_merkle_root(&mut self, ipfs_cid: String, merkle_root: [u8; 32]) -> Result<()> {
+        self.ipfs_cid = ipfs_cid;
+        self.merkle_root = merkle_root;
+
+        Ok(())
+    }
+}
+
+impl From<LegacyCampaign> for Campaign {
+    /// State update for the [`fn@crate::sablier_merkle_instant::migrate_campaign`] instruction. The campaign keeps
+    /// the behavior of the prior version of the program: claims tracked with receipts, leaves in the Sablier format,
+    /// and a 7-day grace period. The aggregate amount is unknown, and thus it is reported as such rather than as zero.
+    /// The statistics start from the migration.
+    fn from(legacy: LegacyCampaign) -> Self {
+        Campaign {
+            additional_airdrop_token_mints: Vec::new(),
+            additional_total_claimed_amounts: Vec::new(),
+            aggregate_amount: 0,
+            airdrop_token_mint: legacy.airdrop_token_mint,
+            bump: legacy.bump,
+            campaign_start_time: legacy.campaign_start_time,
+            claim_count: 0,
+            claim_mode: ClaimMode::Receipt,
+            creator: legacy.creator,
+            eligibility_rule: None,
+            expiration_time: legacy.expiration_time,
+            first_claim_time: legacy.first_claim_time,
+            grace_period: LEGACY_GRACE_PERIOD,
+            ipfs_cid: legacy.ipfs_cid,
+            is_native: false,
+            last_claim_time: 0,
+            leaf_format: LeafFormat::Sablier,
+            legacy_seeds: Some(LegacySeeds {
+                expiration_time: legacy.expiration_time,
+                merkle_root: legacy.merkle_root,
+            }),
+            merkle_root: legacy.merkle_root,
+            name: legacy.name,
+            token_claim_fee: None,
+            total_claimed_amount: 0,
+            total_clawed_back: 0,
+        }
+    }
 }
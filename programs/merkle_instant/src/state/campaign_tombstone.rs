@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+/// Marks the address of a closed or canceled campaign as retired, so that no campaign can be created at it again and
+/// inherit the claim bitmaps, claim receipts and cumulative claims that may outlive the campaign.
+#[account]
+#[derive(InitSpace)]
+pub struct CampaignTombstone {
+    pub bump: u8,
+}
+
+impl CampaignTombstone {
+    /// State update for the [`fn@crate::sablier_merkle_instant::close_campaign`],
+    /// [`fn@crate::sablier_merkle_instant::close_native_campaign`] and
+    /// [`fn@crate::sablier_merkle_instant::cancel_campaign`] instructions.
+    pub fn create(&mut self, bump: u8) -> Result<()> {
+        self.bump = bump;
+
+        Ok(())
+    }
+}
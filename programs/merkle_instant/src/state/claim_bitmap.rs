@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::utils::constants::{CLAIMS_PER_BITMAP, CLAIM_BITMAP_SIZE};
+
+/// Tracks the claim status of a contiguous range of Merkle tree indexes, one bit per index.
+///
+/// The range covered by a bitmap is `[chunk_index * CLAIMS_PER_BITMAP, (chunk_index + 1) * CLAIMS_PER_BITMAP)`, where
+/// `chunk_index` is part of the account seeds.
+///
+/// The bitmap is a zero-copy account, so that it is read and written in place rather than being copied onto the stack or
+/// the heap, which would exhaust them when several bitmaps are loaded in the same instruction.
+#[account(zero_copy)]
+pub struct ClaimBitmap {
+    pub bits: [u8; CLAIM_BITMAP_SIZE],
+    pub campaign: Pubkey,
+}
+
+impl ClaimBitmap {
+    /// State update for the [`fn@crate::sablier_merkle_instant::claim`] instruction.
+    pub fn claim(&mut self, index: u32) -> Result<()> {
+        let (byte, mask) = Self::position(index);
+        self.bits[byte] |= mask;
+
+        Ok(())
+    }
+
+    /// State update for the [`fn@crate::sablier_merkle_instant::create_claim_bitmap`] instruction.
+    pub fn create(&mut self, campaign: Pubkey) -> Result<()> {
+        // The bits are zero-initialized upon the account creation.
+        self.campaign = campaign;
+
+        Ok(())
+    }
+
+    /// Returns a flag indicating whether the given index has been claimed.
+    pub fn is_claimed(&self, index: u32) -> bool {
+        let (byte, mask) = Self::position(index);
+        self.bits[byte] & mask != 0
+    }
+
+    /// Returns the byte offset and the bit mask of the given index within the bitmap.
+    fn position(index: u32) -> (usize, u8) {
+        let bit = index % CLAIMS_PER_BITMAP;
+        ((bit / 8) as usize, 1 << (bit % 8))
+    }
+}
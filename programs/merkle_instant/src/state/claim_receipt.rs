@@ -1,6 +1,39 @@
 use anchor_lang::prelude::*;
 
+use crate::utils::{constants::LEGACY_CLAIM_RECEIPT_SIZE, time::get_current_time};
+
+/// Marks an index of a campaign as claimed, and records the details of the claim.
 #[account]
-#[derive(InitSpace)]
-// This struct is empty to avoid manually allocating space.
-pub struct ClaimReceipt {}
+#[derive(Default, InitSpace)]
+pub struct ClaimReceipt {
+    /// The amount claimed. For multi-token campaigns, the amount of the main airdrop token.
+    pub amount: u64,
+    /// The time when the claim was made, in seconds since the Unix epoch.
+    pub claim_time: u64,
+    /// The signer who paid the rent of the receipt, and to whom it is refunded when the receipt is closed.
+    pub claimer: Pubkey,
+    /// The claim fee paid by the claimer, in lamports. For batch claims, the fee is recorded in the first receipt.
+    pub fee_in_lamports: u64,
+    /// The recipient of the airdrop.
+    pub recipient: Pubkey,
+}
+
+impl ClaimReceipt {
+    /// Whether the account is a claim receipt created by a prior version of the program, which holds no claim details.
+    pub fn is_legacy(account: &AccountInfo) -> Result<bool> {
+        Ok(account.owner == &crate::ID &&
+            account.data_len() == LEGACY_CLAIM_RECEIPT_SIZE &&
+            account.try_borrow_data()?[..] == *ClaimReceipt::DISCRIMINATOR)
+    }
+
+    /// State update for the [`fn@crate::sablier_merkle_instant::claim`] instruction.
+    pub fn create(&mut self, amount: u64, claimer: Pubkey, fee_in_lamports: u64, recipient: Pubkey) -> Result<()> {
+        self.amount = amount;
+        self.claim_time = get_current_time()?;
+        self.claimer = claimer;
+        self.fee_in_lamports = fee_in_lamports;
+        self.recipient = recipient;
+
+        Ok(())
+    }
+}
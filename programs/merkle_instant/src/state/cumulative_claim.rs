@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Tracks the amount claimed so far by a recipient of a campaign whose leaves carry cumulative amounts.
+#[account]
+#[derive(InitSpace)]
+pub struct CumulativeClaim {
+    pub claimed_amount: u64,
+}
+
+impl CumulativeClaim {
+    /// State update for the [`fn@crate::sablier_merkle_instant::claim_cumulative`] instruction.
+    pub fn claim(&mut self, amount: u64) -> Result<()> {
+        self.claimed_amount = self.claimed_amount.checked_add(amount).expect("Claimed amount overflow");
+
+        Ok(())
+    }
+}
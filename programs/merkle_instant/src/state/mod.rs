@@ -1,7 +1,17 @@
 pub mod campaign;
+pub mod campaign_tombstone;
+pub mod claim_bitmap;
 pub mod claim_receipt;
+pub mod cumulative_claim;
+pub mod mint_list_entry;
+pub mod proof_buffer;
 pub mod treasury;
 
 pub use campaign::*;
+pub use campaign_tombstone::*;
+pub use claim_bitmap::*;
 pub use claim_receipt::*;
+pub use cumulative_claim::*;
+pub use mint_list_entry::*;
+pub use proof_buffer::*;
 pub use treasury::*;
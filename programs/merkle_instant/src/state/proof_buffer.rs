@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::utils::constants::MAX_MERKLE_PROOF_LENGTH;
+
+/// Temporarily stores a Merkle proof too large to fit in the data of a claim instruction.
+///
+/// The proof is written across multiple transactions via [`fn@crate::sablier_merkle_instant::write_proof_buffer`], and
+/// the buffer is closed when the proof is consumed by [`fn@crate::sablier_merkle_instant::claim_with_proof_account`].
+#[account]
+#[derive(InitSpace)]
+pub struct ProofBuffer {
+    pub campaign: Pubkey,
+    /// The signer who paid the rent of the buffer, and to whom it is refunded when the buffer is closed.
+    pub claimer: Pubkey,
+    #[max_len(MAX_MERKLE_PROOF_LENGTH)]
+    pub proof: Vec<[u8; 32]>,
+}
+
+impl ProofBuffer {
+    /// State update for the [`fn@crate::sablier_merkle_instant::write_proof_buffer`] instruction.
+    pub fn write(&mut self, campaign: Pubkey, claimer: Pubkey, proof_elements: Vec<[u8; 32]>) -> Result<()> {
+        self.campaign = campaign;
+        self.claimer = claimer;
+        self.proof.extend(proof_elements);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,356 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        program::{invoke, invoke_signed},
+        system_instruction::{allocate, assign, create_account, transfer},
+    },
+};
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    state::{Campaign, ClaimBitmap, ClaimMode, ClaimReceipt, TokenClaimFee},
+    utils::{
+        constants::{seeds::*, ANCHOR_DISCRIMINATOR_SIZE, CLAIMS_PER_BITMAP, CLAIM_FEE_USD},
+        errors::ErrorCode,
+        events,
+        fee_calculation::{calculate_token_claim_fee, convert_usd_fee_to_lamports},
+        time::get_current_time,
+        transfer_helper::transfer_tokens,
+        validations::{check_claim, check_eligibility},
+    },
+};
+
+/// The accounts of a single-token claim, as resolved by each of the claim instructions.
+pub struct ClaimAccounts<'a, 'info> {
+    pub airdrop_token_mint: &'a InterfaceAccount<'info, Mint>,
+    pub airdrop_token_program: AccountInfo<'info>,
+    pub campaign: &'a mut Account<'info, Campaign>,
+    pub campaign_ata: AccountInfo<'info>,
+    pub chainlink_program: AccountInfo<'info>,
+    pub chainlink_sol_usd_feed: AccountInfo<'info>,
+    pub claim_bitmap: &'a Option<AccountLoader<'info, ClaimBitmap>>,
+    pub claim_receipt: &'a mut Option<Box<Account<'info, ClaimReceipt>>>,
+    /// The signer paying the claim fee.
+    pub claimer: AccountInfo<'info>,
+    /// The owner of the token account receiving the airdrop.
+    pub destination: Pubkey,
+    pub destination_ata: AccountInfo<'info>,
+    /// The recipient of the leaf.
+    pub recipient: Pubkey,
+    pub treasury: AccountInfo<'info>,
+    pub treasury_ata: Option<AccountInfo<'info>>,
+}
+
+/// Claims the airdrop of a leaf on behalf of its recipient, and transfers it to the destination's ATA. This is the logic
+/// shared by [`fn@crate::sablier_merkle_instant::claim`], [`fn@crate::sablier_merkle_instant::claim_to`],
+/// [`fn@crate::sablier_merkle_instant::claim_with_proof_account`] and
+/// [`fn@crate::sablier_merkle_instant::claim_with_sig`], which only differ in how they resolve the destination and the
+/// Merkle proof, and in how they authorize the claim.
+pub fn claim_core(
+    accounts: ClaimAccounts,
+    index: u32,
+    amount: u64,
+    merkle_proof: Vec<[u8; 32]>,
+    eligibility_accounts: &[AccountInfo],
+) -> Result<()> {
+    let ClaimAccounts {
+        airdrop_token_mint,
+        airdrop_token_program,
+        campaign,
+        campaign_ata,
+        chainlink_program,
+        chainlink_sol_usd_feed,
+        claim_bitmap,
+        claim_receipt,
+        claimer,
+        destination,
+        destination_ata,
+        recipient,
+        treasury,
+        treasury_ata,
+    } = accounts;
+
+    // Check: validate the claim.
+    check_claim(
+        amount,
+        campaign.campaign_start_time,
+        campaign.expiration_time,
+        index,
+        campaign.leaf_format,
+        merkle_proof,
+        campaign.merkle_root,
+        recipient,
+    )?;
+
+    // Check: the recipient holds the required tokens, if the campaign is token-gated.
+    if let Some(eligibility_rule) = &campaign.eligibility_rule {
+        check_eligibility(eligibility_rule, recipient, eligibility_accounts)?;
+    }
+
+    // Interaction: charge the claim fee, which is recorded in the claim receipt, unless the campaign charges it in the
+    // airdrop token.
+    let fee_in_lamports = if campaign.token_claim_fee.is_none() {
+        charge_claim_fee(chainlink_program, chainlink_sol_usd_feed, claimer.clone(), treasury)?
+    } else {
+        0
+    };
+
+    // Effect: mark the index as claimed.
+    let claim_status = record_claim(
+        amount,
+        campaign.claim_mode,
+        claimer.key(),
+        fee_in_lamports,
+        index,
+        recipient,
+        claim_receipt,
+        claim_bitmap,
+    )?;
+
+    // Effect: update the campaign state.
+    campaign.claim(amount)?;
+
+    let campaign_seeds = campaign.signer_seeds();
+    let campaign_seeds: Vec<&[u8]> = campaign_seeds.iter().map(Vec::as_slice).collect();
+    let signer_seeds: &[&[&[u8]]] = &[&campaign_seeds];
+
+    // Interaction: charge the claim fee in the airdrop token, if any.
+    let fee_in_tokens = charge_token_claim_fee(
+        amount,
+        campaign.token_claim_fee,
+        campaign_ata.clone(),
+        treasury_ata,
+        campaign.to_account_info(),
+        airdrop_token_mint.to_account_info(),
+        airdrop_token_program.clone(),
+        airdrop_token_mint.decimals,
+        signer_seeds,
+    )?;
+
+    // Interaction: transfer the claimed amount, net of the fee, from the campaign's ATA to the destination's ATA.
+    transfer_tokens(
+        campaign_ata,
+        destination_ata,
+        campaign.to_account_info(),
+        airdrop_token_mint.to_account_info(),
+        airdrop_token_program,
+        amount - fee_in_tokens,
+        airdrop_token_mint.decimals,
+        signer_seeds,
+    )?;
+
+    // Log the claim.
+    emit!(events::Claim {
+        amount,
+        campaign: campaign.key(),
+        claimer: claimer.key(),
+        claim_receipt: claim_status,
+        destination,
+        fee_in_lamports,
+        fee_in_tokens,
+        index,
+        recipient,
+    });
+
+    Ok(())
+}
+
+/// Marks the index as claimed in the storage used by the campaign's claim mode, and returns the address of the claim
+/// status account, i.e. the claim receipt or the claim bitmap.
+#[allow(clippy::too_many_arguments)]
+pub fn record_claim<'info>(
+    amount: u64,
+    claim_mode: ClaimMode,
+    claimer: Pubkey,
+    fee_in_lamports: u64,
+    index: u32,
+    recipient: Pubkey,
+    claim_receipt: &mut Option<Box<Account<'info, ClaimReceipt>>>,
+    claim_bitmap: &Option<AccountLoader<'info, ClaimBitmap>>,
+) -> Result<Pubkey> {
+    match claim_mode {
+        // The claim receipt is initialized by Anchor, which fails if it already exists.
+        ClaimMode::Receipt => {
+            let claim_receipt = claim_receipt.as_mut().ok_or(ErrorCode::ClaimStatusAccountMissing)?;
+            claim_receipt.create(amount, claimer, fee_in_lamports, recipient)?;
+            Ok(claim_receipt.key())
+        }
+        ClaimMode::Bitmap => {
+            let claim_bitmap = claim_bitmap.as_ref().ok_or(ErrorCode::ClaimStatusAccountMissing)?;
+            mark_claimed_in_bitmap(claim_bitmap, index)?;
+            Ok(claim_bitmap.key())
+        }
+        ClaimMode::Cumulative => Err(ErrorCode::CumulativeClaimRequired.into()),
+    }
+}
+
+/// Same as [`record_claim`], but for a claim status account that is not validated by Anchor, e.g. one passed via the
+/// remaining accounts. The claim receipt, if any, is created by this function.
+#[allow(clippy::too_many_arguments)]
+pub fn record_claim_unchecked<'info>(
+    amount: u64,
+    campaign: Pubkey,
+    claim_mode: ClaimMode,
+    claim_status: &'info AccountInfo<'info>,
+    claimer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    fee_in_lamports: u64,
+    index: u32,
+    recipient: Pubkey,
+    program_id: &Pubkey,
+) -> Result<Pubkey> {
+    match claim_mode {
+        ClaimMode::Receipt => {
+            let index_bytes = index.to_le_bytes();
+            let (claim_receipt_address, bump) =
+                Pubkey::find_program_address(&[CLAIM_RECEIPT, campaign.as_ref(), &index_bytes], program_id);
+
+            // Check: the claim receipt is the one derived from the campaign and the index.
+            if claim_status.key() != claim_receipt_address {
+                return Err(ErrorCode::InvalidClaimReceipt.into());
+            }
+
+            // Check: the claim receipt does not exist yet.
+            if claim_status.owner == program_id {
+                return Err(ErrorCode::AlreadyClaimed.into());
+            }
+
+            create_claim_receipt(
+                claim_status,
+                claimer,
+                system_program,
+                ClaimReceipt {
+                    amount,
+                    claim_time: get_current_time()?,
+                    claimer: claimer.key(),
+                    fee_in_lamports,
+                    recipient,
+                },
+                program_id,
+                &[CLAIM_RECEIPT, campaign.as_ref(), &index_bytes, &[bump]],
+            )?;
+            Ok(claim_status.key())
+        }
+        ClaimMode::Bitmap => {
+            let chunk_index_bytes = (index / CLAIMS_PER_BITMAP).to_le_bytes();
+            let (claim_bitmap_address, _) =
+                Pubkey::find_program_address(&[CLAIM_BITMAP, campaign.as_ref(), &chunk_index_bytes], program_id);
+
+            // Check: the claim bitmap is the one covering the index.
+            if claim_status.key() != claim_bitmap_address {
+                return Err(ErrorCode::InvalidClaimBitmap.into());
+            }
+
+            // The bitmap is written in place, so the next index of the same chunk sees this claim.
+            mark_claimed_in_bitmap(&AccountLoader::<ClaimBitmap>::try_from(claim_status)?, index)?;
+            Ok(claim_status.key())
+        }
+        ClaimMode::Cumulative => Err(ErrorCode::CumulativeClaimRequired.into()),
+    }
+}
+
+/// Marks the index as claimed in the claim bitmap, failing if it has already been claimed.
+fn mark_claimed_in_bitmap(claim_bitmap: &AccountLoader<ClaimBitmap>, index: u32) -> Result<()> {
+    let mut claim_bitmap = claim_bitmap.load_mut()?;
+
+    // Check: the index has not been claimed yet.
+    if claim_bitmap.is_claimed(index) {
+        return Err(ErrorCode::AlreadyClaimed.into());
+    }
+
+    claim_bitmap.claim(index)
+}
+
+/// Creates a claim receipt at the given PDA, paid by the claimer, and writes the claim details in it.
+fn create_claim_receipt<'info>(
+    claim_receipt: &AccountInfo<'info>,
+    claimer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    receipt: ClaimReceipt,
+    program_id: &Pubkey,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let accounts = [claimer.clone(), claim_receipt.clone(), system_program.clone()];
+    let space = ANCHOR_DISCRIMINATOR_SIZE + ClaimReceipt::INIT_SPACE;
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(space);
+    let current_lamports = claim_receipt.lamports();
+
+    if current_lamports == 0 {
+        let create_ix = create_account(claimer.key, claim_receipt.key, rent_exempt_minimum, space as u64, program_id);
+        invoke_signed(&create_ix, &accounts, &[signer_seeds])?;
+    } else {
+        // The PDA has been pre-funded, so it cannot be created with `create_account`.
+        let top_up = rent_exempt_minimum.saturating_sub(current_lamports);
+        if top_up > 0 {
+            invoke(&transfer(claimer.key, claim_receipt.key, top_up), &accounts)?;
+        }
+        invoke_signed(&allocate(claim_receipt.key, space as u64), &accounts, &[signer_seeds])?;
+        invoke_signed(&assign(claim_receipt.key, program_id), &accounts, &[signer_seeds])?;
+    }
+
+    // Write the discriminator and the data of the claim receipt.
+    let mut data = claim_receipt.try_borrow_mut_data()?;
+    receipt.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
+
+/// Charges the claim fee in lamports.
+pub fn charge_claim_fee<'info>(
+    chainlink_program: AccountInfo<'info>,
+    chainlink_sol_usd_feed: AccountInfo<'info>,
+    tx_signer: AccountInfo<'info>,
+    treasury: AccountInfo<'info>,
+) -> Result<u64> {
+    // Calculate the fee in lamports.
+    let fee_in_lamports: u64 = convert_usd_fee_to_lamports(CLAIM_FEE_USD, chainlink_program, chainlink_sol_usd_feed);
+
+    if fee_in_lamports > 0 {
+        // Interaction: transfer the fee from the signer to the treasury.
+        let fee_charging_ix = transfer(&tx_signer.key(), &treasury.key(), fee_in_lamports);
+        invoke(&fee_charging_ix, &[tx_signer, treasury])?;
+    }
+
+    Ok(fee_in_lamports)
+}
+
+/// Charges the claim fee in the airdrop token, if the campaign opted into it, by transferring it from the campaign's
+/// ATA to the treasury's ATA. Returns the fee charged, to be deducted from the claimed amount.
+#[allow(clippy::too_many_arguments)]
+pub fn charge_token_claim_fee<'info>(
+    amount: u64,
+    token_claim_fee: Option<TokenClaimFee>,
+    campaign_ata: AccountInfo<'info>,
+    treasury_ata: Option<AccountInfo<'info>>,
+    campaign: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    decimals: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<u64> {
+    let Some(token_claim_fee) = token_claim_fee else {
+        return Ok(0);
+    };
+
+    // Calculate the fee in the airdrop token.
+    let fee_in_tokens = calculate_token_claim_fee(amount, token_claim_fee)?;
+
+    if fee_in_tokens > 0 {
+        // Check: the treasury's ATA is provided.
+        let treasury_ata = treasury_ata.ok_or(ErrorCode::TreasuryAtaMissing)?;
+
+        // Interaction: transfer the fee from the campaign's ATA to the treasury's ATA.
+        transfer_tokens(
+            campaign_ata,
+            treasury_ata,
+            campaign,
+            mint,
+            token_program,
+            fee_in_tokens,
+            decimals,
+            signer_seeds,
+        )?;
+    }
+
+    Ok(fee_in_tokens)
+}
@@ -1,14 +1,47 @@
 pub const ANCHOR_DISCRIMINATOR_SIZE: usize = 8;
+// The number of basis points in 100%.
+pub const BASIS_POINTS_SCALE: u64 = 10_000;
+// The size of the campaign account prior to the decoupling of its address from the Merkle root and the expiration time.
+pub const LEGACY_CAMPAIGN_SIZE: usize =
+    ANCHOR_DISCRIMINATOR_SIZE + 3 * 32 + 1 + 3 * 8 + (4 + CAMPAIGN_IPFS_CID_SIZE) + (4 + CAMPAIGN_NAME_SIZE);
+// The size of the claim receipt account prior to the recording of the claim details, i.e. only the discriminator.
+pub const LEGACY_CLAIM_RECEIPT_SIZE: usize = ANCHOR_DISCRIMINATOR_SIZE;
+// The grace period of the campaigns created prior to the configurable grace period.
+pub const LEGACY_GRACE_PERIOD: u64 = 7 * 24 * 60 * 60; // 7 days
+                                                       // The size of the treasury account prior to the addition of the fee split, the pause and the mint list.
+pub const LEGACY_TREASURY_SIZE: usize = ANCHOR_DISCRIMINATOR_SIZE + 1 + 3 * 32;
 // The fee amount in USD, denominated in Chainlink's 8-decimal format for USD prices, where 1e8 is $1.
 // Currently set to 0.
 pub const CLAIM_FEE_USD: u64 = 0;
+// The maximum period, in seconds, after the first claim during which the creator can claw back the tokens.
+pub const MAX_GRACE_PERIOD: u64 = 90 * 24 * 60 * 60; // 90 days
+/// The minimum notice, in seconds, between an update of the expiration time and the new expiration time.
+pub const MIN_EXPIRATION_NOTICE: u64 = 7 * 24 * 60 * 60; // 7 days
 pub const LAMPORTS_PER_SOL: u64 = 1e9 as u64; // 1 billion lamports in 1 SOL
 pub const CAMPAIGN_IPFS_CID_SIZE: usize = 59;
 pub const CAMPAIGN_NAME_SIZE: usize = 32;
+pub const CLAIM_BITMAP_SIZE: usize = 8192; // 8 KiB, within the 10 KiB limit for accounts created via CPI
+pub const CLAIMS_PER_BITMAP: u32 = (CLAIM_BITMAP_SIZE * 8) as u32;
+// The maximum number of recipients among which the collected fees can be split.
+pub const MAX_FEE_SPLIT_RECIPIENTS: usize = 10;
+// The maximum number of airdrop tokens that can be added to a campaign, in addition to its main airdrop token.
+pub const MAX_ADDITIONAL_AIRDROP_TOKENS: usize = 4;
+// The maximum number of indexes queried via `claimed_bitmap_of`, so that the packed bitset and its 4-byte length prefix
+// fit in the 1 KiB of return data.
+pub const MAX_CLAIMED_BITMAP_OF_COUNT: u32 = (1024 - 4) * 8;
+// The maximum claim fee charged in the airdrop token, in basis points of the claimed amount, i.e. 10%.
+pub const MAX_TOKEN_CLAIM_FEE_BPS: u16 = 1_000;
+// The maximum length of a Merkle proof, matching the depth of a tree with `u32::MAX` leaves.
+pub const MAX_MERKLE_PROOF_LENGTH: usize = 32;
 
 // Account Seeds
 pub mod seeds {
     pub const CAMPAIGN: &[u8] = b"campaign";
+    pub const CAMPAIGN_TOMBSTONE: &[u8] = b"campaign_tombstone";
+    pub const CLAIM_BITMAP: &[u8] = b"claim_bitmap";
     pub const CLAIM_RECEIPT: &[u8] = b"claim_receipt";
+    pub const CUMULATIVE_CLAIM: &[u8] = b"cumulative_claim";
+    pub const MINT_LIST_ENTRY: &[u8] = b"mint_list_entry";
+    pub const PROOF_BUFFER: &[u8] = b"proof_buffer";
     pub const TREASURY: &[u8] = b"treasury";
 }
@@ -0,0 +1,67 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        ed25519_program,
+        sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+    },
+};
+
+use crate::utils::errors::ErrorCode;
+
+/// The size of the ed25519 instruction header: the number of signatures and a padding byte.
+const HEADER_SIZE: usize = 2;
+/// The size of the `Ed25519SignatureOffsets` struct of the ed25519 program.
+const OFFSETS_SIZE: usize = 14;
+/// The instruction index used by the ed25519 program to refer to its own instruction data.
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Verifies that the instruction preceding the current one is an ed25519 program instruction verifying the signature
+/// of `message` by `signer`.
+///
+/// Dev: the ed25519 program makes the whole transaction fail if the signature is invalid, so it is sufficient to check
+/// that the public key and the message it verified are the expected ones.
+pub fn verify_ed25519_signature(instructions_sysvar: &AccountInfo, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    // Load the instruction preceding the current one.
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    if current_index == 0 {
+        return Err(ErrorCode::InvalidSignature.into());
+    }
+    let ed25519_ix = load_instruction_at_checked((current_index - 1) as usize, instructions_sysvar)?;
+
+    // Check: the instruction is a call to the ed25519 program verifying exactly one signature.
+    let data = ed25519_ix.data.as_slice();
+    if ed25519_ix.program_id != ed25519_program::ID || data.len() < HEADER_SIZE + OFFSETS_SIZE || data[0] != 1 {
+        return Err(ErrorCode::InvalidSignature.into());
+    }
+
+    let read_u16 = |offset: usize| u16::from_le_bytes([data[HEADER_SIZE + offset], data[HEADER_SIZE + offset + 1]]);
+    let signature_instruction_index = read_u16(2);
+    let public_key_offset = read_u16(4) as usize;
+    let public_key_instruction_index = read_u16(6);
+    let message_data_offset = read_u16(8) as usize;
+    let message_data_size = read_u16(10) as usize;
+    let message_instruction_index = read_u16(12);
+
+    // Check: the signature, the public key and the message are all read from the ed25519 instruction itself.
+    if signature_instruction_index != CURRENT_INSTRUCTION ||
+        public_key_instruction_index != CURRENT_INSTRUCTION ||
+        message_instruction_index != CURRENT_INSTRUCTION
+    {
+        return Err(ErrorCode::InvalidSignature.into());
+    }
+
+    // Check: the verified public key is the signer's.
+    let public_key = data.get(public_key_offset..public_key_offset + 32).ok_or(ErrorCode::InvalidSignature)?;
+    if public_key != signer.as_ref() {
+        return Err(ErrorCode::InvalidSignature.into());
+    }
+
+    // Check: the verified message is the expected one.
+    let signed_message =
+        data.get(message_data_offset..message_data_offset + message_data_size).ok_or(ErrorCode::InvalidSignature)?;
+    if signed_message != message {
+        return Err(ErrorCode::InvalidSignature.into());
+    }
+
+    Ok(())
+}
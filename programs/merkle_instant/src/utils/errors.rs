@@ -2,6 +2,32 @@ use anchor_lang::prelude::*;
 
 #[error_code]
 pub enum ErrorCode {
+    // -------------------------------------------------------------------------- //
+    //                          ADD AIRDROP TOKEN ERRORS                          //
+    // -------------------------------------------------------------------------- //
+    #[msg("Can't add an airdrop token once the campaign has started!")]
+    AirdropTokenAdditionNotAllowed,
+    #[msg("The token is already airdropped by the campaign!")]
+    AirdropTokenAlreadyAdded,
+    #[msg("The campaign airdrops the maximum number of tokens!")]
+    TooManyAirdropTokens,
+    #[msg("Multi-token campaigns are only supported for SPL tokens with the Sablier leaf format!")]
+    MultiTokenNotSupported,
+
+    // -------------------------------------------------------------------------- //
+    //                           CANCEL CAMPAIGN ERRORS                           //
+    // -------------------------------------------------------------------------- //
+    #[msg("Can't cancel a campaign once it has started!")]
+    CampaignAlreadyStarted,
+    #[msg("Can't cancel a campaign once a claim has been made!")]
+    CampaignCancelNotAllowed,
+
+    // -------------------------------------------------------------------------- //
+    //                           CAMPAIGN STATS ERRORS                            //
+    // -------------------------------------------------------------------------- //
+    #[msg("The campaign's ATA is required unless the campaign airdrops native SOL!")]
+    CampaignAtaMissing,
+
     // -------------------------------------------------------------------------- //
     //                                CLAIM ERRORS                                //
     // -------------------------------------------------------------------------- //
@@ -11,16 +37,178 @@ pub enum ErrorCode {
     InvalidMerkleProof,
     #[msg("Campaign has not started yet!")]
     CampaignNotStarted,
+    #[msg("Airdrop already claimed!")]
+    AlreadyClaimed,
+    #[msg("The claim status account required by the campaign's claim mode is missing!")]
+    ClaimStatusAccountMissing,
+    #[msg("No leaves to claim!")]
+    NoLeavesToClaim,
+    #[msg("The campaign tracks cumulative claims, which must be made via claim_cumulative!")]
+    CumulativeClaimRequired,
+    #[msg("The campaign airdrops multiple tokens, which must be claimed via claim_multi_token!")]
+    MultiTokenClaimRequired,
+    #[msg("The recipient does not hold the tokens required by the campaign!")]
+    NotEligible,
+    #[msg("The claim fee in the airdrop token exceeds the claimed amount!")]
+    TokenClaimFeeExceedsAmount,
+    #[msg("The campaign charges the claim fee in the airdrop token, which is not supported by this instruction!")]
+    TokenClaimFeeNotSupported,
+    #[msg("The treasury's ATA is required to charge the claim fee in the airdrop token!")]
+    TreasuryAtaMissing,
+
+    // -------------------------------------------------------------------------- //
+    //                          CLAIM CUMULATIVE ERRORS                           //
+    // -------------------------------------------------------------------------- //
+    #[msg("The campaign does not track cumulative claims!")]
+    CumulativeClaimNotSupported,
+    #[msg("The cumulative amount has already been claimed!")]
+    NothingToClaim,
+
+    // -------------------------------------------------------------------------- //
+    //                          CLAIM MULTI TOKEN ERRORS                          //
+    // -------------------------------------------------------------------------- //
+    #[msg("The number of amounts does not match the number of airdrop tokens!")]
+    AirdropTokensMismatch,
+
+    // -------------------------------------------------------------------------- //
+    //                            CLAIM NATIVE ERRORS                             //
+    // -------------------------------------------------------------------------- //
+    #[msg("The campaign airdrops native SOL, which requires the native instructions!")]
+    CampaignIsNative,
+    #[msg("The campaign does not airdrop native SOL!")]
+    CampaignNotNative,
+    #[msg("The campaign does not hold enough SOL above its rent-exempt minimum!")]
+    InsufficientNativeBalance,
+
+    // -------------------------------------------------------------------------- //
+    //                        CLAIM WITH SIGNATURE ERRORS                         //
+    // -------------------------------------------------------------------------- //
+    #[msg("Invalid ed25519 signature of the claim!")]
+    InvalidSignature,
+    #[msg("The claim signature has expired!")]
+    SignatureExpired,
+
+    // -------------------------------------------------------------------------- //
+    //                          CLAIMED BITMAP OF ERRORS                          //
+    // -------------------------------------------------------------------------- //
+    #[msg("The number of indexes exceeds the maximum that fits in the return data!")]
+    TooManyIndexes,
+    #[msg("The range of indexes exceeds the maximum index!")]
+    IndexRangeOverflow,
+
+    // -------------------------------------------------------------------------- //
+    //                            CLAIM BITMAP ERRORS                             //
+    // -------------------------------------------------------------------------- //
+    #[msg("The campaign does not track claims in bitmaps!")]
+    ClaimBitmapNotSupported,
 
     // -------------------------------------------------------------------------- //
     //                               CLAWBACK ERRORS                              //
     // -------------------------------------------------------------------------- //
     #[msg("Clawback not allowed past the grace period and before campaign expiration!")]
     ClawbackNotAllowed,
+    #[msg("The token is not airdropped by the campaign!")]
+    InvalidAirdropToken,
+
+    // -------------------------------------------------------------------------- //
+    //                            CLOSE CAMPAIGN ERRORS                           //
+    // -------------------------------------------------------------------------- //
+    #[msg("Campaign has not expired yet!")]
+    CampaignNotExpired,
+    #[msg("Can't close a campaign whose ATA still holds tokens!")]
+    CampaignAtaNotEmpty,
+    #[msg("The claim bitmap does not belong to the campaign!")]
+    InvalidClaimBitmap,
+
+    // -------------------------------------------------------------------------- //
+    //                         CLOSE CLAIM RECEIPTS ERRORS                        //
+    // -------------------------------------------------------------------------- //
+    #[msg("Campaign has not been closed yet!")]
+    CampaignNotClosed,
+    #[msg("The claim receipt does not match the campaign and index!")]
+    InvalidClaimReceipt,
+    #[msg("The account does not match the claimer stored in the claim receipt!")]
+    InvalidClaimer,
+    #[msg("Invalid number of remaining accounts!")]
+    InvalidRemainingAccounts,
 
     // -------------------------------------------------------------------------- //
     //                             COLLECT FEES ERRORS                            //
     // -------------------------------------------------------------------------- //
     #[msg("Can't collect zero fees!")]
     CantCollectZeroFees,
+    #[msg("The fee recipient is required if the treasury has no fee split!")]
+    FeeRecipientMissing,
+    #[msg("The remaining accounts must be the fee split recipients, in order!")]
+    FeeSplitRecipientsMismatch,
+    #[msg("No fee split recipient can be paid without falling below the rent-exempt minimum!")]
+    FeeSplitSharesBelowRentExempt,
+
+    // -------------------------------------------------------------------------- //
+    //                           CREATE CAMPAIGN ERRORS                           //
+    // -------------------------------------------------------------------------- //
+    #[msg("The campaign address has been retired by the closure of a previous campaign!")]
+    CampaignAddressRetired,
+    #[msg("The grace period exceeds the maximum allowed!")]
+    GracePeriodTooLong,
+    #[msg("The Jito leaf format, which does not encode the index, requires the cumulative claim mode!")]
+    LeafFormatNotSupported,
+    #[msg("The airdrop token mint is not allowed by the mint list!")]
+    MintNotAllowed,
+    #[msg("Native SOL campaigns do not support the cumulative claim mode!")]
+    NativeClaimModeNotSupported,
+    #[msg("The claim fee in the airdrop token exceeds the maximum allowed!")]
+    TokenClaimFeeTooHigh,
+
+    // -------------------------------------------------------------------------- //
+    //                          EXTEND EXPIRATION ERRORS                          //
+    // -------------------------------------------------------------------------- //
+    #[msg("The new expiration time must be at least 7 days in the future!")]
+    ExpirationTimeTooSoon,
+
+    // -------------------------------------------------------------------------- //
+    //                          MIGRATE CAMPAIGN ERRORS                           //
+    // -------------------------------------------------------------------------- //
+    #[msg("The campaign has already been migrated!")]
+    CampaignAlreadyMigrated,
+
+    // -------------------------------------------------------------------------- //
+    //                          MIGRATE TREASURY ERRORS                           //
+    // -------------------------------------------------------------------------- //
+    #[msg("The treasury has already been migrated!")]
+    TreasuryAlreadyMigrated,
+
+    // -------------------------------------------------------------------------- //
+    //                            PAUSE PROGRAM ERRORS                            //
+    // -------------------------------------------------------------------------- //
+    #[msg("The program is already paused!")]
+    ProgramAlreadyPaused,
+    #[msg("The program is paused!")]
+    ProgramIsPaused,
+    #[msg("The program is not paused!")]
+    ProgramNotPaused,
+
+    // -------------------------------------------------------------------------- //
+    //                            SET FEE SPLIT ERRORS                            //
+    // -------------------------------------------------------------------------- //
+    #[msg("The fee split recipients must be unique!")]
+    FeeSplitDuplicateRecipient,
+    #[msg("The fee split has too many recipients!")]
+    FeeSplitTooManyRecipients,
+    #[msg("The fee split shares must be non-zero and sum to 10,000 basis points!")]
+    InvalidFeeSplitShares,
+
+    // -------------------------------------------------------------------------- //
+    //                          UPDATE MERKLE ROOT ERRORS                         //
+    // -------------------------------------------------------------------------- //
+    #[msg("Can't update the Merkle root once the campaign has started and a claim has been made!")]
+    MerkleRootUpdateNotAllowed,
+    #[msg("The IPFS CID exceeds the maximum length!")]
+    IpfsCidTooLong,
+
+    // -------------------------------------------------------------------------- //
+    //                          WRITE PROOF BUFFER ERRORS                         //
+    // -------------------------------------------------------------------------- //
+    #[msg("The proof exceeds the maximum length of a Merkle proof!")]
+    ProofTooLong,
 }
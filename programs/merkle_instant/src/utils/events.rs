@@ -1,17 +1,62 @@
 use anchor_lang::prelude::*;
 
-/// Emitted when an airdrop is claimed on behalf of an eligible recipient.
+use crate::state::{ClaimMode, EligibilityRule, FeeShare, LeafFormat, MintListMode, TokenClaimFee};
+
+/// Emitted when the campaign creator adds an airdrop token to a campaign.
+#[event]
+pub struct AddAirdropToken {
+    pub campaign: Pubkey,
+    pub campaign_creator: Pubkey,
+    pub token_mint: Pubkey,
+}
+
+/// Emitted when the campaign creator cancels a campaign before any claim, and the airdrop tokens are refunded.
+#[event]
+pub struct CampaignCanceled {
+    pub campaign: Pubkey,
+    pub campaign_creator: Pubkey,
+    pub closed_claim_bitmaps: u32,
+    pub refunded_amount: u64,
+    pub token_mint: Pubkey,
+}
+
+/// Emitted when the campaign creator updates the expiration time of a campaign.
+#[event]
+pub struct CampaignExpirationExtended {
+    pub campaign: Pubkey,
+    pub campaign_creator: Pubkey,
+    pub new_expiration_time: u64,
+    pub old_expiration_time: u64,
+}
+
+/// Emitted when an airdrop is claimed on behalf of an eligible recipient and transferred to the destination.
 #[event]
 pub struct Claim {
     pub amount: u64,
     pub campaign: Pubkey,
     pub claimer: Pubkey,
     pub claim_receipt: Pubkey,
+    pub destination: Pubkey,
     pub fee_in_lamports: u64,
+    pub fee_in_tokens: u64,
     pub index: u32,
     pub recipient: Pubkey,
 }
 
+/// Emitted when the airdrops of all the tokens of a multi-token campaign are claimed on behalf of an eligible
+/// recipient.
+#[event]
+pub struct ClaimMultiToken {
+    pub amounts: Vec<u64>,
+    pub campaign: Pubkey,
+    pub claimer: Pubkey,
+    pub claim_receipt: Pubkey,
+    pub fee_in_lamports: u64,
+    pub index: u32,
+    pub recipient: Pubkey,
+    pub token_mints: Vec<Pubkey>,
+}
+
 /// Emitted when the campaign creator claws back the unclaimed tokens.
 #[event]
 pub struct Clawback {
@@ -19,6 +64,22 @@ pub struct Clawback {
     pub campaign: Pubkey,
     pub campaign_creator: Pubkey,
     pub clawback_recipient: Pubkey,
+    pub token_mint: Pubkey,
+}
+
+/// Emitted when the campaign creator closes an expired campaign.
+#[event]
+pub struct CloseCampaign {
+    pub campaign: Pubkey,
+    pub campaign_creator: Pubkey,
+    pub closed_claim_bitmaps: u32,
+}
+
+/// Emitted when the claim receipts of a closed campaign are closed and their rent refunded to the claimers.
+#[event]
+pub struct CloseClaimReceipts {
+    pub campaign: Pubkey,
+    pub indexes: Vec<u32>,
 }
 
 /// Emitted when a Merkle Instant campaign is created.
@@ -28,11 +89,17 @@ pub struct CreateCampaign {
     pub campaign: Pubkey,
     pub campaign_name: String,
     pub campaign_start_time: u64,
+    pub claim_mode: ClaimMode,
     pub creator: Pubkey,
+    pub eligibility_rule: Option<EligibilityRule>,
     pub expiration_time: u64,
+    pub grace_period: u64,
     pub ipfs_cid: String,
+    pub is_native: bool,
+    pub leaf_format: LeafFormat,
     pub merkle_root: [u8; 32],
     pub recipient_count: u32,
+    pub token_claim_fee: Option<TokenClaimFee>,
     pub token_decimals: u8,
     pub token_mint: Pubkey,
 }
@@ -44,3 +111,62 @@ pub struct FeesCollected {
     pub fee_collector: Pubkey,
     pub fee_recipient: Pubkey,
 }
+
+/// Emitted when the fee collector sets the fee split of the treasury.
+#[event]
+pub struct FeeSplitSet {
+    pub fee_collector: Pubkey,
+    pub fee_split: Vec<FeeShare>,
+}
+
+/// Emitted when the fee collector adds a mint to the mint list.
+#[event]
+pub struct MintAddedToList {
+    pub fee_collector: Pubkey,
+    pub mint: Pubkey,
+}
+
+/// Emitted when the fee collector sets the mode of the mint list.
+#[event]
+pub struct MintListModeSet {
+    pub fee_collector: Pubkey,
+    pub mint_list_mode: MintListMode,
+}
+
+/// Emitted when the fee collector removes a mint from the mint list.
+#[event]
+pub struct MintRemovedFromList {
+    pub fee_collector: Pubkey,
+    pub mint: Pubkey,
+}
+
+/// Emitted when the fee collector pauses the program.
+#[event]
+pub struct ProgramPaused {
+    pub fee_collector: Pubkey,
+}
+
+/// Emitted when the fee collector unpauses the program.
+#[event]
+pub struct ProgramUnpaused {
+    pub fee_collector: Pubkey,
+}
+
+/// Emitted when token fees are collected from the treasury's ATA.
+#[event]
+pub struct TokenFeesCollected {
+    pub fee_amount: u64,
+    pub fee_collector: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub token_mint: Pubkey,
+}
+
+/// Emitted when the campaign creator updates the Merkle root of a campaign.
+#[event]
+pub struct UpdateMerkleRoot {
+    pub campaign: Pubkey,
+    pub campaign_creator: Pubkey,
+    pub new_ipfs_cid: String,
+    pub new_merkle_root: [u8; 32],
+    pub old_merkle_root: [u8; 32],
+}
@@ -1,7 +1,29 @@
-use crate::utils::constants::LAMPORTS_PER_SOL;
+use crate::{
+    state::TokenClaimFee,
+    utils::{
+        constants::{BASIS_POINTS_SCALE, LAMPORTS_PER_SOL},
+        errors::ErrorCode,
+    },
+};
 use anchor_lang::prelude::*;
 use chainlink_solana as chainlink;
 
+/// Calculates the claim fee charged in the airdrop token on the claimed amount.
+pub fn calculate_token_claim_fee(amount: u64, token_claim_fee: TokenClaimFee) -> Result<u64> {
+    let fee_in_tokens = match token_claim_fee {
+        // `u128` is used to prevent overflow, and the result cannot exceed the amount since the share is at most 100%.
+        TokenClaimFee::BasisPoints(bps) => ((amount as u128) * (bps as u128) / (BASIS_POINTS_SCALE as u128)) as u64,
+        TokenClaimFee::Fixed(fee) => fee,
+    };
+
+    // Check: the fee does not exceed the claimed amount.
+    if fee_in_tokens > amount {
+        return Err(ErrorCode::TokenClaimFeeExceedsAmount.into());
+    }
+
+    Ok(fee_in_tokens)
+}
+
 // TODO: export this into a crate that'd be imported by both the lockup and merkle_instant programs.
 /// Converts the fee amount from USD to lamports.
 /// The price is considered to be 0 if:
@@ -1,4 +1,6 @@
+pub mod claim_helper;
 pub mod constants;
+pub mod ed25519;
 pub mod errors;
 pub mod events;
 pub mod fee_calculation;
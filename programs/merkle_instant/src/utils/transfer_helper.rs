@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{transfer_checked, TransferChecked};
+use anchor_spl::token_interface::{close_account, transfer_checked, CloseAccount, TransferChecked};
 
 /// Helper function facilitating token transfer.
 #[allow(clippy::too_many_arguments)]
@@ -28,3 +28,26 @@ pub fn transfer_tokens<'info>(
 
     Ok(())
 }
+
+/// Helper function facilitating the closure of a token account.
+pub fn close_token_account<'info>(
+    account: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    // Prepare the instruction to close the token account
+    let close_ix = CloseAccount {
+        account,
+        destination,
+        authority,
+    };
+
+    // Execute the closure with the provided signer seeds
+    let cpi_ctx = CpiContext::new_with_signer(token_program, close_ix, signer_seeds);
+
+    close_account(cpi_ctx)?;
+
+    Ok(())
+}
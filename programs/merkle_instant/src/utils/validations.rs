@@ -1,17 +1,600 @@
-use anchor_lang::{prelude::*, solana_program::keccak::hashv as keccak};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{hash::hashv as sha256, keccak::hashv as keccak},
+};
+use anchor_spl::{metadata::mpl_token_metadata::accounts::Metadata, token_interface::TokenAccount};
 
-use crate::utils::{errors::ErrorCode, time::get_current_time};
+use crate::{
+    state::{
+        Campaign, ClaimMode, EligibilityKind, EligibilityRule, FeeShare, LeafFormat, MintListMode, TokenClaimFee,
+        Treasury,
+    },
+    utils::{
+        constants::{
+            BASIS_POINTS_SCALE, CAMPAIGN_IPFS_CID_SIZE, CLAIMS_PER_BITMAP, LEGACY_CAMPAIGN_SIZE, LEGACY_TREASURY_SIZE,
+            MAX_ADDITIONAL_AIRDROP_TOKENS, MAX_CLAIMED_BITMAP_OF_COUNT, MAX_FEE_SPLIT_RECIPIENTS, MAX_GRACE_PERIOD,
+            MAX_MERKLE_PROOF_LENGTH, MAX_TOKEN_CLAIM_FEE_BPS, MIN_EXPIRATION_NOTICE,
+        },
+        errors::ErrorCode,
+        time::get_current_time,
+    },
+};
 
 /// Validate the claim of a campaign.
+#[allow(clippy::too_many_arguments)]
 pub fn check_claim(
     amount: u64,
     campaign_start_time: u64,
     expiration_time: u64,
     index: u32,
+    leaf_format: LeafFormat,
     merkle_proof: Vec<[u8; 32]>,
     merkle_root: [u8; 32],
     recipient: Pubkey,
 ) -> Result<()> {
+    // Check: the campaign is claimable.
+    check_claim_period(campaign_start_time, expiration_time)?;
+
+    // Check: the Merkle proof is valid.
+    check_merkle_proof(hash_leaf(leaf_format, index, recipient, amount), leaf_format, merkle_proof, merkle_root)
+}
+
+/// Validate a cumulative claim of a campaign, in addition to [`check_claim`].
+pub fn check_claim_cumulative(claimed_amount: u64, cumulative_amount: u64) -> Result<()> {
+    // Check: the cumulative amount exceeds the amount claimed so far.
+    if cumulative_amount <= claimed_amount {
+        return Err(ErrorCode::NothingToClaim.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the claim of all the tokens of a multi-token campaign.
+#[allow(clippy::too_many_arguments)]
+pub fn check_claim_multi_token(
+    airdrop_tokens_count: usize,
+    amounts: &[u64],
+    campaign_start_time: u64,
+    expiration_time: u64,
+    index: u32,
+    merkle_proof: Vec<[u8; 32]>,
+    merkle_root: [u8; 32],
+    recipient: Pubkey,
+) -> Result<()> {
+    // Check: the campaign is claimable.
+    check_claim_period(campaign_start_time, expiration_time)?;
+
+    // Check: an amount is provided for each airdrop token.
+    if amounts.len() != airdrop_tokens_count {
+        return Err(ErrorCode::AirdropTokensMismatch.into());
+    }
+
+    // Check: the Merkle proof is valid.
+    let leaf_hash = hash_multi_token_leaf(index, recipient, amounts);
+    check_merkle_proof(leaf_hash, LeafFormat::Sablier, merkle_proof, merkle_root)
+}
+
+/// Validate a batch of claims of a campaign. Each claim is then validated individually via [`check_claim`].
+pub fn check_claim_multiple(
+    leaves_count: usize,
+    remaining_accounts_count: usize,
+    accounts_per_leaf: usize,
+) -> Result<()> {
+    // Check: the batch is not empty.
+    if leaves_count == 0 {
+        return Err(ErrorCode::NoLeavesToClaim.into());
+    }
+
+    // Check: the expected accounts are provided for each leaf.
+    if remaining_accounts_count != leaves_count * accounts_per_leaf {
+        return Err(ErrorCode::InvalidRemainingAccounts.into());
+    }
+
+    Ok(())
+}
+
+/// Validate a batch of claims of a campaign proven with a single Merkle multiproof.
+pub fn check_claim_multiproof(
+    campaign_start_time: u64,
+    expiration_time: u64,
+    leaf_format: LeafFormat,
+    leaf_hashes: &[[u8; 32]],
+    merkle_root: [u8; 32],
+    proof: &[[u8; 32]],
+    proof_flags: &[bool],
+) -> Result<()> {
+    // Check: the campaign is claimable.
+    check_claim_period(campaign_start_time, expiration_time)?;
+
+    // Check: the batch is not empty.
+    if leaf_hashes.is_empty() {
+        return Err(ErrorCode::NoLeavesToClaim.into());
+    }
+
+    // Check: the multiproof proves all the leaves against the Merkle root.
+    if !multi_proof_verify(leaf_format, proof, proof_flags, merkle_root, leaf_hashes) {
+        return Err(ErrorCode::InvalidMerkleProof.into());
+    }
+
+    Ok(())
+}
+
+/// Validate that the recipient holds the tokens required by the eligibility rule of a token-gated campaign.
+pub fn check_eligibility(
+    eligibility_rule: &EligibilityRule,
+    recipient: Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    // Check: the recipient's token account is provided, followed by the metadata account of its mint if the rule
+    // requires an NFT collection.
+    if remaining_accounts.len() != eligibility_accounts_count(Some(*eligibility_rule)) {
+        return Err(ErrorCode::InvalidRemainingAccounts.into());
+    }
+
+    // Check: the token account is owned by a Token program, belongs to the recipient and holds enough tokens.
+    let token_account_info = &remaining_accounts[0];
+    if !<TokenAccount as anchor_lang::Owners>::owners().contains(token_account_info.owner) {
+        return Err(ErrorCode::NotEligible.into());
+    }
+    let token_account = TokenAccount::try_deserialize(&mut &token_account_info.data.borrow()[..])
+        .map_err(|_| ErrorCode::NotEligible)?;
+    if token_account.owner != recipient || token_account.amount < eligibility_rule.min_amount {
+        return Err(ErrorCode::NotEligible.into());
+    }
+
+    match eligibility_rule.kind {
+        // Check: the token is the required one.
+        EligibilityKind::Mint => {
+            if token_account.mint != eligibility_rule.key {
+                return Err(ErrorCode::NotEligible.into());
+            }
+        }
+        // Check: the token is a verified member of the required collection.
+        EligibilityKind::Collection => {
+            let metadata_info = &remaining_accounts[1];
+            if metadata_info.key() != Metadata::find_pda(&token_account.mint).0 {
+                return Err(ErrorCode::NotEligible.into());
+            }
+            let metadata = Metadata::try_from(metadata_info).map_err(|_| ErrorCode::NotEligible)?;
+            let is_member = metadata
+                .collection
+                .is_some_and(|collection| collection.verified && collection.key == eligibility_rule.key);
+            if !is_member {
+                return Err(ErrorCode::NotEligible.into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the number of accounts required to check the eligibility of a recipient: none if the campaign is not
+/// token-gated, otherwise the recipient's token account, followed by the metadata account of its mint if the rule
+/// requires an NFT collection.
+pub fn eligibility_accounts_count(eligibility_rule: Option<EligibilityRule>) -> usize {
+    match eligibility_rule.map(|rule| rule.kind) {
+        None => 0,
+        Some(EligibilityKind::Mint) => 1,
+        Some(EligibilityKind::Collection) => 2,
+    }
+}
+
+/// Validate the addition of an airdrop token to a campaign.
+#[allow(clippy::too_many_arguments)]
+pub fn check_add_airdrop_token(
+    additional_airdrop_token_mints: &[Pubkey],
+    airdrop_token_mint: Pubkey,
+    campaign_start_time: u64,
+    claim_mode: ClaimMode,
+    is_native: bool,
+    leaf_format: LeafFormat,
+    new_airdrop_token_mint: Pubkey,
+    token_claim_fee: Option<TokenClaimFee>,
+) -> Result<()> {
+    // Check: the campaign has not started.
+    if has_campaign_started(campaign_start_time)? {
+        return Err(ErrorCode::AirdropTokenAdditionNotAllowed.into());
+    }
+
+    // Check: the campaign airdrops an SPL token and uses the Sablier leaf format, which is the only one encoding
+    // multiple amounts, does not track cumulative claims, and does not charge the claim fee in the airdrop token.
+    if is_native ||
+        leaf_format != LeafFormat::Sablier ||
+        claim_mode == ClaimMode::Cumulative ||
+        token_claim_fee.is_some()
+    {
+        return Err(ErrorCode::MultiTokenNotSupported.into());
+    }
+
+    // Check: the token is not airdropped by the campaign yet.
+    if new_airdrop_token_mint == airdrop_token_mint || additional_airdrop_token_mints.contains(&new_airdrop_token_mint)
+    {
+        return Err(ErrorCode::AirdropTokenAlreadyAdded.into());
+    }
+
+    // Check: the maximum number of airdrop tokens is not reached.
+    if additional_airdrop_token_mints.len() >= MAX_ADDITIONAL_AIRDROP_TOKENS {
+        return Err(ErrorCode::TooManyAirdropTokens.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the expiry of the recipient's signature authorizing a claim.
+pub fn check_claim_signature_expiry(signature_expiry: u64) -> Result<()> {
+    // Check: the signature has not expired.
+    if signature_expiry < get_current_time()? {
+        return Err(ErrorCode::SignatureExpired.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the clawback from a campaign.
+pub fn check_clawback(expiration_time: u64, first_claim_time: u64, grace_period: u64) -> Result<()> {
+    // Check: the grace period has not passed or the campaign has expired.
+    if has_grace_period_passed(first_claim_time, grace_period)? && !has_expired(expiration_time)? {
+        return Err(ErrorCode::ClawbackNotAllowed.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the cancellation of a campaign.
+pub fn check_cancel_campaign(campaign_start_time: u64, claim_count: u32, first_claim_time: u64) -> Result<()> {
+    // Check: the campaign has not started.
+    if has_campaign_started(campaign_start_time)? {
+        return Err(ErrorCode::CampaignAlreadyStarted.into());
+    }
+
+    // Check: no claim has been made. The first claim time is checked as well, as the claim count of a migrated
+    // campaign only covers the claims made after the migration.
+    if claim_count > 0 || first_claim_time > 0 {
+        return Err(ErrorCode::CampaignCancelNotAllowed.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the query of the claim statuses of an index range via the claimed bitmap view.
+pub fn check_claimed_bitmap_of(
+    claim_mode: ClaimMode,
+    count: u32,
+    remaining_accounts_count: usize,
+    start_index: u32,
+) -> Result<()> {
+    // Check: the packed bitset fits in the return data.
+    if count > MAX_CLAIMED_BITMAP_OF_COUNT {
+        return Err(ErrorCode::TooManyIndexes.into());
+    }
+
+    // Check: the range does not exceed the maximum index.
+    if start_index.checked_add(count.saturating_sub(1)).is_none() {
+        return Err(ErrorCode::IndexRangeOverflow.into());
+    }
+
+    // Check: the claim status accounts are provided for the range, i.e. one claim receipt per index, or the claim
+    // bitmaps covering the range.
+    let expected_accounts_count = match claim_mode {
+        ClaimMode::Receipt => count as usize,
+        ClaimMode::Bitmap if count == 0 => 0,
+        ClaimMode::Bitmap => {
+            let last_index = start_index as u64 + count as u64 - 1;
+            let first_chunk = start_index as u64 / CLAIMS_PER_BITMAP as u64;
+            let last_chunk = last_index / CLAIMS_PER_BITMAP as u64;
+            (last_chunk - first_chunk + 1) as usize
+        }
+        ClaimMode::Cumulative => return Err(ErrorCode::CumulativeClaimRequired.into()),
+    };
+    if remaining_accounts_count != expected_accounts_count {
+        return Err(ErrorCode::InvalidRemainingAccounts.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the closure of a campaign.
+pub fn check_close_campaign(campaign_ata_amount: u64, expiration_time: u64) -> Result<()> {
+    // Check: the campaign has expired.
+    if !has_expired(expiration_time)? {
+        return Err(ErrorCode::CampaignNotExpired.into());
+    }
+
+    // Check: the campaign's ATA has been swept.
+    check_campaign_ata_swept(campaign_ata_amount)
+}
+
+/// Validate that a campaign's ATA does not hold any tokens, so that it can be closed.
+pub fn check_campaign_ata_swept(campaign_ata_amount: u64) -> Result<()> {
+    if campaign_ata_amount > 0 {
+        return Err(ErrorCode::CampaignAtaNotEmpty.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the closure of a native SOL campaign.
+pub fn check_close_native_campaign(expiration_time: u64) -> Result<()> {
+    // Check: the campaign has expired.
+    if !has_expired(expiration_time)? {
+        return Err(ErrorCode::CampaignNotExpired.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the closure of the claim receipts of a campaign.
+pub fn check_close_claim_receipts(
+    is_campaign_closed: bool,
+    indexes_count: usize,
+    remaining_accounts_count: usize,
+) -> Result<()> {
+    // Check: the campaign has been closed.
+    if !is_campaign_closed {
+        return Err(ErrorCode::CampaignNotClosed.into());
+    }
+
+    // Check: a claim receipt and its claimer are provided for each index.
+    if remaining_accounts_count != indexes_count * 2 {
+        return Err(ErrorCode::InvalidRemainingAccounts.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the creation of a campaign.
+pub fn check_create_campaign(
+    claim_mode: ClaimMode,
+    grace_period: u64,
+    is_native: bool,
+    leaf_format: LeafFormat,
+    token_claim_fee: Option<TokenClaimFee>,
+) -> Result<()> {
+    // Check: the grace period does not exceed the maximum allowed.
+    if grace_period > MAX_GRACE_PERIOD {
+        return Err(ErrorCode::GracePeriodTooLong.into());
+    }
+
+    // Check: the native SOL claims are tracked per index, as there is no native counterpart of `claim_cumulative`.
+    if is_native && claim_mode == ClaimMode::Cumulative {
+        return Err(ErrorCode::NativeClaimModeNotSupported.into());
+    }
+
+    // Check: the Jito leaves, which do not encode the index, are only used with claims tracked per recipient.
+    if leaf_format == LeafFormat::Jito && claim_mode != ClaimMode::Cumulative {
+        return Err(ErrorCode::LeafFormatNotSupported.into());
+    }
+
+    if let Some(token_claim_fee) = token_claim_fee {
+        // Check: the claim fee is charged in an SPL token, on claims tracked per index.
+        if is_native || claim_mode == ClaimMode::Cumulative {
+            return Err(ErrorCode::TokenClaimFeeNotSupported.into());
+        }
+
+        // Check: the claim fee does not exceed the maximum allowed.
+        if let TokenClaimFee::BasisPoints(bps) = token_claim_fee {
+            if bps > MAX_TOKEN_CLAIM_FEE_BPS {
+                return Err(ErrorCode::TokenClaimFeeTooHigh.into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate a transfer of native SOL out of a campaign.
+pub fn check_native_transfer(amount: u64, transferable_amount: u64) -> Result<()> {
+    // Check: the transfer does not dip into the rent-exempt minimum of the campaign.
+    if amount > transferable_amount {
+        return Err(ErrorCode::InsufficientNativeBalance.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the collection of fees.
+pub fn check_collect_fees(collectible_amount: u64) -> Result<()> {
+    // Check: the collectable amount is not zero.
+    if collectible_amount == 0 {
+        return Err(ErrorCode::CantCollectZeroFees.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the recipients of a fee split, passed via the remaining accounts.
+pub fn check_fee_split_recipients(fee_split: &[FeeShare], remaining_accounts: &[AccountInfo]) -> Result<()> {
+    // Check: the remaining accounts are the fee split recipients, in the same order.
+    if remaining_accounts.len() != fee_split.len() ||
+        fee_split.iter().zip(remaining_accounts).any(|(share, account)| share.recipient != account.key())
+    {
+        return Err(ErrorCode::FeeSplitRecipientsMismatch.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the migration of a campaign to the current layout.
+pub fn check_migrate_campaign(campaign_data: &[u8]) -> Result<()> {
+    // Check: the account is a campaign.
+    if !campaign_data.starts_with(Campaign::DISCRIMINATOR) {
+        return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+
+    // Check: the campaign has the layout prior to the decoupling of its address from the Merkle root and the
+    // expiration time.
+    if campaign_data.len() != LEGACY_CAMPAIGN_SIZE {
+        return Err(ErrorCode::CampaignAlreadyMigrated.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the migration of the treasury to the current layout.
+pub fn check_migrate_treasury(treasury_data: &[u8]) -> Result<()> {
+    // Check: the account is a treasury.
+    if !treasury_data.starts_with(Treasury::DISCRIMINATOR) {
+        return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+
+    // Check: the treasury has the layout prior to the fee split, the pause and the mint list.
+    if treasury_data.len() != LEGACY_TREASURY_SIZE {
+        return Err(ErrorCode::TreasuryAlreadyMigrated.into());
+    }
+
+    Ok(())
+}
+
+/// Validate that the mint list allows the airdrop token, given its mint list entry, which exists only if the mint is
+/// listed. Since campaigns may be created before the program initialization, an uninitialized treasury is considered
+/// to have an open mint list.
+pub fn check_mint_list(treasury: &AccountInfo, mint_list_entry: &AccountInfo) -> Result<()> {
+    let is_mint_listed = !mint_list_entry.data_is_empty();
+
+    // Check: the airdrop token mint is allowed by the mint list, if the treasury has been initialized.
+    if let Some(treasury) = load_treasury(treasury)? {
+        match treasury.mint_list_mode {
+            MintListMode::Open => {}
+            MintListMode::Allowlist if !is_mint_listed => return Err(ErrorCode::MintNotAllowed.into()),
+            MintListMode::Denylist if is_mint_listed => return Err(ErrorCode::MintNotAllowed.into()),
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate that the program is not paused. Since campaigns may be created before the program initialization, an
+/// uninitialized treasury is considered unpaused.
+pub fn check_not_paused(treasury: &AccountInfo) -> Result<()> {
+    // Check: the program is not paused, if the treasury has been initialized.
+    if let Some(treasury) = load_treasury(treasury)? {
+        if treasury.is_paused {
+            return Err(ErrorCode::ProgramIsPaused.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate the pause of the program.
+pub fn check_pause(is_paused: bool) -> Result<()> {
+    // Check: the program is not already paused.
+    if is_paused {
+        return Err(ErrorCode::ProgramAlreadyPaused.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the fee split configuration of the treasury.
+pub fn check_set_fee_split(fee_split: &[FeeShare]) -> Result<()> {
+    // Check: the number of recipients does not exceed the maximum allowed.
+    if fee_split.len() > MAX_FEE_SPLIT_RECIPIENTS {
+        return Err(ErrorCode::FeeSplitTooManyRecipients.into());
+    }
+
+    // An empty fee split disables the split, so that the fees are collected to a single recipient.
+    if fee_split.is_empty() {
+        return Ok(());
+    }
+
+    // Check: the shares are non-zero and sum to 100%.
+    let total_bps: u64 = fee_split.iter().map(|share| share.bps as u64).sum();
+    if fee_split.iter().any(|share| share.bps == 0) || total_bps != BASIS_POINTS_SCALE {
+        return Err(ErrorCode::InvalidFeeSplitShares.into());
+    }
+
+    // Check: the recipients are unique.
+    for (i, share) in fee_split.iter().enumerate() {
+        if fee_split[..i].iter().any(|other| other.recipient == share.recipient) {
+            return Err(ErrorCode::FeeSplitDuplicateRecipient.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate the unpause of the program.
+pub fn check_unpause(is_paused: bool) -> Result<()> {
+    // Check: the program is paused.
+    if !is_paused {
+        return Err(ErrorCode::ProgramNotPaused.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the update of the expiration time of a campaign.
+pub fn check_extend_expiration(expiration_time: u64, new_expiration_time: u64) -> Result<()> {
+    // Check: the campaign has not expired.
+    if has_expired(expiration_time)? {
+        return Err(ErrorCode::CampaignExpired.into());
+    }
+
+    // Check: the new expiration time is zero, or it respects the minimum notice.
+    if new_expiration_time > 0 && new_expiration_time < get_current_time()? + MIN_EXPIRATION_NOTICE {
+        return Err(ErrorCode::ExpirationTimeTooSoon.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the update of the Merkle root of a campaign.
+pub fn check_update_merkle_root(
+    campaign_start_time: u64,
+    claim_mode: ClaimMode,
+    expiration_time: u64,
+    first_claim_time: u64,
+    ipfs_cid: &str,
+) -> Result<()> {
+    // Check: the IPFS CID fits in the campaign account.
+    if ipfs_cid.len() > CAMPAIGN_IPFS_CID_SIZE {
+        return Err(ErrorCode::IpfsCidTooLong.into());
+    }
+
+    // Check: the campaign has not expired, as the new Merkle tree could no longer be claimed.
+    if has_expired(expiration_time)? {
+        return Err(ErrorCode::CampaignExpired.into());
+    }
+
+    // Cumulative campaigns are meant to post new roots over time, as the claims only pay out the increase of the
+    // cumulative amounts.
+    if claim_mode == ClaimMode::Cumulative {
+        return Ok(());
+    }
+
+    // Check: the campaign has not started or no claim has been made.
+    if has_campaign_started(campaign_start_time)? && first_claim_time > 0 {
+        return Err(ErrorCode::MerkleRootUpdateNotAllowed.into());
+    }
+
+    Ok(())
+}
+
+/// Validate the writing of Merkle proof elements into a proof buffer.
+pub fn check_write_proof_buffer(proof_length: usize, new_elements_count: usize) -> Result<()> {
+    // Check: the proof does not exceed the maximum length.
+    if proof_length + new_elements_count > MAX_MERKLE_PROOF_LENGTH {
+        return Err(ErrorCode::ProofTooLong.into());
+    }
+
+    Ok(())
+}
+
+/// Helper function to load the treasury, or `None` if the program has not been initialized yet. Any other failure to
+/// deserialize the treasury, e.g. a treasury that has not been migrated, is propagated.
+fn load_treasury(treasury: &AccountInfo) -> Result<Option<Treasury>> {
+    if treasury.data_is_empty() {
+        return Ok(None);
+    }
+
+    let data = treasury.try_borrow_data()?;
+    Ok(Some(Treasury::try_deserialize(&mut &data[..])?))
+}
+
+/// Helper function to validate that a campaign has started and has not expired.
+fn check_claim_period(campaign_start_time: u64, expiration_time: u64) -> Result<()> {
     // Check: the campaign has started.
     if !has_campaign_started(campaign_start_time)? {
         return Err(ErrorCode::CampaignNotStarted.into());
@@ -22,29 +605,22 @@ pub fn check_claim(
         return Err(ErrorCode::CampaignExpired.into());
     }
 
-    let index_bytes = index.to_le_bytes();
-    let recipient_bytes = recipient.to_bytes();
-    let amount_bytes = amount.to_le_bytes();
-    let leaf_bytes: &[&[u8]] = &[&index_bytes, &recipient_bytes, &amount_bytes];
-
-    // Compute the hash of the leaf
-    let mut leaf_hash = keccak(leaf_bytes).0;
-
-    // Hash one more time to protect against the second pre-image attacks
-    leaf_hash = keccak(&[&leaf_hash]).0;
+    Ok(())
+}
 
+/// Helper function to validate a Merkle proof against the Merkle root.
+fn check_merkle_proof(
+    leaf_hash: [u8; 32],
+    leaf_format: LeafFormat,
+    merkle_proof: Vec<[u8; 32]>,
+    merkle_root: [u8; 32],
+) -> Result<()> {
     // Compute the root hash from the leaf hash and the merkle proof
     // Dev: this algorithm has been inspired by OpenZeppelin
     // https://github.com/OpenZeppelin/openzeppelin-contracts/blob/v3.4.0/contracts/utils/cryptography/MerkleProof.sol
     let mut computed_hash = leaf_hash;
     for proof_element in merkle_proof.iter() {
-        if computed_hash <= *proof_element {
-            // Hash: current computed hash + current element of the merkle proof
-            computed_hash = keccak(&[&computed_hash, proof_element]).0;
-        } else {
-            // Hash: current element of the merkle proof + current computed hash
-            computed_hash = keccak(&[proof_element, &computed_hash]).0;
-        }
+        computed_hash = hash_pair(leaf_format, &computed_hash, proof_element);
     }
     // Check if the computed hash (root) is equal to the provided root
     if computed_hash != merkle_root {
@@ -54,24 +630,133 @@ pub fn check_claim(
     Ok(())
 }
 
-/// Validate the clawback from a campaign.
-pub fn check_clawback(expiration_time: u64, first_claim_time: u64) -> Result<()> {
-    // Check: the grace period has not passed or the campaign has expired.
-    if has_grace_period_passed(first_claim_time)? && !has_expired(expiration_time)? {
-        return Err(ErrorCode::ClawbackNotAllowed.into());
+/// Helper function to compute the hash of a Merkle tree leaf, as encoded by the given leaf format.
+pub fn hash_leaf(leaf_format: LeafFormat, index: u32, recipient: Pubkey, amount: u64) -> [u8; 32] {
+    let recipient_bytes = recipient.to_bytes();
+    let amount_bytes = amount.to_le_bytes();
+
+    match leaf_format {
+        LeafFormat::Sablier => {
+            let index_bytes = index.to_le_bytes();
+            let leaf_bytes: &[&[u8]] = &[&index_bytes, &recipient_bytes, &amount_bytes];
+
+            // Compute the hash of the leaf
+            let leaf_hash = keccak(leaf_bytes).0;
+
+            // Hash one more time to protect against the second pre-image attacks
+            keccak(&[&leaf_hash]).0
+        }
+        // Dev: the index is encoded as a u64, as in the Saber merkle-distributor.
+        LeafFormat::Saber => keccak(&[&(index as u64).to_le_bytes(), &recipient_bytes, &amount_bytes]).0,
+        // Dev: the whole amount is unlocked, and the leaf is prefixed to protect against the second pre-image attacks,
+        // as in the Jito merkle-distributor.
+        LeafFormat::Jito => {
+            let node = sha256(&[&recipient_bytes, &amount_bytes, &0u64.to_le_bytes()]).to_bytes();
+            sha256(&[&[0u8], &node]).to_bytes()
+        }
     }
+}
 
-    Ok(())
+/// Helper function to compute the hash of a multi-token Merkle tree leaf, which carries one amount per airdrop token.
+/// With a single amount, the hash is the same as the one of a leaf in the Sablier leaf format.
+fn hash_multi_token_leaf(index: u32, recipient: Pubkey, amounts: &[u64]) -> [u8; 32] {
+    let index_bytes = index.to_le_bytes();
+    let recipient_bytes = recipient.to_bytes();
+    let amounts_bytes: Vec<[u8; 8]> = amounts.iter().map(|amount| amount.to_le_bytes()).collect();
+
+    let mut leaf_bytes: Vec<&[u8]> = vec![&index_bytes, &recipient_bytes];
+    leaf_bytes.extend(amounts_bytes.iter().map(|amount_bytes| amount_bytes.as_slice()));
+
+    // Compute the hash of the leaf, and hash it one more time to protect against the second pre-image attacks
+    let leaf_hash = keccak(&leaf_bytes).0;
+    keccak(&[&leaf_hash]).0
 }
 
-/// Validate the collection of fees.
-pub fn check_collect_fees(collectible_amount: u64) -> Result<()> {
-    // Check: the collectable amount is not zero.
-    if collectible_amount == 0 {
-        return Err(ErrorCode::CantCollectZeroFees.into());
+/// Helper function to hash a pair of nodes of a Merkle tree, sorted so that the result is commutative.
+fn hash_pair(leaf_format: LeafFormat, a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    };
+
+    match leaf_format {
+        LeafFormat::Sablier | LeafFormat::Saber => keccak(&[first, second]).0,
+        // Dev: the intermediate nodes are prefixed, as in the Jito merkle-distributor.
+        LeafFormat::Jito => sha256(&[&[1u8], first, second]).to_bytes(),
     }
+}
 
-    Ok(())
+/// Helper function to return whether the leaves can be simultaneously proven to be part of the Merkle tree with the
+/// given root, using a multiproof and its flags.
+///
+/// Dev: this algorithm is a port of OpenZeppelin's `multiProofVerify`, so the multiproofs generated by the
+/// OpenZeppelin-compatible tooling can be used as is. The leaves must be provided in the order of the multiproof.
+/// https://github.com/OpenZeppelin/openzeppelin-contracts/blob/v5.0.0/contracts/utils/cryptography/MerkleProof.sol
+pub fn multi_proof_verify(
+    leaf_format: LeafFormat,
+    proof: &[[u8; 32]],
+    proof_flags: &[bool],
+    root: [u8; 32],
+    leaves: &[[u8; 32]],
+) -> bool {
+    process_multi_proof(leaf_format, proof, proof_flags, leaves) == Some(root)
+}
+
+/// Helper function to return the root reconstructed from the leaves and the multiproof, or `None` if the multiproof is
+/// malformed.
+fn process_multi_proof(
+    leaf_format: LeafFormat,
+    proof: &[[u8; 32]],
+    proof_flags: &[bool],
+    leaves: &[[u8; 32]],
+) -> Option<[u8; 32]> {
+    let leaves_len = leaves.len();
+    let proof_flags_len = proof_flags.len();
+
+    // Check: the multiproof is consistent with the number of leaves.
+    if leaves_len + proof.len() != proof_flags_len + 1 {
+        return None;
+    }
+
+    // At each step, the next hash is computed from the next leaf or the next computed hash, and either the next leaf or
+    // computed hash (if the flag is set) or the next proof element.
+    let mut hashes: Vec<[u8; 32]> = Vec::with_capacity(proof_flags_len);
+    let (mut leaf_pos, mut hash_pos, mut proof_pos) = (0, 0, 0);
+
+    for &flag in proof_flags {
+        let a = next_node(leaves, &hashes, &mut leaf_pos, &mut hash_pos)?;
+        let b = if flag {
+            next_node(leaves, &hashes, &mut leaf_pos, &mut hash_pos)?
+        } else {
+            proof_pos += 1;
+            *proof.get(proof_pos - 1)?
+        };
+        hashes.push(hash_pair(leaf_format, &a, &b));
+    }
+
+    if proof_flags_len > 0 {
+        // Check: all the proof elements have been consumed.
+        if proof_pos != proof.len() {
+            return None;
+        }
+        hashes.last().copied()
+    } else if leaves_len > 0 {
+        Some(leaves[0])
+    } else {
+        proof.first().copied()
+    }
+}
+
+/// Helper function to return the next unprocessed leaf, or the next computed hash once all leaves have been processed.
+fn next_node(leaves: &[[u8; 32]], hashes: &[[u8; 32]], leaf_pos: &mut usize, hash_pos: &mut usize) -> Option<[u8; 32]> {
+    if *leaf_pos < leaves.len() {
+        *leaf_pos += 1;
+        Some(leaves[*leaf_pos - 1])
+    } else {
+        *hash_pos += 1;
+        hashes.get(*hash_pos - 1).copied()
+    }
 }
 
 // Helper function to return whether a campaign has started.
@@ -89,9 +774,8 @@ pub fn has_expired(expiration_time: u64) -> Result<bool> {
 }
 
 /// Helper function to return whether the grace period of a campaign has passed.
-pub fn has_grace_period_passed(first_claim_time: u64) -> Result<bool> {
+pub fn has_grace_period_passed(first_claim_time: u64, grace_period: u64) -> Result<bool> {
     let current_time = get_current_time()?;
-    let grace_period = 7 * 24 * 60 * 60; // 7 days in seconds
 
     Ok(first_claim_time > 0 && current_time > first_claim_time + grace_period)
 }
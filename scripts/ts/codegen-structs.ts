@@ -149,7 +149,7 @@ function generateStructType(idlType: IdlType): string {
   }
 
   if (!idlType.type.fields || idlType.type.fields.length === 0) {
-    // Skip empty structs (like ClaimReceipt which has no fields)
+    // Skip empty structs, which have no fields to type
     return "";
   }
 
@@ -21,7 +21,10 @@ describe("Sablier Merkle Instant post-deployment initialization with campaign",
 
 const Campaign = {
   EXPIRATION_TIME: toBn(Math.floor(Date.now() / 1000) + 10 * 24 * 60 * 60), // 10 days from now
+  CLAIM_MODE: { receipt: {} },
+  GRACE_PERIOD: toBn(7 * 24 * 60 * 60), // 7 days
   IPFS_CID: "bafkreiecpwdhvkmw4y6iihfndk7jhwjas3m5htm7nczovt6m37mucwgsrq",
+  LEAF_FORMAT: { sablier: {} },
   MERKLE_ROOT: Array.from(
     Buffer.from("d52549cb072a1fcd052412fc80f678effe92aeeedccd1cae632c5c6e1de89379", "hex"),
   ),
@@ -53,6 +56,9 @@ async function createCampaign({
       Campaign.IPFS_CID,
       toBn(10_000),
       100,
+      Campaign.CLAIM_MODE,
+      Campaign.GRACE_PERIOD,
+      Campaign.LEAF_FORMAT,
     )
     .signers([signerKeys])
     .accounts({
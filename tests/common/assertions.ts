@@ -45,6 +45,17 @@ export function assertEqTreasury(left: Treasury, right: Treasury) {
     "Chainlink sol usd feed mismatch",
   );
   assertEqPublicKey(left.feeCollector, right.feeCollector, "Fee collector mismatch");
+  assert.equal(left.feeSplit.length, right.feeSplit.length, "Fee split length mismatch");
+  left.feeSplit.forEach((share, i) => {
+    assert.equal(share.bps, right.feeSplit[i].bps, "Fee share bps mismatch");
+    assertEqPublicKey(share.recipient, right.feeSplit[i].recipient, "Fee share recipient mismatch");
+  });
+  assert.equal(left.isPaused, right.isPaused, "Pause flag mismatch");
+  assert.deepEqual(left.mintListMode, right.mintListMode, "Mint list mode mismatch");
+  assert.equal(left.owedFees.length, right.owedFees.length, "Owed fees length mismatch");
+  left.owedFees.forEach((owedFee, i) => {
+    assertEqBn(owedFee, right.owedFees[i], "Owed fee mismatch");
+  });
 }
 
 export function assertLteBn(left: BN, right: BN, message?: string) {
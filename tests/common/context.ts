@@ -13,13 +13,16 @@ import { Decimals, ProgramId } from "../../lib/constants";
 import { dai, sol, usdc } from "../../lib/convertors";
 import { toBigInt, toBn } from "../../lib/helpers";
 import type { ProgramName } from "../../lib/types";
-import { createATAAndFund, createMint, transferLamports } from "./anchor-bankrun";
+import { createATAAndFund, createMint, deriveATAAddress, transferLamports } from "./anchor-bankrun";
 import { ChainlinkMock } from "./chainlink-mock";
 import type { User } from "./types";
 
+// The size of the treasury prior to the addition of the fee split, the pause and the mint list.
+const LEGACY_TREASURY_SIZE = 8 + 1 + 3 * 32;
+
 export class TestContext {
   // Core Bankrun components
-  private context!: ProgramTestContext;
+  protected context!: ProgramTestContext;
   public banksClient!: BanksClient;
   public bankrunProvider!: BankrunProvider;
   public defaultBankrunPayer!: Keypair;
@@ -111,6 +114,11 @@ export class TestContext {
     return (await this.banksClient.getAccount(address)) !== null;
   }
 
+  // Returns the address of the treasury's ATA for the given mint.
+  treasuryAtaAddress(mint = this.usdc, tokenProgram = ProgramId.TOKEN): PublicKey {
+    return deriveATAAddress(mint, this.treasuryAddress, tokenProgram);
+  }
+
   async getLamportsOf(user: PublicKey): Promise<BN> {
     const balance = await this.banksClient.getBalance(user);
     return toBn(balance);
@@ -130,6 +138,38 @@ export class TestContext {
     return fees;
   }
 
+  // Truncates the treasury to its layout prior to the fee split, the pause and the mint list, as if
+  // it had been initialized by a prior version of the program.
+  async simulateLegacyTreasury() {
+    const treasury = await this.banksClient.getAccount(this.treasuryAddress);
+    if (!treasury) {
+      throw new Error("The treasury is not initialized");
+    }
+
+    this.context.setAccount(this.treasuryAddress, {
+      ...treasury,
+      data: treasury.data.slice(0, LEGACY_TREASURY_SIZE),
+    });
+  }
+
+  // Funds the treasury's ATA for the given mint, creating it beforehand.
+  async simulateTokenFeeGeneration(
+    amount = usdc(100),
+    mint = this.usdc,
+    tokenProgram = ProgramId.TOKEN,
+  ): Promise<BN> {
+    await createATAAndFund(
+      this.banksClient,
+      this.defaultBankrunPayer,
+      mint,
+      amount,
+      tokenProgram,
+      this.treasuryAddress,
+    );
+
+    return amount;
+  }
+
   async timeTravelTo(timestamp: BN) {
     const currentClock = await this.banksClient.getClock();
 
@@ -1,3 +1,4 @@
+import type { BN } from "@coral-xyz/anchor";
 import type { Keypair, PublicKey } from "@solana/web3.js";
 
 export type User = {
@@ -6,9 +7,18 @@ export type User = {
   usdcATA: PublicKey;
 };
 
+export type FeeShare = {
+  bps: number;
+  recipient: PublicKey;
+};
+
 export type Treasury = {
   bump: number;
   chainlinkProgram: PublicKey;
   chainlinkSolUsdFeed: PublicKey;
   feeCollector: PublicKey;
+  feeSplit: FeeShare[];
+  isPaused: boolean;
+  mintListMode: object;
+  owedFees: BN[];
 };
@@ -11,7 +11,7 @@ import type { SablierLockup as SablierLockupProgram } from "../../target/types/s
 import type { NftCollectionData, StreamData } from "../../target/types/sablier_lockup_structs";
 import { buildSignAndProcessTx, deriveATAAddress, getATABalance } from "../common/anchor-bankrun";
 import { TestContext } from "../common/context";
-import type { Treasury, User } from "../common/types";
+import type { FeeShare, Treasury, User } from "../common/types";
 import { AMOUNTS, Amount, Seed, TIMESTAMPS, Time, UNLOCK_AMOUNTS } from "./utils/defaults";
 import type { Salts, Stream } from "./utils/types";
 
@@ -70,20 +70,36 @@ export class LockupTestContext extends TestContext {
                             STATE-CHANGING INSTRUCTIONS
   //////////////////////////////////////////////////////////////////////////*/
 
+  async addToMintList(mint: PublicKey = this.usdc, signer: Keypair = this.feeCollector.keys) {
+    const addToMintListIx = await this.lockup.methods
+      .addToMintList()
+      .accounts({
+        feeCollector: signer.publicKey,
+        mint,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, addToMintListIx, signer);
+  }
+
   async cancel({
     salt = this.salts.default,
     signer = this.sender.keys,
     depositedTokenMint = this.usdc,
     depositedTokenProgram = token.TOKEN_PROGRAM_ID,
+    unwrap = false,
   } = {}): Promise<void> {
     const streamNftMint = this.getStreamNftMintAddress(salt);
     const cancelStreamIx = await this.lockup.methods
       .cancel()
-      .accounts({
+      .accountsPartial({
         depositedTokenMint,
         depositedTokenProgram,
         sender: signer.publicKey,
         streamNftMint,
+        ...(unwrap
+          ? { senderAta: null, temporaryWsolAccount: this.getTemporaryWsolAddress(salt) }
+          : { temporaryWsolAccount: null }),
       })
       .instruction();
 
@@ -98,18 +114,38 @@ export class LockupTestContext extends TestContext {
     });
   }
 
-  async collectFees(signer: Keypair = this.feeCollector.keys) {
+  async collectFees(
+    signer: Keypair = this.feeCollector.keys,
+    feeSplitRecipients: PublicKey[] = [],
+  ) {
     const collectFeesIx = await this.lockup.methods
       .collectFees()
       .accounts({
         feeCollector: signer.publicKey,
         feeRecipient: this.sender.keys.publicKey,
       })
+      .remainingAccounts(
+        feeSplitRecipients.map((pubkey) => ({ isSigner: false, isWritable: true, pubkey })),
+      )
       .instruction();
 
     await buildSignAndProcessTx(this.banksClient, collectFeesIx, signer);
   }
 
+  async collectTokenFees(signer: Keypair = this.feeCollector.keys) {
+    const collectTokenFeesIx = await this.lockup.methods
+      .collectTokenFees()
+      .accounts({
+        feeCollector: signer.publicKey,
+        feeRecipient: this.sender.keys.publicKey,
+        tokenMint: this.usdc,
+        tokenProgram: ProgramId.TOKEN,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, collectTokenFeesIx, signer);
+  }
+
   async createWithDurationsLl({
     cliffDuration = Time.CLIFF_DURATION,
     salt,
@@ -156,6 +192,7 @@ export class LockupTestContext extends TestContext {
     unlockAmounts = UNLOCK_AMOUNTS(),
     isCancelable = true,
     salt = new BN(-1),
+    wrapSol = false,
   } = {}): Promise<BN> {
     // Use the total supply as the salt for the stream
     salt = salt.isNeg() ? await this.getTotalSupply() : salt;
@@ -171,13 +208,15 @@ export class LockupTestContext extends TestContext {
         unlockAmounts.cliff,
         isCancelable,
       )
-      .accounts({
+      .accountsPartial({
         creator: creator.publicKey,
         depositTokenMint,
         depositTokenProgram,
         nftTokenProgram: token.TOKEN_PROGRAM_ID,
         recipient: recipientPubKey,
         sender: senderPubKey,
+        // Omit the creator's ATA so that the deposit is wrapped from the creator's SOL
+        ...(wrapSol ? { creatorAta: null } : {}),
       })
       .instruction();
 
@@ -209,6 +248,40 @@ export class LockupTestContext extends TestContext {
     await buildSignAndProcessTx(this.banksClient, initializeIx, this.sender.keys);
   }
 
+  async migrateTreasury(signer: Keypair = this.sender.keys) {
+    const migrateTreasuryIx = await this.lockup.methods
+      .migrateTreasury()
+      .accounts({
+        signer: signer.publicKey,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, migrateTreasuryIx, signer);
+  }
+
+  async pause(signer: Keypair = this.feeCollector.keys) {
+    const pauseIx = await this.lockup.methods
+      .pause()
+      .accounts({
+        feeCollector: signer.publicKey,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, pauseIx, signer);
+  }
+
+  async removeFromMintList(mint: PublicKey = this.usdc, signer: Keypair = this.feeCollector.keys) {
+    const removeFromMintListIx = await this.lockup.methods
+      .removeFromMintList()
+      .accounts({
+        feeCollector: signer.publicKey,
+        mint,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, removeFromMintListIx, signer);
+  }
+
   async renounce({ salt = this.salts.default, signer = this.sender.keys } = {}): Promise<void> {
     const streamNftMint = this.getStreamNftMintAddress(salt);
     const renounceIx = await this.lockup.methods
@@ -222,6 +295,39 @@ export class LockupTestContext extends TestContext {
     await buildSignAndProcessTx(this.banksClient, renounceIx, signer);
   }
 
+  async setFeeSplit(feeSplit: FeeShare[], signer: Keypair = this.feeCollector.keys) {
+    const setFeeSplitIx = await this.lockup.methods
+      .setFeeSplit(feeSplit)
+      .accounts({
+        feeCollector: signer.publicKey,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, setFeeSplitIx, signer);
+  }
+
+  async setMintListMode(mintListMode: object, signer: Keypair = this.feeCollector.keys) {
+    const setMintListModeIx = await this.lockup.methods
+      .setMintListMode(mintListMode)
+      .accounts({
+        feeCollector: signer.publicKey,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, setMintListModeIx, signer);
+  }
+
+  async unpause(signer: Keypair = this.feeCollector.keys) {
+    const unpauseIx = await this.lockup.methods
+      .unpause()
+      .accounts({
+        feeCollector: signer.publicKey,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, unpauseIx, signer);
+  }
+
   async withdraw({
     salt = this.salts.default,
     withdrawAmount = Amount.WITHDRAW,
@@ -229,11 +335,12 @@ export class LockupTestContext extends TestContext {
     withdrawalRecipient = this.recipient.keys.publicKey,
     depositedTokenMint = this.usdc,
     depositedTokenProgram = token.TOKEN_PROGRAM_ID,
+    unwrap = false,
   } = {}): Promise<void> {
     const streamNftMint = this.getStreamNftMintAddress(salt);
     const withdrawIx = await this.lockup.methods
       .withdraw(withdrawAmount)
-      .accounts({
+      .accountsPartial({
         chainlinkProgram: ProgramId.CHAINLINK_PROGRAM,
         chainlinkSolUsdFeed: ProgramId.CHAINLINK_SOL_USD_FEED,
         depositedTokenMint,
@@ -243,6 +350,7 @@ export class LockupTestContext extends TestContext {
         streamNftMint,
         streamRecipient: this.recipient.keys.publicKey,
         withdrawalRecipient,
+        ...this.unwrapAccounts(salt, unwrap),
       })
       .instruction();
 
@@ -264,12 +372,13 @@ export class LockupTestContext extends TestContext {
     withdrawalRecipient = this.recipient.keys.publicKey,
     depositedTokenMint = this.usdc,
     depositedTokenProgram = token.TOKEN_PROGRAM_ID,
+    unwrap = false,
   } = {}): Promise<void> {
     const streamNftMint = this.getStreamNftMintAddress(salt);
 
     const withdrawMaxIx = await this.lockup.methods
       .withdrawMax()
-      .accounts({
+      .accountsPartial({
         chainlinkProgram: ProgramId.CHAINLINK_PROGRAM,
         chainlinkSolUsdFeed: ProgramId.CHAINLINK_SOL_USD_FEED,
         depositedTokenMint,
@@ -279,6 +388,7 @@ export class LockupTestContext extends TestContext {
         streamNftMint,
         streamRecipient: this.recipient.keys.publicKey,
         withdrawalRecipient,
+        ...this.unwrapAccounts(salt, unwrap),
       })
       .instruction();
 
@@ -460,6 +570,26 @@ export class LockupTestContext extends TestContext {
                                 PRIVATE METHODS
   //////////////////////////////////////////////////////////////////////////*/
 
+  getMintListEntryAddress(mint = this.usdc): PublicKey {
+    return getPDAAddress([Seed.MINT_LIST_ENTRY, mint.toBuffer()], this.lockup.programId);
+  }
+
+  private getTemporaryWsolAddress(salt: BN): PublicKey {
+    const streamNftMint = this.getStreamNftMintAddress(salt);
+    return getPDAAddress([Seed.TEMPORARY_WSOL, streamNftMint.toBuffer()], this.lockup.programId);
+  }
+
+  // Returns the withdrawal accounts to either unwrap the wSOL through the temporary account or
+  // transfer it to the ATA.
+  private unwrapAccounts(
+    salt: BN,
+    unwrap: boolean,
+  ): { temporaryWsolAccount: PublicKey | null; withdrawalRecipientAta?: null } {
+    return unwrap
+      ? { temporaryWsolAccount: this.getTemporaryWsolAddress(salt), withdrawalRecipientAta: null }
+      : { temporaryWsolAccount: null };
+  }
+
   private getStreamDataAddress(salt: BN): PublicKey {
     const streamNftMint = this.getStreamNftMintAddress(salt);
     const streamDataSeeds = [Seed.STREAM_DATA, streamNftMint.toBuffer()];
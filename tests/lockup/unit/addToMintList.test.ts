@@ -0,0 +1,53 @@
+import {
+  ANCHOR_ERROR__ACCOUNT_NOT_INITIALIZED as ACCOUNT_NOT_INITIALIZED,
+  ANCHOR_ERROR__CONSTRAINT_ADDRESS as CONSTRAINT_ADDRESS,
+} from "@coral-xyz/anchor-errors";
+import { beforeAll, beforeEach, describe, it } from "vitest";
+import { assertAccountExists } from "../../common/assertions";
+import { LockupTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+
+let ctx: LockupTestContext;
+
+describe("addToMintList", () => {
+  describe("when the program is not initialized", () => {
+    beforeAll(async () => {
+      ctx = new LockupTestContext();
+      await ctx.setUpLockup({ initProgram: false });
+    });
+
+    it("should fail", async () => {
+      await expectToThrow(ctx.addToMintList(), ACCOUNT_NOT_INITIALIZED);
+    });
+  });
+
+  describe("when the program is initialized", () => {
+    beforeEach(async () => {
+      ctx = new LockupTestContext();
+      await ctx.setUpLockup();
+    });
+
+    describe("when signer is not the authorized fee collector", () => {
+      it("should fail", async () => {
+        await expectToThrow(ctx.addToMintList(ctx.usdc, ctx.eve.keys), CONSTRAINT_ADDRESS);
+      });
+    });
+
+    describe("when signer is the authorized fee collector", () => {
+      describe("when the mint is already in the mint list", () => {
+        it("should fail", async () => {
+          await ctx.addToMintList();
+          await expectToThrow(ctx.addToMintList(), 0x0);
+        });
+      });
+
+      describe("when the mint is not in the mint list", () => {
+        it("should add the mint to the mint list", async () => {
+          await ctx.addToMintList();
+
+          await assertAccountExists(ctx, ctx.getMintListEntryAddress(), "Mint list entry");
+        });
+      });
+    });
+  });
+});
@@ -3,8 +3,10 @@ import {
   ANCHOR_ERROR__ACCOUNT_NOT_INITIALIZED as ACCOUNT_NOT_INITIALIZED,
   ANCHOR_ERROR__CONSTRAINT_ADDRESS as CONSTRAINT_ADDRESS,
 } from "@coral-xyz/anchor-errors";
+import { NATIVE_MINT } from "@solana/spl-token";
 import { beforeAll, beforeEach, describe, it } from "vitest";
 import { BN_1, ProgramId, ZERO } from "../../../lib/constants";
+import { sol } from "../../../lib/convertors";
 import { sleepFor } from "../../../lib/helpers";
 import {
   createATAAndFund,
@@ -12,7 +14,7 @@ import {
   getATABalance,
   getATABalanceMint,
 } from "../../common/anchor-bankrun";
-import { assertAccountNotExists, assertEqBn } from "../../common/assertions";
+import { assertAccountNotExists, assertEqBn, assertLteBn } from "../../common/assertions";
 import { LockupTestContext } from "../context";
 import { assertEqStreamData, expectToThrow } from "../utils/assertions";
 import { Amount, Time } from "../utils/defaults";
@@ -209,6 +211,41 @@ describe("cancel", () => {
                   });
                 });
 
+                describe("given wSOL unwrapped", () => {
+                  it("should cancel the stream and refund SOL", async () => {
+                    const salt = await ctx.createWithTimestampsLl({
+                      depositTokenMint: NATIVE_MINT,
+                      wrapSol: true,
+                    });
+                    const senderLamportsBefore = await ctx.getSenderLamports();
+
+                    // Cancel the stream
+                    await ctx.cancel({ depositedTokenMint: NATIVE_MINT, salt, unwrap: true });
+
+                    const expectedStream = ctx.defaultStream({
+                      depositedTokenMint: NATIVE_MINT,
+                      isCancelable: false,
+                      salt,
+                      wasCanceled: true,
+                    });
+                    expectedStream.data.amounts.refunded = Amount.REFUND;
+
+                    // Assert that the stream has been canceled
+                    const actualStreamData = await ctx.fetchStreamData(salt);
+                    assertEqStreamData(actualStreamData, expectedStream.data);
+
+                    // Assert that the sender has been refunded in SOL, less the transaction fee,
+                    // which is lower than 0.001 SOL. The rent of the temporary wSOL account is
+                    // refunded to the sender.
+                    const senderLamportsAfter = await ctx.getSenderLamports();
+                    assertLteBn(senderLamportsAfter, senderLamportsBefore.add(Amount.REFUND));
+                    assertLteBn(
+                      senderLamportsBefore.add(Amount.REFUND).sub(sol(0.001)),
+                      senderLamportsAfter,
+                    );
+                  });
+                });
+
                 describe("given token 2022 standard", () => {
                   it("should cancel the stream", async () => {
                     // Create a stream with a Token2022 mint
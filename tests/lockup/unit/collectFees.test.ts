@@ -2,8 +2,10 @@ import {
   ANCHOR_ERROR__ACCOUNT_NOT_INITIALIZED as ACCOUNT_NOT_INITIALIZED,
   ANCHOR_ERROR__CONSTRAINT_ADDRESS as CONSTRAINT_ADDRESS,
 } from "@coral-xyz/anchor-errors";
+import { Keypair, PublicKey } from "@solana/web3.js";
 import { beforeAll, beforeEach, describe, it } from "vitest";
-import { REDUNDANCY_BUFFER } from "../../../lib/constants";
+import { REDUNDANCY_BUFFER, ZERO } from "../../../lib/constants";
+import { transferLamports } from "../../common/anchor-bankrun";
 import { assertEqBn } from "../../common/assertions";
 import { LockupTestContext } from "../context";
 import { expectToThrow } from "../utils/assertions";
@@ -71,6 +73,125 @@ describe("collectFees", () => {
           );
         });
       });
+
+      describe("given a fee split", () => {
+        const getRecipients = () => [ctx.recipient.keys.publicKey, ctx.eve.keys.publicKey];
+
+        beforeEach(async () => {
+          const [recipient, eve] = getRecipients();
+          const feeSplit = [
+            { bps: 3_000, recipient },
+            { bps: 7_000, recipient: eve },
+          ];
+          await ctx.setFeeSplit(feeSplit);
+        });
+
+        describe("when the remaining accounts do not match the fee split", () => {
+          it("should fail", async () => {
+            await ctx.simulateFeeGeneration();
+
+            await expectToThrow(
+              ctx.collectFees(ctx.feeCollector.keys, [ctx.eve.keys.publicKey]),
+              "FeeSplitRecipientsMismatch",
+            );
+          });
+        });
+
+        describe("when the remaining accounts match the fee split", () => {
+          it("should distribute the fees", async () => {
+            const fees = await ctx.simulateFeeGeneration();
+            const recipients = getRecipients();
+            const lamportsBefore = await Promise.all(recipients.map((r) => ctx.getLamportsOf(r)));
+
+            // Collect the fees
+            await ctx.collectFees(ctx.feeCollector.keys, recipients);
+
+            const lamportsAfter = await Promise.all(recipients.map((r) => ctx.getLamportsOf(r)));
+
+            // The rounding remainder goes to the last recipient.
+            const expectedFeesCollected = fees.sub(REDUNDANCY_BUFFER);
+            const expectedFirstShare = expectedFeesCollected.muln(3_000).divn(10_000);
+            const expectedSecondShare = expectedFeesCollected.sub(expectedFirstShare);
+
+            // Assert that the recipient balances have been updated correctly.
+            assertEqBn(lamportsAfter[0].sub(lamportsBefore[0]), expectedFirstShare);
+            assertEqBn(lamportsAfter[1].sub(lamportsBefore[1]), expectedSecondShare);
+          });
+        });
+      });
+
+      describe("given a fee split with an unfunded recipient", () => {
+        let unfunded: PublicKey;
+
+        beforeEach(async () => {
+          unfunded = Keypair.generate().publicKey;
+        });
+
+        describe("when no recipient can be paid", () => {
+          it("should fail", async () => {
+            await ctx.setFeeSplit([{ bps: 10_000, recipient: unfunded }]);
+
+            // Accumulate fees below the rent-exempt minimum of the recipient
+            await transferLamports(
+              ctx.banksClient,
+              ctx.defaultBankrunPayer,
+              ctx.defaultBankrunPayer.publicKey,
+              ctx.treasuryAddress,
+              REDUNDANCY_BUFFER.addn(100_000).toNumber(),
+            );
+
+            await expectToThrow(
+              ctx.collectFees(ctx.feeCollector.keys, [unfunded]),
+              "FeeSplitSharesBelowRentExempt",
+            );
+          });
+        });
+
+        describe("when another recipient can be paid", () => {
+          it("should pay the owed share in the next collection", async () => {
+            const eve = ctx.eve.keys.publicKey;
+            const feeSplit = [
+              { bps: 1, recipient: unfunded },
+              { bps: 9_999, recipient: eve },
+            ];
+            await ctx.setFeeSplit(feeSplit);
+
+            // Collect the fees, leaving the unfunded recipient's share below the rent-exempt minimum
+            const fees = await ctx.simulateFeeGeneration();
+            const eveLamportsBefore = await ctx.getLamportsOf(eve);
+            await ctx.collectFees(ctx.feeCollector.keys, [unfunded, eve]);
+
+            const expectedFeesCollected = fees.sub(REDUNDANCY_BUFFER);
+            const expectedOwedFee = expectedFeesCollected.divn(10_000);
+            const expectedEveShare = expectedFeesCollected.sub(expectedOwedFee);
+
+            // Assert that the share is owed to the unfunded recipient, and that eve has been paid
+            assertEqBn(await ctx.getLamportsOf(unfunded), ZERO);
+            assertEqBn((await ctx.getLamportsOf(eve)).sub(eveLamportsBefore), expectedEveShare);
+            const { owedFees } = await ctx.treasuryView();
+            assertEqBn(owedFees[0], expectedOwedFee);
+            assertEqBn(owedFees[1], ZERO);
+
+            // Fund the recipient, and collect the fees again
+            const funding = REDUNDANCY_BUFFER;
+            await transferLamports(
+              ctx.banksClient,
+              ctx.defaultBankrunPayer,
+              ctx.defaultBankrunPayer.publicKey,
+              unfunded,
+              funding.toNumber(),
+            );
+            const nextFees = await ctx.simulateFeeGeneration();
+            await ctx.collectFees(ctx.feeCollector.keys, [unfunded, eve]);
+
+            // Assert that the recipient has been paid the owed fee along with its new share
+            const expectedNextShare = nextFees.divn(10_000);
+            const expectedLamports = funding.add(expectedOwedFee).add(expectedNextShare);
+            assertEqBn(await ctx.getLamportsOf(unfunded), expectedLamports);
+            assertEqBn((await ctx.treasuryView()).owedFees[0], ZERO);
+          });
+        });
+      });
     });
   });
 });
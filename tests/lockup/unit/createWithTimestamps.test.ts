@@ -1,10 +1,11 @@
 import type { BN } from "@coral-xyz/anchor";
 import { ANCHOR_ERROR__ACCOUNT_NOT_INITIALIZED as ACCOUNT_NOT_INITIALIZED } from "@coral-xyz/anchor-errors";
+import { NATIVE_MINT } from "@solana/spl-token";
 import { beforeAll, beforeEach, describe, it } from "vitest";
 import { BN_1, BN_1000, ZERO } from "../../../lib/constants";
 import { usdc } from "../../../lib/convertors";
 import { getATABalance, getMintTotalSupplyOf } from "../../common/anchor-bankrun";
-import { assertAccountExists, assertEqBn } from "../../common/assertions";
+import { assertAccountExists, assertEqBn, assertLteBn } from "../../common/assertions";
 import { LockupTestContext } from "../context";
 import { assertEqStreamData, expectToThrow } from "../utils/assertions";
 import { AMOUNTS, Amount, TIMESTAMPS, Time, UNLOCK_AMOUNTS } from "../utils/defaults";
@@ -62,6 +63,40 @@ describe("createWithTimestampsLl", () => {
           });
         });
 
+        describe("when sender omits the ATA for a deposited token other than wSOL", () => {
+          it("should fail", async () => {
+            await expectToThrow(
+              ctx.createWithTimestampsLl({ wrapSol: true }),
+              "TokenAccountMissing",
+            );
+          });
+        });
+
+        describe("when sender omits the ATA for wSOL", () => {
+          it("should wrap the deposit from the sender's SOL", async () => {
+            const senderLamportsBefore = await ctx.getSenderLamports();
+            const salt = await ctx.createWithTimestampsLl({
+              depositTokenMint: NATIVE_MINT,
+              wrapSol: true,
+            });
+
+            // Assert that the deposit has been wrapped into the Stream Data ATA
+            const expectedStream = ctx.defaultStream({ depositedTokenMint: NATIVE_MINT, salt });
+            const actualStreamData = await ctx.fetchStreamData(salt);
+            assertEqStreamData(actualStreamData, expectedStream.data);
+            const streamDataAtaBalance = await getATABalance(
+              ctx.banksClient,
+              expectedStream.dataAta,
+            );
+            assertEqBn(streamDataAtaBalance, Amount.DEPOSIT);
+
+            // Assert that the sender paid for the deposit in SOL. We use `<=` because the sender
+            // also pays the rent of the created accounts and the transaction fee.
+            const senderLamportsAfter = await ctx.getSenderLamports();
+            assertLteBn(senderLamportsAfter, senderLamportsBefore.sub(Amount.DEPOSIT));
+          });
+        });
+
         describe("when sender has an ATA for deposited token", () => {
           describe("when sender has an insufficient token balance", () => {
             it("should fail", async () => {
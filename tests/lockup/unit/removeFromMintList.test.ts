@@ -0,0 +1,43 @@
+import {
+  ANCHOR_ERROR__ACCOUNT_NOT_INITIALIZED as ACCOUNT_NOT_INITIALIZED,
+  ANCHOR_ERROR__CONSTRAINT_ADDRESS as CONSTRAINT_ADDRESS,
+} from "@coral-xyz/anchor-errors";
+import { beforeEach, describe, it } from "vitest";
+import { assertAccountNotExists } from "../../common/assertions";
+import { LockupTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+
+let ctx: LockupTestContext;
+
+describe("removeFromMintList", () => {
+  beforeEach(async () => {
+    ctx = new LockupTestContext();
+    await ctx.setUpLockup();
+  });
+
+  describe("when the mint is not in the mint list", () => {
+    it("should fail", async () => {
+      await expectToThrow(ctx.removeFromMintList(), ACCOUNT_NOT_INITIALIZED);
+    });
+  });
+
+  describe("when the mint is in the mint list", () => {
+    beforeEach(async () => {
+      await ctx.addToMintList();
+    });
+
+    describe("when signer is not the authorized fee collector", () => {
+      it("should fail", async () => {
+        await expectToThrow(ctx.removeFromMintList(ctx.usdc, ctx.eve.keys), CONSTRAINT_ADDRESS);
+      });
+    });
+
+    describe("when signer is the authorized fee collector", () => {
+      it("should remove the mint from the mint list", async () => {
+        await ctx.removeFromMintList();
+
+        await assertAccountNotExists(ctx, ctx.getMintListEntryAddress(), "Mint list entry");
+      });
+    });
+  });
+});
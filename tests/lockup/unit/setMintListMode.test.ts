@@ -0,0 +1,95 @@
+import {
+  ANCHOR_ERROR__ACCOUNT_NOT_INITIALIZED as ACCOUNT_NOT_INITIALIZED,
+  ANCHOR_ERROR__CONSTRAINT_ADDRESS as CONSTRAINT_ADDRESS,
+} from "@coral-xyz/anchor-errors";
+import { beforeAll, beforeEach, describe, it } from "vitest";
+import { assertEqTreasury } from "../../common/assertions";
+import { LockupTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+import { MintListMode } from "../utils/defaults";
+
+let ctx: LockupTestContext;
+
+describe("setMintListMode", () => {
+  describe("when the program is not initialized", () => {
+    beforeAll(async () => {
+      ctx = new LockupTestContext();
+      await ctx.setUpLockup({ initProgram: false });
+    });
+
+    it("should fail", async () => {
+      await expectToThrow(ctx.setMintListMode(MintListMode.ALLOWLIST), ACCOUNT_NOT_INITIALIZED);
+    });
+  });
+
+  describe("when the program is initialized", () => {
+    beforeEach(async () => {
+      ctx = new LockupTestContext();
+      await ctx.setUpLockup();
+    });
+
+    describe("when signer is not the authorized fee collector", () => {
+      it("should fail", async () => {
+        await expectToThrow(
+          ctx.setMintListMode(MintListMode.ALLOWLIST, ctx.eve.keys),
+          CONSTRAINT_ADDRESS,
+        );
+      });
+    });
+
+    describe("when signer is the authorized fee collector", () => {
+      it("should set the mint list mode", async () => {
+        await ctx.setMintListMode(MintListMode.DENYLIST);
+
+        const treasury = await ctx.treasuryView();
+        assertEqTreasury(treasury, { ...treasury, mintListMode: MintListMode.DENYLIST });
+      });
+
+      describe("when the mode is allowlist", () => {
+        beforeEach(async () => {
+          await ctx.setMintListMode(MintListMode.ALLOWLIST);
+        });
+
+        describe("when the deposit token is not in the mint list", () => {
+          it("should fail the stream creation", async () => {
+            await expectToThrow(ctx.createWithTimestampsLl(), "MintNotAllowed");
+          });
+        });
+
+        describe("when the deposit token is in the mint list", () => {
+          it("should create the stream", async () => {
+            await ctx.addToMintList();
+            await ctx.createWithTimestampsLl();
+          });
+        });
+      });
+
+      describe("when the mode is denylist", () => {
+        beforeEach(async () => {
+          await ctx.setMintListMode(MintListMode.DENYLIST);
+        });
+
+        describe("when the deposit token is in the mint list", () => {
+          it("should fail the stream creation", async () => {
+            await ctx.addToMintList();
+            await expectToThrow(ctx.createWithTimestampsLl(), "MintNotAllowed");
+          });
+        });
+
+        describe("when the deposit token is not in the mint list", () => {
+          it("should create the stream", async () => {
+            await ctx.createWithTimestampsLl();
+          });
+        });
+      });
+
+      describe("when the mode is open", () => {
+        it("should create the stream even if the deposit token is in the mint list", async () => {
+          await ctx.addToMintList();
+          await ctx.setMintListMode(MintListMode.OPEN);
+          await ctx.createWithTimestampsLl();
+        });
+      });
+    });
+  });
+});
@@ -0,0 +1,63 @@
+import {
+  ANCHOR_ERROR__ACCOUNT_NOT_INITIALIZED as ACCOUNT_NOT_INITIALIZED,
+  ANCHOR_ERROR__CONSTRAINT_ADDRESS as CONSTRAINT_ADDRESS,
+} from "@coral-xyz/anchor-errors";
+import { beforeAll, beforeEach, describe, it } from "vitest";
+import { assertEqTreasury } from "../../common/assertions";
+import { LockupTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+
+let ctx: LockupTestContext;
+
+describe("unpause", () => {
+  describe("when the program is not initialized", () => {
+    beforeAll(async () => {
+      ctx = new LockupTestContext();
+      await ctx.setUpLockup({ initProgram: false });
+    });
+
+    it("should fail", async () => {
+      await expectToThrow(ctx.unpause(), ACCOUNT_NOT_INITIALIZED);
+    });
+  });
+
+  describe("when the program is initialized", () => {
+    beforeEach(async () => {
+      ctx = new LockupTestContext();
+      await ctx.setUpLockup();
+    });
+
+    describe("when signer is not the authorized fee collector", () => {
+      it("should fail", async () => {
+        await ctx.pause();
+        await expectToThrow(ctx.unpause(ctx.eve.keys), CONSTRAINT_ADDRESS);
+      });
+    });
+
+    describe("when signer is the authorized fee collector", () => {
+      describe("when the program is not paused", () => {
+        it("should fail", async () => {
+          await expectToThrow(ctx.unpause(), "ProgramNotPaused");
+        });
+      });
+
+      describe("when the program is paused", () => {
+        beforeEach(async () => {
+          await ctx.pause();
+        });
+
+        it("should unpause the program", async () => {
+          await ctx.unpause();
+
+          const treasury = await ctx.treasuryView();
+          assertEqTreasury(treasury, { ...treasury, isPaused: false });
+        });
+
+        it("should allow stream creation again", async () => {
+          await ctx.unpause();
+          await ctx.createWithTimestampsLl();
+        });
+      });
+    });
+  });
+});
@@ -3,6 +3,7 @@ import {
   ANCHOR_ERROR__ACCOUNT_NOT_INITIALIZED as ACCOUNT_NOT_INITIALIZED,
   ANCHOR_ERROR__CONSTRAINT_RAW as CONSTRAINT_RAW,
 } from "@coral-xyz/anchor-errors";
+import { NATIVE_MINT } from "@solana/spl-token";
 import type { PublicKey } from "@solana/web3.js";
 import { beforeAll, beforeEach, describe, it } from "vitest";
 import { BN_1, ProgramId, ZERO } from "../../../lib/constants";
@@ -105,6 +106,15 @@ describe("withdraw", () => {
                   });
                 });
 
+                describe("when unwrapping a stream not depositing wSOL", () => {
+                  it("should fail", async () => {
+                    await expectToThrow(
+                      ctx.withdraw({ signer: ctx.sender.keys, unwrap: true }),
+                      "UnwrapNotSupported",
+                    );
+                  });
+                });
+
                 describe("when recipient doesn't have an ATA for the Stream's asset", () => {
                   it("should create the ATA", async () => {
                     // Set up the sender for the test
@@ -323,6 +333,48 @@ describe("withdraw", () => {
                         });
                       });
 
+                      describe("given wSOL unwrapped", () => {
+                        it("should make the withdrawal in SOL", async () => {
+                          const salt = await ctx.createWithTimestampsLl({
+                            depositTokenMint: NATIVE_MINT,
+                            wrapSol: true,
+                          });
+
+                          const recipient = ctx.recipient.keys.publicKey;
+                          const recipientLamportsBefore = await ctx.getLamportsOf(recipient);
+
+                          await ctx.withdraw({
+                            depositedTokenMint: NATIVE_MINT,
+                            salt,
+                            signer: ctx.sender.keys,
+                            unwrap: true,
+                          });
+
+                          // Assert that the recipient received SOL, without any wSOL ATA created
+                          const recipientLamportsAfter = await ctx.getLamportsOf(recipient);
+                          assertEqBn(
+                            recipientLamportsAfter,
+                            recipientLamportsBefore.add(Amount.WITHDRAW),
+                          );
+                          await assertAccountNotExists(
+                            ctx,
+                            deriveATAAddress(NATIVE_MINT, recipient, ProgramId.TOKEN),
+                            "Recipient's wSOL ATA",
+                          );
+
+                          // Assert that the stream has been debited
+                          const streamDataAta = ctx.defaultStream({
+                            depositedTokenMint: NATIVE_MINT,
+                            salt,
+                          }).dataAta;
+                          const streamDataAtaBalance = await getATABalance(
+                            ctx.banksClient,
+                            streamDataAta,
+                          );
+                          assertEqBn(streamDataAtaBalance, Amount.DEPOSIT.sub(Amount.WITHDRAW));
+                        });
+                      });
+
                       describe("given token 2022 standard", () => {
                         it("should make the withdrawal", async () => {
                           const salt = await ctx.createWithTimestampsLlToken2022();
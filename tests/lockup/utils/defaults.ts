@@ -13,13 +13,21 @@ export namespace Amount {
   export const REFUND = DEPOSIT.sub(WITHDRAW);
 }
 
+export namespace MintListMode {
+  export const ALLOWLIST = { allowlist: {} };
+  export const DENYLIST = { denylist: {} };
+  export const OPEN = { open: {} };
+}
+
 export namespace Seed {
   export const EDITION = Buffer.from("edition");
   export const METADATA = Buffer.from("metadata");
+  export const MINT_LIST_ENTRY = Buffer.from("mint_list_entry");
   export const NFT_COLLECTION_DATA = Buffer.from("nft_collection_data");
   export const NFT_COLLECTION_MINT = Buffer.from("nft_collection_mint");
   export const STREAM_DATA = Buffer.from("stream_data");
   export const STREAM_NFT_MINT = Buffer.from("stream_nft_mint");
+  export const TEMPORARY_WSOL = Buffer.from("temporary_wsol");
   export const TREASURY = Buffer.from("treasury");
 }
 
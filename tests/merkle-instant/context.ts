@@ -1,18 +1,52 @@
 import type { BN } from "@coral-xyz/anchor";
 import * as anchor from "@coral-xyz/anchor";
-import { PublicKey } from "@solana/web3.js";
+import { NATIVE_MINT } from "@solana/spl-token";
+import { Ed25519Program, PublicKey } from "@solana/web3.js";
 import { ProgramId, ZERO } from "../../lib/constants";
 import { ProgramName } from "../../lib/enums";
 import { getPDAAddress } from "../../lib/helpers";
 import IDL from "../../target/idl/sablier_merkle_instant.json";
 import type { SablierMerkleInstant as SablierMerkleInstantProgram } from "../../target/types/sablier_merkle_instant";
-import type { Campaign as CampaignData } from "../../target/types/sablier_merkle_instant_structs";
-import { buildSignAndProcessTx, deriveATAAddress, transfer } from "../common/anchor-bankrun";
+import type {
+  Campaign as CampaignData,
+  CampaignStatsData,
+  ClaimReceipt,
+  EligibilityRule,
+  TokenClaimFee,
+} from "../../target/types/sablier_merkle_instant_structs";
+import {
+  buildSignAndProcessTx,
+  createATAAndFund,
+  deriveATAAddress,
+  transfer,
+  transferLamports,
+} from "../common/anchor-bankrun";
 import { TestContext } from "../common/context";
-import type { Treasury, User } from "../common/types";
-import { Amount, Campaign, Seed, Time } from "./utils/defaults";
-import type { LeafData } from "./utils/merkle";
-import { getProof, getRoot } from "./utils/merkle";
+import type { FeeShare, Treasury, User } from "../common/types";
+import {
+  Amount,
+  CLAIMS_PER_BITMAP,
+  Campaign,
+  ClaimMode,
+  LeafFormat,
+  MintListMode,
+  Seed,
+  Time,
+} from "./utils/defaults";
+import type { LeafData, MultiTokenLeafData } from "./utils/merkle";
+import {
+  getMultiProof,
+  getMultiTokenProof,
+  getMultiTokenRoot,
+  getProof,
+  getRoot,
+} from "./utils/merkle";
+
+// The size of a campaign prior to the decoupling of its address from the Merkle root and the
+// expiration time.
+const LEGACY_CAMPAIGN_SIZE = 8 + 3 * 32 + 1 + 3 * 8 + (4 + 59) + (4 + 32);
+// The size of a claim receipt prior to the recording of the claim details.
+const LEGACY_CLAIM_RECEIPT_SIZE = 8;
 
 export class MerkleInstantTestContext extends TestContext {
   // Programs and addresses
@@ -28,7 +62,7 @@ export class MerkleInstantTestContext extends TestContext {
 
   /** For the recipient declared in the base TestContext */
   public readonly defaultIndex = 0;
-  private defaultMerkleProof!: number[][];
+  public defaultMerkleProof!: number[][];
 
   // Merkle Tree
   private leaves!: LeafData[];
@@ -94,6 +128,58 @@ export class MerkleInstantTestContext extends TestContext {
                             STATE-CHANGING INSTRUCTIONS
   //////////////////////////////////////////////////////////////////////////*/
 
+  async addAirdropToken({
+    signer = this.campaignCreator.keys,
+    campaign = this.defaultCampaign,
+    airdropTokenMint = this.dai,
+    airdropTokenProgram = ProgramId.TOKEN_2022,
+  } = {}): Promise<void> {
+    const txIx = await this.merkleInstant.methods
+      .addAirdropToken()
+      .accounts({
+        airdropTokenMint,
+        airdropTokenProgram,
+        campaign,
+        campaignCreator: signer.publicKey,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, signer);
+  }
+
+  async addToMintList({ signer = this.feeCollector.keys, mint = this.usdc } = {}): Promise<void> {
+    const txIx = await this.merkleInstant.methods
+      .addToMintList()
+      .accounts({
+        feeCollector: signer.publicKey,
+        mint,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, signer);
+  }
+
+  async cancelCampaign({
+    signer = this.campaignCreator.keys,
+    campaign = this.defaultCampaign,
+    claimBitmaps = [] as PublicKey[],
+  } = {}): Promise<void> {
+    const txIx = await this.merkleInstant.methods
+      .cancelCampaign()
+      .accounts({
+        airdropTokenMint: this.usdc,
+        airdropTokenProgram: ProgramId.TOKEN,
+        campaign,
+        campaignCreator: signer.publicKey,
+      })
+      .remainingAccounts(
+        claimBitmaps.map((pubkey) => ({ isSigner: false, isWritable: true, pubkey })),
+      )
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, signer);
+  }
+
   async claim({
     campaign = this.defaultCampaign,
     claimerKeys = this.recipient.keys,
@@ -101,10 +187,16 @@ export class MerkleInstantTestContext extends TestContext {
     recipientAddress = this.recipient.keys.publicKey,
     airdropTokenMint = this.usdc,
     airdropTokenProgram = ProgramId.TOKEN,
+    claimMode = ClaimMode.RECEIPT,
+    leafFormat = LeafFormat.SABLIER,
+    eligibilityAccounts = [] as PublicKey[],
+    treasuryAta = null as PublicKey | null,
   } = {}): Promise<void> {
+    const merkleProof = getProof(this.leaves, this.leaves[this.defaultIndex], leafFormat);
+
     const txIx = await this.merkleInstant.methods
-      .claim(this.defaultIndex, amount, this.defaultMerkleProof)
-      .accounts({
+      .claim(this.defaultIndex, amount, merkleProof)
+      .accountsPartial({
         airdropTokenMint,
         airdropTokenProgram,
         campaign: campaign,
@@ -112,13 +204,249 @@ export class MerkleInstantTestContext extends TestContext {
         chainlinkSolUsdFeed: ProgramId.CHAINLINK_SOL_USD_FEED,
         claimer: claimerKeys.publicKey,
         recipient: recipientAddress,
+        treasuryAta,
+        ...this.claimStatusAccounts(campaign, this.defaultIndex, claimMode),
       })
+      .remainingAccounts(
+        eligibilityAccounts.map((pubkey) => ({ isSigner: false, isWritable: false, pubkey })),
+      )
       .instruction();
 
     // Build and sign the transaction
     await buildSignAndProcessTx(this.banksClient, txIx, claimerKeys);
   }
 
+  async claimCumulative({
+    campaign = this.defaultCampaign,
+    claimerKeys = this.recipient.keys,
+    cumulativeAmount = Amount.CLAIM,
+    leafFormat = LeafFormat.SABLIER,
+  } = {}): Promise<void> {
+    const leaves = this.cumulativeLeaves(cumulativeAmount);
+    const merkleProof = getProof(leaves, leaves[this.defaultIndex], leafFormat);
+
+    const txIx = await this.merkleInstant.methods
+      .claimCumulative(this.defaultIndex, cumulativeAmount, merkleProof)
+      .accounts({
+        airdropTokenMint: this.usdc,
+        airdropTokenProgram: ProgramId.TOKEN,
+        campaign,
+        chainlinkProgram: ProgramId.CHAINLINK_PROGRAM,
+        chainlinkSolUsdFeed: ProgramId.CHAINLINK_SOL_USD_FEED,
+        claimer: claimerKeys.publicKey,
+        recipient: this.recipient.keys.publicKey,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, claimerKeys);
+  }
+
+  async claimMultiToken({
+    campaign = this.defaultCampaign,
+    claimerKeys = this.recipient.keys,
+    amounts = [Amount.CLAIM, Amount.CLAIM],
+    additionalTokens = [{ mint: this.dai, program: ProgramId.TOKEN_2022 }],
+  } = {}): Promise<void> {
+    const leaves = this.multiTokenLeaves(amounts);
+    const recipient = this.recipient.keys.publicKey;
+
+    const remainingAccounts = additionalTokens.flatMap(({ mint, program }) => [
+      { isSigner: false, isWritable: false, pubkey: mint },
+      { isSigner: false, isWritable: true, pubkey: deriveATAAddress(mint, campaign, program) },
+      { isSigner: false, isWritable: true, pubkey: deriveATAAddress(mint, recipient, program) },
+      { isSigner: false, isWritable: false, pubkey: program },
+    ]);
+
+    const txIx = await this.merkleInstant.methods
+      .claimMultiToken(this.defaultIndex, amounts, getMultiTokenProof(leaves, leaves[this.defaultIndex]))
+      .accountsPartial({
+        airdropTokenMint: this.usdc,
+        airdropTokenProgram: ProgramId.TOKEN,
+        campaign,
+        chainlinkProgram: ProgramId.CHAINLINK_PROGRAM,
+        chainlinkSolUsdFeed: ProgramId.CHAINLINK_SOL_USD_FEED,
+        claimer: claimerKeys.publicKey,
+        recipient,
+        ...this.claimStatusAccounts(campaign, this.defaultIndex, ClaimMode.RECEIPT),
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, claimerKeys);
+  }
+
+  async claimMultiple({
+    campaign = this.defaultCampaign,
+    claimerKeys = this.recipient.keys,
+    indexes = [0, 1, 2, 3],
+    amount = Amount.CLAIM,
+    claimMode = ClaimMode.RECEIPT,
+  } = {}): Promise<void> {
+    const leaves = indexes.map((index) => ({
+      amount,
+      index,
+      merkleProof: getProof(this.leaves, this.leaves[index]),
+      recipient: this.leaves[index].recipient,
+    }));
+
+    const txIx = await this.merkleInstant.methods
+      .claimMultiple(leaves)
+      .accounts({
+        airdropTokenMint: this.usdc,
+        airdropTokenProgram: ProgramId.TOKEN,
+        campaign,
+        chainlinkProgram: ProgramId.CHAINLINK_PROGRAM,
+        chainlinkSolUsdFeed: ProgramId.CHAINLINK_SOL_USD_FEED,
+        claimer: claimerKeys.publicKey,
+      })
+      .remainingAccounts(this.claimMultipleAccounts(campaign, leaves, claimMode))
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, claimerKeys);
+  }
+
+  async claimMultipleWithMultiproof({
+    campaign = this.defaultCampaign,
+    claimerKeys = this.recipient.keys,
+    indexes = [0, 1, 2, 3],
+    amount = Amount.CLAIM,
+    claimMode = ClaimMode.RECEIPT,
+    treeLeaves = this.leaves,
+  } = {}): Promise<void> {
+    const targetLeaves = indexes.map((index) => treeLeaves[index]);
+    const { leaves, proof, proofFlags } = getMultiProof(treeLeaves, targetLeaves);
+    const claimLeaves = leaves.map((leaf) => ({ ...leaf, amount }));
+
+    const txIx = await this.merkleInstant.methods
+      .claimMultipleWithMultiproof(claimLeaves, proof, proofFlags)
+      .accounts({
+        airdropTokenMint: this.usdc,
+        airdropTokenProgram: ProgramId.TOKEN,
+        campaign,
+        chainlinkProgram: ProgramId.CHAINLINK_PROGRAM,
+        chainlinkSolUsdFeed: ProgramId.CHAINLINK_SOL_USD_FEED,
+        claimer: claimerKeys.publicKey,
+      })
+      .remainingAccounts(this.claimMultipleAccounts(campaign, claimLeaves, claimMode))
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, claimerKeys);
+  }
+
+  async claimNative({
+    campaign = this.defaultCampaign,
+    claimerKeys = this.recipient.keys,
+    amount = Amount.CLAIM,
+    recipientAddress = this.recipient.keys.publicKey,
+    claimMode = ClaimMode.RECEIPT,
+  } = {}): Promise<void> {
+    const merkleProof = getProof(this.leaves, this.leaves[this.defaultIndex]);
+
+    const txIx = await this.merkleInstant.methods
+      .claimNative(this.defaultIndex, amount, merkleProof)
+      .accountsPartial({
+        campaign,
+        chainlinkProgram: ProgramId.CHAINLINK_PROGRAM,
+        chainlinkSolUsdFeed: ProgramId.CHAINLINK_SOL_USD_FEED,
+        claimer: claimerKeys.publicKey,
+        recipient: recipientAddress,
+        ...this.claimStatusAccounts(campaign, this.defaultIndex, claimMode),
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, claimerKeys);
+  }
+
+  async claimTo({
+    campaign = this.defaultCampaign,
+    recipientKeys = this.recipient.keys,
+    amount = Amount.CLAIM,
+    destination = this.recipient.keys.publicKey,
+    claimMode = ClaimMode.RECEIPT,
+    eligibilityAccounts = [] as PublicKey[],
+  } = {}): Promise<void> {
+    const txIx = await this.merkleInstant.methods
+      .claimTo(this.defaultIndex, amount, this.defaultMerkleProof)
+      .accountsPartial({
+        airdropTokenMint: this.usdc,
+        airdropTokenProgram: ProgramId.TOKEN,
+        campaign,
+        chainlinkProgram: ProgramId.CHAINLINK_PROGRAM,
+        chainlinkSolUsdFeed: ProgramId.CHAINLINK_SOL_USD_FEED,
+        destination,
+        recipient: recipientKeys.publicKey,
+        treasuryAta: null,
+        ...this.claimStatusAccounts(campaign, this.defaultIndex, claimMode),
+      })
+      .remainingAccounts(
+        eligibilityAccounts.map((pubkey) => ({ isSigner: false, isWritable: false, pubkey })),
+      )
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, recipientKeys);
+  }
+
+  async claimWithProofAccount({
+    campaign = this.defaultCampaign,
+    claimerKeys = this.recipient.keys,
+    amount = Amount.CLAIM,
+    claimMode = ClaimMode.RECEIPT,
+  } = {}): Promise<void> {
+    const txIx = await this.merkleInstant.methods
+      .claimWithProofAccount(this.defaultIndex, amount)
+      .accountsPartial({
+        airdropTokenMint: this.usdc,
+        airdropTokenProgram: ProgramId.TOKEN,
+        campaign,
+        chainlinkProgram: ProgramId.CHAINLINK_PROGRAM,
+        chainlinkSolUsdFeed: ProgramId.CHAINLINK_SOL_USD_FEED,
+        claimer: claimerKeys.publicKey,
+        proofBuffer: this.proofBufferAddress(campaign, claimerKeys.publicKey),
+        recipient: this.recipient.keys.publicKey,
+        treasuryAta: null,
+        ...this.claimStatusAccounts(campaign, this.defaultIndex, claimMode),
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, claimerKeys);
+  }
+
+  async claimWithSig({
+    campaign = this.defaultCampaign,
+    claimerKeys = this.campaignCreator.keys,
+    signerKeys = this.recipient.keys,
+    amount = Amount.CLAIM,
+    destination = this.recipient.keys.publicKey,
+    signatureExpiry = Campaign.EXPIRATION_TIME,
+    signedDestination = destination,
+    withSignature = true,
+    claimMode = ClaimMode.RECEIPT,
+  } = {}): Promise<void> {
+    const ed25519Ix = Ed25519Program.createInstructionWithPrivateKey({
+      message: this.claimMessage(campaign, this.defaultIndex, amount, signedDestination, signatureExpiry),
+      privateKey: signerKeys.secretKey,
+    });
+
+    const txIx = await this.merkleInstant.methods
+      .claimWithSig(this.defaultIndex, amount, this.defaultMerkleProof, signatureExpiry)
+      .accountsPartial({
+        airdropTokenMint: this.usdc,
+        airdropTokenProgram: ProgramId.TOKEN,
+        campaign,
+        chainlinkProgram: ProgramId.CHAINLINK_PROGRAM,
+        chainlinkSolUsdFeed: ProgramId.CHAINLINK_SOL_USD_FEED,
+        claimer: claimerKeys.publicKey,
+        destination,
+        recipient: this.recipient.keys.publicKey,
+        treasuryAta: null,
+        ...this.claimStatusAccounts(campaign, this.defaultIndex, claimMode),
+      })
+      .instruction();
+
+    const txIxs = withSignature ? [ed25519Ix, txIx] : [txIx];
+    await buildSignAndProcessTx(this.banksClient, txIxs, claimerKeys);
+  }
+
   async claimFeeInLamports(): Promise<BN> {
     return await this.merkleInstant.methods
       .claimFeeInLamports()
@@ -152,9 +480,110 @@ export class MerkleInstantTestContext extends TestContext {
     await buildSignAndProcessTx(this.banksClient, txIx, signer);
   }
 
+  async clawbackNative({
+    signer = this.campaignCreator.keys,
+    campaign = this.defaultCampaign,
+    amount = Amount.CLAWBACK,
+    clawbackRecipient = this.clawbackRecipient.keys.publicKey,
+  } = {}): Promise<void> {
+    const txIx = await this.merkleInstant.methods
+      .clawbackNative(amount)
+      .accounts({
+        campaign,
+        campaignCreator: signer.publicKey,
+        clawbackRecipient,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, signer);
+  }
+
+  async closeCampaign({
+    signer = this.campaignCreator.keys,
+    campaign = this.defaultCampaign,
+    airdropTokenMint = this.usdc,
+    airdropTokenProgram = ProgramId.TOKEN,
+    claimBitmaps = [] as PublicKey[],
+    additionalTokens = [] as { mint: PublicKey; program: PublicKey }[],
+  } = {}): Promise<void> {
+    const additionalAccounts = additionalTokens.flatMap(({ mint, program }) => [
+      { isSigner: false, isWritable: true, pubkey: deriveATAAddress(mint, campaign, program) },
+      { isSigner: false, isWritable: false, pubkey: program },
+    ]);
+
+    const txIx = await this.merkleInstant.methods
+      .closeCampaign()
+      .accounts({
+        airdropTokenMint,
+        airdropTokenProgram,
+        campaign,
+        campaignCreator: signer.publicKey,
+      })
+      .remainingAccounts([
+        ...additionalAccounts,
+        ...claimBitmaps.map((pubkey) => ({ isSigner: false, isWritable: true, pubkey })),
+      ])
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, signer);
+  }
+
+  async closeClaimReceipts({
+    signer = this.recipient.keys,
+    campaign = this.defaultCampaign,
+    indexes = [this.defaultIndex],
+    claimers = [this.recipient.keys.publicKey],
+  } = {}): Promise<void> {
+    const remainingAccounts = indexes.flatMap((index, i) => [
+      { isSigner: false, isWritable: true, pubkey: this.claimReceiptAddress(campaign, index) },
+      { isSigner: false, isWritable: true, pubkey: claimers[i] },
+    ]);
+
+    const txIx = await this.merkleInstant.methods
+      .closeClaimReceipts(indexes)
+      .accounts({
+        campaign,
+        signer: signer.publicKey,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, signer);
+  }
+
+  async closeNativeCampaign({
+    signer = this.campaignCreator.keys,
+    campaign = this.defaultCampaign,
+    claimBitmaps = [] as PublicKey[],
+  } = {}): Promise<void> {
+    const txIx = await this.merkleInstant.methods
+      .closeNativeCampaign()
+      .accounts({
+        campaign,
+        campaignCreator: signer.publicKey,
+      })
+      .remainingAccounts(claimBitmaps.map((pubkey) => ({ isSigner: false, isWritable: true, pubkey })))
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, signer);
+  }
+
+  async closeProofBuffer({
+    campaign = this.defaultCampaign,
+    claimerKeys = this.recipient.keys,
+  } = {}): Promise<void> {
+    const txIx = await this.merkleInstant.methods
+      .closeProofBuffer()
+      .accounts({ campaign, claimer: claimerKeys.publicKey })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, claimerKeys);
+  }
+
   async collectFees({
     signer = this.feeCollector.keys,
-    feeRecipient = this.recipient.keys.publicKey,
+    feeRecipient = this.recipient.keys.publicKey as PublicKey | null,
+    feeSplitRecipients = [] as PublicKey[],
   } = {}): Promise<void> {
     const txIx = await this.merkleInstant.methods
       .collectFees()
@@ -162,6 +591,28 @@ export class MerkleInstantTestContext extends TestContext {
         feeCollector: signer.publicKey,
         feeRecipient,
       })
+      .remainingAccounts(
+        feeSplitRecipients.map((pubkey) => ({ isSigner: false, isWritable: true, pubkey })),
+      )
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, signer);
+  }
+
+  async collectTokenFees({
+    signer = this.feeCollector.keys,
+    feeRecipient = this.recipient.keys.publicKey,
+    tokenMint = this.usdc,
+    tokenProgram = ProgramId.TOKEN,
+  } = {}): Promise<void> {
+    const txIx = await this.merkleInstant.methods
+      .collectTokenFees()
+      .accounts({
+        feeCollector: signer.publicKey,
+        feeRecipient,
+        tokenMint,
+        tokenProgram,
+      })
       .instruction();
 
     await buildSignAndProcessTx(this.banksClient, txIx, signer);
@@ -175,15 +626,19 @@ export class MerkleInstantTestContext extends TestContext {
     expirationTime = Campaign.EXPIRATION_TIME,
     airdropTokenMint = this.usdc,
     airdropTokenProgram = ProgramId.TOKEN,
+    claimMode = ClaimMode.RECEIPT,
+    gracePeriod = Campaign.GRACE_PERIOD,
+    leafFormat = LeafFormat.SABLIER,
+    merkleRoot = undefined as number[] | undefined,
+    eligibilityRule = null as EligibilityRule | null,
+    tokenClaimFee = null as TokenClaimFee | null,
   } = {}): Promise<PublicKey> {
     // Derive the address of the campaign
     const campaign = getPDAAddress(
       [
         Seed.CAMPAIGN,
         creator.keys.publicKey.toBuffer(),
-        Buffer.from(this.merkleRoot),
         startTime.toArrayLike(Buffer, "le", 8),
-        expirationTime.toArrayLike(Buffer, "le", 8),
         Buffer.from(name),
         airdropTokenMint.toBuffer(),
       ],
@@ -192,13 +647,18 @@ export class MerkleInstantTestContext extends TestContext {
 
     const txIx = await this.merkleInstant.methods
       .createCampaign(
-        this.merkleRoot,
+        merkleRoot ?? getRoot(this.leaves, leafFormat),
         startTime,
         expirationTime,
         name,
         Campaign.IPFS_CID,
         Amount.AGGREGATE,
         this.leaves.length,
+        claimMode,
+        gracePeriod,
+        leafFormat,
+        eligibilityRule,
+        tokenClaimFee,
       )
       .accounts({
         airdropTokenMint,
@@ -232,6 +692,87 @@ export class MerkleInstantTestContext extends TestContext {
     return campaign;
   }
 
+  async createClaimBitmap({
+    signer = this.campaignCreator.keys,
+    campaign = this.defaultCampaign,
+    chunkIndex = 0,
+  } = {}): Promise<void> {
+    const txIx = await this.merkleInstant.methods
+      .createClaimBitmap(chunkIndex)
+      .accounts({
+        campaign,
+        payer: signer.publicKey,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, signer);
+  }
+
+  async createNativeCampaign({
+    creator = this.campaignCreator,
+    name = Campaign.NAME,
+    startTime = Campaign.START_TIME,
+    claimMode = ClaimMode.RECEIPT,
+    fundingAmount = Amount.AGGREGATE.add(Amount.NATIVE_BUFFER),
+  } = {}): Promise<PublicKey> {
+    // Derive the address of the campaign, which uses the native mint as the airdrop token
+    const campaign = getPDAAddress(
+      [
+        Seed.CAMPAIGN,
+        creator.keys.publicKey.toBuffer(),
+        startTime.toArrayLike(Buffer, "le", 8),
+        Buffer.from(name),
+        NATIVE_MINT.toBuffer(),
+      ],
+      this.merkleInstant.programId,
+    );
+
+    const txIx = await this.merkleInstant.methods
+      .createNativeCampaign(
+        getRoot(this.leaves),
+        startTime,
+        Campaign.EXPIRATION_TIME,
+        name,
+        Campaign.IPFS_CID,
+        Amount.AGGREGATE,
+        this.leaves.length,
+        claimMode,
+        Campaign.GRACE_PERIOD,
+        LeafFormat.SABLIER,
+      )
+      .accounts({ creator: creator.keys.publicKey })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, creator.keys);
+
+    // Fund the campaign with the aggregate amount and the buffer kept on top of the rent-exempt minimum
+    await transferLamports(
+      this.banksClient,
+      creator.keys,
+      creator.keys.publicKey,
+      campaign,
+      fundingAmount.toNumber(),
+    );
+
+    return campaign;
+  }
+
+  async extendExpiration({
+    signer = this.campaignCreator.keys,
+    campaign = this.defaultCampaign,
+    newExpirationTime = Campaign.EXPIRATION_TIME.add(Campaign.MIN_EXPIRATION_NOTICE),
+  } = {}): Promise<void> {
+    const txIx = await this.merkleInstant.methods
+      .extendExpiration(newExpirationTime)
+      .accounts({
+        campaign,
+        campaignCreator: signer.publicKey,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, signer);
+  }
+
   async initializeMerkleInstant(): Promise<void> {
     const initializeIx = await this.merkleInstant.methods
       .initialize(
@@ -247,10 +788,202 @@ export class MerkleInstantTestContext extends TestContext {
     await buildSignAndProcessTx(this.banksClient, initializeIx, this.campaignCreator.keys);
   }
 
+  async migrateCampaign({
+    campaign = this.defaultCampaign,
+    signer = this.campaignCreator.keys,
+  } = {}): Promise<void> {
+    const txIx = await this.merkleInstant.methods
+      .migrateCampaign()
+      .accounts({
+        campaign,
+        signer: signer.publicKey,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, signer);
+  }
+
+  async migrateTreasury({ signer = this.campaignCreator.keys } = {}): Promise<void> {
+    const txIx = await this.merkleInstant.methods
+      .migrateTreasury()
+      .accounts({
+        signer: signer.publicKey,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, signer);
+  }
+
+  async pause({ signer = this.feeCollector.keys } = {}): Promise<void> {
+    const txIx = await this.merkleInstant.methods
+      .pause()
+      .accounts({
+        feeCollector: signer.publicKey,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, signer);
+  }
+
+  async removeFromMintList({
+    signer = this.feeCollector.keys,
+    mint = this.usdc,
+  } = {}): Promise<void> {
+    const txIx = await this.merkleInstant.methods
+      .removeFromMintList()
+      .accounts({
+        feeCollector: signer.publicKey,
+        mint,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, signer);
+  }
+
+  async setFeeSplit({
+    signer = this.feeCollector.keys,
+    feeSplit = [] as FeeShare[],
+  } = {}): Promise<void> {
+    const txIx = await this.merkleInstant.methods
+      .setFeeSplit(feeSplit)
+      .accounts({
+        feeCollector: signer.publicKey,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, signer);
+  }
+
+  async setMintListMode({
+    signer = this.feeCollector.keys,
+    mintListMode = MintListMode.ALLOWLIST,
+  } = {}): Promise<void> {
+    const txIx = await this.merkleInstant.methods
+      .setMintListMode(mintListMode)
+      .accounts({
+        feeCollector: signer.publicKey,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, signer);
+  }
+
+  async unpause({ signer = this.feeCollector.keys } = {}): Promise<void> {
+    const txIx = await this.merkleInstant.methods
+      .unpause()
+      .accounts({
+        feeCollector: signer.publicKey,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, signer);
+  }
+
+  async updateMerkleRoot({
+    signer = this.campaignCreator.keys,
+    campaign = this.defaultCampaign,
+    merkleRoot = Array.from(this.merkleRoot),
+    ipfsCid = Campaign.IPFS_CID,
+  } = {}): Promise<void> {
+    const txIx = await this.merkleInstant.methods
+      .updateMerkleRoot(merkleRoot, ipfsCid)
+      .accounts({
+        campaign,
+        campaignCreator: signer.publicKey,
+      })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, signer);
+  }
+
+  async writeProofBuffer({
+    campaign = this.defaultCampaign,
+    claimerKeys = this.recipient.keys,
+    proofElements = this.defaultMerkleProof,
+  } = {}): Promise<void> {
+    const txIx = await this.merkleInstant.methods
+      .writeProofBuffer(proofElements)
+      .accounts({ campaign, claimer: claimerKeys.publicKey })
+      .instruction();
+
+    await buildSignAndProcessTx(this.banksClient, txIx, claimerKeys);
+  }
+
   /*//////////////////////////////////////////////////////////////////////////
                                READ-ONLY INSTRUCTIONS
   //////////////////////////////////////////////////////////////////////////*/
 
+  async campaignStats({
+    campaign = this.defaultCampaign,
+    airdropTokenMint = this.usdc,
+    airdropTokenProgram = ProgramId.TOKEN,
+    campaignAta = deriveATAAddress(airdropTokenMint, campaign, airdropTokenProgram) as PublicKey | null,
+  } = {}): Promise<CampaignStatsData> {
+    return await this.merkleInstant.methods
+      .campaignStats()
+      .accountsPartial({
+        airdropTokenMint,
+        airdropTokenProgram,
+        campaign,
+        campaignAta,
+      })
+      .signers([this.defaultBankrunPayer])
+      .view();
+  }
+
+  async claimedBitmapOf({
+    campaign = this.defaultCampaign,
+    startIndex = 0,
+    count = this.leaves.length,
+    claimMode = ClaimMode.RECEIPT,
+  } = {}): Promise<number[]> {
+    // Pass the claim receipt of each index, or the claim bitmaps covering the range
+    const indexes = Array.from({ length: count }, (_, offset) => startIndex + offset);
+    const claimStatuses = indexes.map((index) => {
+      const { claimBitmap, claimReceipt } = this.claimStatusAccounts(campaign, index, claimMode);
+      return (claimBitmap ?? claimReceipt) as PublicKey;
+    });
+    const uniqueClaimStatuses = claimStatuses.filter(
+      (pubkey, i) => i === 0 || !pubkey.equals(claimStatuses[i - 1]),
+    );
+
+    const claimedBitmap: Buffer = await this.merkleInstant.methods
+      .claimedBitmapOf(startIndex, count)
+      .accounts({ campaign })
+      .remainingAccounts(
+        uniqueClaimStatuses.map((pubkey) => ({ isSigner: false, isWritable: false, pubkey })),
+      )
+      .signers([this.defaultBankrunPayer])
+      .view();
+    return Array.from(claimedBitmap);
+  }
+
+  async claimReceiptView({
+    campaign = this.defaultCampaign,
+    index = this.defaultIndex,
+  } = {}): Promise<ClaimReceipt> {
+    return await this.merkleInstant.methods
+      .claimReceiptView(index)
+      .accounts({ campaign })
+      .signers([this.defaultBankrunPayer])
+      .view();
+  }
+
+  async hasClaimed({
+    campaign = this.defaultCampaign,
+    index = this.defaultIndex,
+    claimMode = ClaimMode.RECEIPT,
+  } = {}): Promise<boolean> {
+    return await this.merkleInstant.methods
+      .hasClaimed(index)
+      .accountsPartial({
+        campaign,
+        ...this.claimStatusAccounts(campaign, index, claimMode),
+      })
+      .signers([this.defaultBankrunPayer])
+      .view();
+  }
+
   async treasuryView(): Promise<Treasury> {
     return await this.merkleInstant.methods
       .treasuryView()
@@ -263,20 +996,212 @@ export class MerkleInstantTestContext extends TestContext {
                                    HELPERS
   //////////////////////////////////////////////////////////////////////////*/
 
+  campaignTombstoneAddress(campaign = this.defaultCampaign): PublicKey {
+    return getPDAAddress(
+      [Seed.CAMPAIGN_TOMBSTONE, campaign.toBuffer()],
+      this.merkleInstant.programId,
+    );
+  }
+
+  claimBitmapAddress(campaign = this.defaultCampaign, chunkIndex = 0): PublicKey {
+    const chunkIndexBuffer = Buffer.alloc(4);
+    chunkIndexBuffer.writeUInt32LE(chunkIndex);
+    return getPDAAddress(
+      [Seed.CLAIM_BITMAP, campaign.toBuffer(), chunkIndexBuffer],
+      this.merkleInstant.programId,
+    );
+  }
+
+  // Returns the message signed by the recipient to authorize a claim via `claim_with_sig`.
+  claimMessage(
+    campaign: PublicKey,
+    index: number,
+    amount: BN,
+    destination: PublicKey,
+    signatureExpiry: BN,
+  ): Buffer {
+    const indexBuffer = Buffer.alloc(4);
+    indexBuffer.writeUInt32LE(index);
+    return Buffer.concat([
+      campaign.toBuffer(),
+      indexBuffer,
+      amount.toArrayLike(Buffer, "le", 8),
+      destination.toBuffer(),
+      signatureExpiry.toArrayLike(Buffer, "le", 8),
+    ]);
+  }
+
+  // Returns the remaining accounts expected by the batch claims: the claim status, the recipient and its ATA per leaf.
+  claimMultipleAccounts(
+    campaign: PublicKey,
+    leaves: { index: number; recipient: PublicKey }[],
+    claimMode: object,
+  ): anchor.web3.AccountMeta[] {
+    return leaves.flatMap((leaf) => {
+      const { claimBitmap, claimReceipt } = this.claimStatusAccounts(campaign, leaf.index, claimMode);
+      return [
+        { isSigner: false, isWritable: true, pubkey: (claimReceipt ?? claimBitmap) as PublicKey },
+        { isSigner: false, isWritable: false, pubkey: leaf.recipient },
+        {
+          isSigner: false,
+          isWritable: true,
+          pubkey: deriveATAAddress(this.usdc, leaf.recipient, ProgramId.TOKEN),
+        },
+      ];
+    });
+  }
+
+  claimReceiptAddress(campaign = this.defaultCampaign, index = this.defaultIndex): PublicKey {
+    const indexBuffer = Buffer.alloc(4);
+    indexBuffer.writeUInt32LE(index);
+    return getPDAAddress(
+      [Seed.CLAIM_RECEIPT, campaign.toBuffer(), indexBuffer],
+      this.merkleInstant.programId,
+    );
+  }
+
+  // Returns the claim status accounts expected by the given claim mode, leaving the other one unset.
+  claimStatusAccounts(
+    campaign: PublicKey,
+    index: number,
+    claimMode: object,
+  ): { claimBitmap: PublicKey | null; claimReceipt: PublicKey | null } {
+    if ("bitmap" in claimMode) {
+      const chunkIndex = Math.floor(index / CLAIMS_PER_BITMAP);
+      return { claimBitmap: this.claimBitmapAddress(campaign, chunkIndex), claimReceipt: null };
+    }
+    return { claimBitmap: null, claimReceipt: this.claimReceiptAddress(campaign, index) };
+  }
+
+  cumulativeClaimAddress(campaign = this.defaultCampaign, recipient = this.recipient.keys.publicKey): PublicKey {
+    return getPDAAddress(
+      [Seed.CUMULATIVE_CLAIM, campaign.toBuffer(), recipient.toBuffer()],
+      this.merkleInstant.programId,
+    );
+  }
+
+  mintListEntryAddress(mint = this.usdc): PublicKey {
+    return getPDAAddress([Seed.MINT_LIST_ENTRY, mint.toBuffer()], this.merkleInstant.programId);
+  }
+
+  // Returns the Merkle root of the default leaves, with each of them carrying the given amounts of a multi-token
+  // campaign.
+  multiTokenMerkleRoot(amounts: BN[]): number[] {
+    return getMultiTokenRoot(this.multiTokenLeaves(amounts));
+  }
+
+  // Returns the Merkle root of the default leaves, with each of them carrying the given cumulative amount.
+  cumulativeMerkleRoot(cumulativeAmount: BN): number[] {
+    return getRoot(this.cumulativeLeaves(cumulativeAmount));
+  }
+
   defaultCampaignData(): CampaignData {
     return {
+      additionalAirdropTokenMints: [],
+      additionalTotalClaimedAmounts: [],
+      aggregateAmount: Amount.AGGREGATE,
       airdropTokenMint: this.usdc,
       bump: 0,
       campaignStartTime: Campaign.START_TIME,
+      claimCount: 0,
+      claimMode: ClaimMode.RECEIPT,
       creator: this.campaignCreator.keys.publicKey,
+      eligibilityRule: null,
       expirationTime: Campaign.EXPIRATION_TIME,
       firstClaimTime: ZERO,
+      gracePeriod: Campaign.GRACE_PERIOD,
       ipfsCid: Campaign.IPFS_CID,
+      isNative: false,
+      lastClaimTime: ZERO,
+      leafFormat: LeafFormat.SABLIER,
+      legacySeeds: null,
       merkleRoot: Array.from(this.merkleRoot),
       name: Campaign.NAME,
+      tokenClaimFee: null,
+      totalClaimedAmount: ZERO,
+      totalClawedBack: ZERO,
     };
   }
 
+  // Writes a campaign in the layout of a prior version of the program, at the address derived from
+  // the Merkle root and the expiration time, and funds its ATA with the aggregate amount.
+  async simulateLegacyCampaign({
+    name = "Legacy Campaign",
+    firstClaimTime = ZERO,
+  } = {}): Promise<PublicKey> {
+    const creator = this.campaignCreator.keys.publicKey;
+    const merkleRoot = getRoot(this.leaves);
+    const [campaign, bump] = PublicKey.findProgramAddressSync(
+      [
+        Seed.CAMPAIGN,
+        creator.toBuffer(),
+        Buffer.from(merkleRoot),
+        Campaign.START_TIME.toArrayLike(Buffer, "le", 8),
+        Campaign.EXPIRATION_TIME.toArrayLike(Buffer, "le", 8),
+        Buffer.from(name),
+        this.usdc.toBuffer(),
+      ],
+      this.merkleInstant.programId,
+    );
+
+    const borshString = (value: string) => {
+      const length = Buffer.alloc(4);
+      length.writeUInt32LE(value.length);
+      return Buffer.concat([length, Buffer.from(value)]);
+    };
+    const campaignAccount = IDL.accounts.find((account) => account.name === "Campaign");
+    const discriminator = campaignAccount?.discriminator ?? [];
+    const data = Buffer.alloc(LEGACY_CAMPAIGN_SIZE);
+    Buffer.concat([
+      Buffer.from(discriminator),
+      this.usdc.toBuffer(),
+      Buffer.from([bump]),
+      Campaign.START_TIME.toArrayLike(Buffer, "le", 8),
+      creator.toBuffer(),
+      Campaign.EXPIRATION_TIME.toArrayLike(Buffer, "le", 8),
+      firstClaimTime.toArrayLike(Buffer, "le", 8),
+      borshString(Campaign.IPFS_CID),
+      Buffer.from(merkleRoot),
+      borshString(name),
+    ]).copy(data);
+
+    const rent = await this.banksClient.getRent();
+    this.context.setAccount(campaign, {
+      data,
+      executable: false,
+      lamports: Number(rent.minimumBalance(BigInt(LEGACY_CAMPAIGN_SIZE))),
+      owner: this.merkleInstant.programId,
+    });
+    await createATAAndFund(
+      this.banksClient,
+      this.defaultBankrunPayer,
+      this.usdc,
+      Amount.AGGREGATE,
+      ProgramId.TOKEN,
+      campaign,
+    );
+
+    return campaign;
+  }
+
+  async simulateLegacyClaimReceipt({
+    campaign = this.defaultCampaign,
+    index = this.defaultIndex,
+  } = {}): Promise<PublicKey> {
+    const claimReceipt = this.claimReceiptAddress(campaign, index);
+    const claimReceiptAccount = IDL.accounts.find((account) => account.name === "ClaimReceipt");
+
+    const rent = await this.banksClient.getRent();
+    this.context.setAccount(claimReceipt, {
+      data: Buffer.from(claimReceiptAccount?.discriminator ?? []),
+      executable: false,
+      lamports: Number(rent.minimumBalance(BigInt(LEGACY_CLAIM_RECEIPT_SIZE))),
+      owner: this.merkleInstant.programId,
+    });
+
+    return claimReceipt;
+  }
+
   async getTreasuryLamports(): Promise<BN> {
     return await this.getLamportsOf(this.treasuryAddress);
   }
@@ -284,4 +1209,19 @@ export class MerkleInstantTestContext extends TestContext {
   async fetchCampaignData(campaign = this.defaultCampaign): Promise<CampaignData> {
     return await this.merkleInstant.account.campaign.fetch(campaign);
   }
+
+  proofBufferAddress(campaign = this.defaultCampaign, claimer = this.recipient.keys.publicKey): PublicKey {
+    return getPDAAddress(
+      [Seed.PROOF_BUFFER, campaign.toBuffer(), claimer.toBuffer()],
+      this.merkleInstant.programId,
+    );
+  }
+
+  private multiTokenLeaves(amounts: BN[]): MultiTokenLeafData[] {
+    return this.leaves.map(({ index, recipient }) => ({ amounts, index, recipient }));
+  }
+
+  private cumulativeLeaves(cumulativeAmount: BN): LeafData[] {
+    return this.leaves.map((leaf) => ({ ...leaf, amount: cumulativeAmount }));
+  }
 }
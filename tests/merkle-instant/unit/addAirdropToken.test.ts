@@ -0,0 +1,102 @@
+import { ANCHOR_ERROR__CONSTRAINT_ADDRESS as CONSTRAINT_ADDRESS } from "@coral-xyz/anchor-errors";
+import type { PublicKey } from "@solana/web3.js";
+import { assert, beforeEach, describe, it } from "vitest";
+import { ProgramId } from "../../../lib/constants";
+import { deriveATAAddress } from "../../common/anchor-bankrun";
+import { assertAccountExists } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+import { Campaign, ClaimMode, LeafFormat, MintListMode } from "../utils/defaults";
+
+let ctx: MerkleInstantTestContext;
+let campaign: PublicKey;
+
+describe("addAirdropToken", () => {
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+  });
+
+  describe("when the signer is not the campaign creator", () => {
+    it("should fail", async () => {
+      await expectToThrow(ctx.addAirdropToken({ signer: ctx.eve.keys }), CONSTRAINT_ADDRESS);
+    });
+  });
+
+  describe("when the signer is the campaign creator", () => {
+    describe("given campaign started", () => {
+      it("should fail", async () => {
+        await expectToThrow(ctx.addAirdropToken(), "AirdropTokenAdditionNotAllowed");
+      });
+    });
+
+    describe("given campaign not started", () => {
+      const startTime = Campaign.START_TIME.addn(1000);
+
+      describe("when the leaf format is not the sablier one", () => {
+        it("should fail", async () => {
+          campaign = await ctx.createCampaign({
+            leafFormat: LeafFormat.SABER,
+            name: "Saber Campaign",
+            startTime,
+          });
+          await expectToThrow(ctx.addAirdropToken({ campaign }), "MultiTokenNotSupported");
+        });
+      });
+
+      describe("when the campaign tracks cumulative claims", () => {
+        it("should fail", async () => {
+          campaign = await ctx.createCampaign({
+            claimMode: ClaimMode.CUMULATIVE,
+            name: "Cumulative Campaign",
+            startTime,
+          });
+          await expectToThrow(ctx.addAirdropToken({ campaign }), "MultiTokenNotSupported");
+        });
+      });
+
+      describe("when the leaf format is the sablier one", () => {
+        beforeEach(async () => {
+          campaign = await ctx.createCampaign({ name: "Multi-Token Campaign", startTime });
+        });
+
+        describe("when the token is already airdropped", () => {
+          it("should fail", async () => {
+            await expectToThrow(
+              ctx.addAirdropToken({ airdropTokenMint: ctx.usdc, airdropTokenProgram: ProgramId.TOKEN, campaign }),
+              "AirdropTokenAlreadyAdded",
+            );
+          });
+        });
+
+        describe("when the token is not airdropped yet", () => {
+          describe("when the program is paused", () => {
+            it("should fail", async () => {
+              await ctx.pause();
+              await expectToThrow(ctx.addAirdropToken({ campaign }), "ProgramIsPaused");
+            });
+          });
+
+          describe("when the token is not allowed by the mint list", () => {
+            it("should fail", async () => {
+              await ctx.setMintListMode({ mintListMode: MintListMode.ALLOWLIST });
+              await expectToThrow(ctx.addAirdropToken({ campaign }), "MintNotAllowed");
+            });
+          });
+
+          it("should add the airdrop token", async () => {
+            await ctx.addAirdropToken({ campaign });
+
+            // Assert that the token has been added to the campaign
+            const campaignData = await ctx.fetchCampaignData(campaign);
+            assert.deepEqual(campaignData.additionalAirdropTokenMints, [ctx.dai]);
+
+            // Assert that the campaign's ATA for the token has been created
+            const campaignAta = deriveATAAddress(ctx.dai, campaign, ProgramId.TOKEN_2022);
+            await assertAccountExists(ctx, campaignAta, "Campaign's ATA");
+          });
+        });
+      });
+    });
+  });
+});
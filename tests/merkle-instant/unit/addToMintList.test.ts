@@ -0,0 +1,55 @@
+import {
+  ANCHOR_ERROR__ACCOUNT_NOT_INITIALIZED as ACCOUNT_NOT_INITIALIZED,
+  ANCHOR_ERROR__CONSTRAINT_ADDRESS as CONSTRAINT_ADDRESS,
+} from "@coral-xyz/anchor-errors";
+import { beforeAll, beforeEach, describe, it } from "vitest";
+import { assertAccountExists } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+
+let ctx: MerkleInstantTestContext;
+
+describe("addToMintList", () => {
+  describe("when the program is not initialized", () => {
+    beforeAll(async () => {
+      ctx = new MerkleInstantTestContext();
+      await ctx.setUpMerkleInstant({
+        initProgram: false,
+      });
+    });
+
+    it("should fail", async () => {
+      await expectToThrow(ctx.addToMintList(), ACCOUNT_NOT_INITIALIZED);
+    });
+  });
+
+  describe("when the program is initialized", () => {
+    beforeEach(async () => {
+      ctx = new MerkleInstantTestContext();
+      await ctx.setUpMerkleInstant();
+    });
+
+    describe("when signer is not the authorized fee collector", () => {
+      it("should fail", async () => {
+        await expectToThrow(ctx.addToMintList({ signer: ctx.eve.keys }), CONSTRAINT_ADDRESS);
+      });
+    });
+
+    describe("when signer is the authorized fee collector", () => {
+      describe("when the mint is already in the mint list", () => {
+        it("should fail", async () => {
+          await ctx.addToMintList();
+          await expectToThrow(ctx.addToMintList(), 0x0);
+        });
+      });
+
+      describe("when the mint is not in the mint list", () => {
+        it("should add the mint to the mint list", async () => {
+          await ctx.addToMintList();
+
+          await assertAccountExists(ctx, ctx.mintListEntryAddress(), "Mint list entry");
+        });
+      });
+    });
+  });
+});
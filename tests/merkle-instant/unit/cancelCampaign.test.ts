@@ -0,0 +1,118 @@
+import { ANCHOR_ERROR__CONSTRAINT_ADDRESS as CONSTRAINT_ADDRESS } from "@coral-xyz/anchor-errors";
+import type { PublicKey } from "@solana/web3.js";
+import { beforeEach, describe, it } from "vitest";
+import { ProgramId } from "../../../lib/constants";
+import { deriveATAAddress, getATABalance } from "../../common/anchor-bankrun";
+import { assertAccountExists, assertAccountNotExists, assertEqBn } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+import { Amount, Campaign, ClaimMode } from "../utils/defaults";
+
+let ctx: MerkleInstantTestContext;
+
+describe("cancelCampaign", () => {
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+  });
+
+  describe("when the signer is not the campaign creator", () => {
+    it("should fail", async () => {
+      await expectToThrow(ctx.cancelCampaign({ signer: ctx.eve.keys }), CONSTRAINT_ADDRESS);
+    });
+  });
+
+  describe("when the signer is the campaign creator", () => {
+    describe("given campaign started", () => {
+      it("should fail", async () => {
+        await expectToThrow(ctx.cancelCampaign(), "CampaignAlreadyStarted");
+      });
+    });
+
+    describe("given campaign not started", () => {
+      beforeEach(async () => {
+        await ctx.timeTravelTo(Campaign.START_TIME.subn(1));
+      });
+
+      describe("given a claim made", () => {
+        it("should fail", async () => {
+          // Claim once the campaign has started, and then travel back before the start time
+          await ctx.timeTravelTo(Campaign.START_TIME);
+          await ctx.claim();
+          await ctx.timeTravelTo(Campaign.START_TIME.subn(1));
+
+          await expectToThrow(ctx.cancelCampaign(), "CampaignCancelNotAllowed");
+        });
+      });
+
+      describe("given a claim made before the migration of a legacy campaign", () => {
+        it("should fail", async () => {
+          const firstClaimTime = Campaign.START_TIME;
+          const campaign = await ctx.simulateLegacyCampaign({ firstClaimTime });
+          await ctx.migrateCampaign({ campaign });
+
+          await expectToThrow(ctx.cancelCampaign({ campaign }), "CampaignCancelNotAllowed");
+        });
+      });
+
+      describe("given no claim made", () => {
+        describe("given receipt claim mode", () => {
+          it("should cancel the campaign", async () => {
+            await testCancelCampaign();
+          });
+
+          it("should retire the campaign address", async () => {
+            await ctx.cancelCampaign();
+            await expectToThrow(ctx.createCampaign(), "CampaignAddressRetired");
+          });
+        });
+
+        describe("given bitmap claim mode", () => {
+          let campaign: PublicKey;
+          let claimBitmap: PublicKey;
+
+          beforeEach(async () => {
+            campaign = await ctx.createCampaign({
+              claimMode: ClaimMode.BITMAP,
+              name: "Bitmap Campaign",
+            });
+            await ctx.createClaimBitmap({ campaign });
+            claimBitmap = ctx.claimBitmapAddress(campaign);
+          });
+
+          describe("when a claim bitmap belongs to another campaign", () => {
+            it("should fail", async () => {
+              await expectToThrow(
+                ctx.cancelCampaign({ claimBitmaps: [claimBitmap] }),
+                "InvalidClaimBitmap",
+              );
+            });
+          });
+
+          describe("when the claim bitmaps belong to the campaign", () => {
+            it("should cancel the campaign and close the claim bitmaps", async () => {
+              await testCancelCampaign(campaign, [claimBitmap]);
+              await assertAccountNotExists(ctx, claimBitmap, "Claim Bitmap");
+            });
+          });
+        });
+      });
+    });
+  });
+});
+
+async function testCancelCampaign(campaign = ctx.defaultCampaign, claimBitmaps: PublicKey[] = []) {
+  const campaignAta = deriveATAAddress(ctx.usdc, campaign, ProgramId.TOKEN);
+  const creatorAtaBalanceBefore = await getATABalance(ctx.banksClient, ctx.campaignCreator.usdcATA);
+
+  await ctx.cancelCampaign({ campaign, claimBitmaps });
+
+  // Assert that the Campaign and its ATA have been closed, and that the campaign address has been retired
+  await assertAccountNotExists(ctx, campaign, "Campaign");
+  await assertAccountNotExists(ctx, campaignAta, "Campaign's ATA");
+  await assertAccountExists(ctx, ctx.campaignTombstoneAddress(campaign), "Campaign Tombstone");
+
+  // Assert that the full balance of the campaign has been refunded to the creator
+  const creatorAtaBalanceAfter = await getATABalance(ctx.banksClient, ctx.campaignCreator.usdcATA);
+  assertEqBn(creatorAtaBalanceAfter, creatorAtaBalanceBefore.add(Amount.AGGREGATE));
+}
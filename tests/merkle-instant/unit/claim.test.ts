@@ -2,12 +2,21 @@ import { ANCHOR_ERROR__ACCOUNT_NOT_INITIALIZED as ACCOUNT_NOT_INITIALIZED } from
 import { PublicKey } from "@solana/web3.js";
 import { assert, beforeAll, beforeEach, describe, it } from "vitest";
 import { BN_1, ProgramId, ZERO } from "../../../lib/constants";
+import { dai } from "../../../lib/convertors";
 import { sleepFor } from "../../../lib/helpers";
-import { createATAAndFund, getATABalanceMint } from "../../common/anchor-bankrun";
+import { createATAAndFund, getATABalance, getATABalanceMint } from "../../common/anchor-bankrun";
 import { assertEqBn, assertLteBn, assertZeroBn } from "../../common/assertions";
 import { MerkleInstantTestContext } from "../context";
 import { expectToThrow } from "../utils/assertions";
-import { Amount, Campaign, Time } from "../utils/defaults";
+import {
+  Amount,
+  Campaign,
+  ClaimMode,
+  EligibilityKind,
+  LeafFormat,
+  Time,
+  TokenClaimFee,
+} from "../utils/defaults";
 
 let ctx: MerkleInstantTestContext;
 
@@ -135,6 +144,57 @@ describe("claim", () => {
                     });
                   });
 
+                  describe("given bitmap claim mode", () => {
+                    let campaign: PublicKey;
+
+                    beforeEach(async () => {
+                      campaign = await ctx.createCampaign({
+                        claimMode: ClaimMode.BITMAP,
+                        name: "Bitmap Campaign",
+                      });
+                    });
+
+                    describe("when the claim bitmap doesn't exist", () => {
+                      it("should fail", async () => {
+                        await expectToThrow(
+                          ctx.claim({ campaign, claimMode: ClaimMode.BITMAP }),
+                          ACCOUNT_NOT_INITIALIZED,
+                        );
+                      });
+                    });
+
+                    describe("when the claim bitmap exists", () => {
+                      beforeEach(async () => {
+                        await ctx.createClaimBitmap({ campaign });
+                      });
+
+                      describe("when the airdrop has already been claimed", () => {
+                        it("should fail", async () => {
+                          await ctx.claim({ campaign, claimMode: ClaimMode.BITMAP });
+                          await sleepFor(7);
+
+                          await expectToThrow(
+                            ctx.claim({ campaign, claimMode: ClaimMode.BITMAP }),
+                            "AlreadyClaimed",
+                          );
+                        });
+                      });
+
+                      describe("when the airdrop has not been claimed", () => {
+                        it("should claim the airdrop", async () => {
+                          await testClaim(
+                            campaign,
+                            ctx.recipient.keys,
+                            ctx.usdc,
+                            ProgramId.TOKEN,
+                            true,
+                            ClaimMode.BITMAP,
+                          );
+                        });
+                      });
+                    });
+                  });
+
                   describe("when the claimer is the recipient", () => {
                     describe("given token SPL standard", () => {
                       it("should claim the airdrop", async () => {
@@ -143,6 +203,182 @@ describe("claim", () => {
                       });
                     });
 
+                    describe("given saber leaf format", () => {
+                      it("should claim the airdrop", async () => {
+                        const campaign = await ctx.createCampaign({
+                          leafFormat: LeafFormat.SABER,
+                          name: "Saber Campaign",
+                        });
+                        const campaignAtaBalanceBefore = await getATABalanceMint(
+                          ctx.banksClient,
+                          campaign,
+                          ctx.usdc,
+                        );
+
+                        await ctx.claim({ campaign, leafFormat: LeafFormat.SABER });
+
+                        assert.isTrue(await ctx.hasClaimed({ campaign }));
+                        const campaignAtaBalanceAfter = await getATABalanceMint(
+                          ctx.banksClient,
+                          campaign,
+                          ctx.usdc,
+                        );
+                        assertEqBn(campaignAtaBalanceAfter, campaignAtaBalanceBefore.sub(Amount.CLAIM));
+                      });
+                    });
+
+                    describe("given token-gated campaign", () => {
+                      let campaign: PublicKey;
+
+                      beforeEach(async () => {
+                        // The recipient holds 1M DAI
+                        campaign = await ctx.createCampaign({
+                          eligibilityRule: {
+                            key: ctx.dai,
+                            kind: EligibilityKind.MINT,
+                            minAmount: dai(1000),
+                          },
+                          name: "Token-gated Campaign",
+                        });
+                      });
+
+                      describe("when the recipient's token account is missing", () => {
+                        it("should fail", async () => {
+                          await expectToThrow(ctx.claim({ campaign }), "InvalidRemainingAccounts");
+                        });
+                      });
+
+                      describe("when the token account is not the recipient's", () => {
+                        it("should fail", async () => {
+                          await expectToThrow(
+                            ctx.claim({ campaign, eligibilityAccounts: [ctx.eve.daiATA] }),
+                            "NotEligible",
+                          );
+                        });
+                      });
+
+                      describe("when the token account holds another token", () => {
+                        it("should fail", async () => {
+                          await expectToThrow(
+                            ctx.claim({ campaign, eligibilityAccounts: [ctx.recipient.usdcATA] }),
+                            "NotEligible",
+                          );
+                        });
+                      });
+
+                      describe("when the recipient holds less than the minimum amount", () => {
+                        it("should fail", async () => {
+                          const gatedCampaign = await ctx.createCampaign({
+                            eligibilityRule: {
+                              key: ctx.dai,
+                              kind: EligibilityKind.MINT,
+                              minAmount: dai(1_000_001),
+                            },
+                            name: "Whales-only Campaign",
+                          });
+
+                          await expectToThrow(
+                            ctx.claim({
+                              campaign: gatedCampaign,
+                              eligibilityAccounts: [ctx.recipient.daiATA],
+                            }),
+                            "NotEligible",
+                          );
+                        });
+                      });
+
+                      describe("when the recipient holds the minimum amount", () => {
+                        it("should claim the airdrop", async () => {
+                          const recipient = ctx.recipient.keys.publicKey;
+                          const recipientAtaBalanceBefore = await getATABalanceMint(
+                            ctx.banksClient,
+                            recipient,
+                            ctx.usdc,
+                          );
+
+                          await ctx.claim({
+                            campaign,
+                            eligibilityAccounts: [ctx.recipient.daiATA],
+                          });
+
+                          assert.isTrue(await ctx.hasClaimed({ campaign }));
+                          const recipientAtaBalanceAfter = await getATABalanceMint(
+                            ctx.banksClient,
+                            recipient,
+                            ctx.usdc,
+                          );
+                          assertEqBn(
+                            recipientAtaBalanceAfter,
+                            recipientAtaBalanceBefore.add(Amount.CLAIM),
+                          );
+                        });
+                      });
+                    });
+
+                    describe("given campaign charging the claim fee in the airdrop token", () => {
+                      describe("when the treasury's ATA is missing", () => {
+                        it("should fail", async () => {
+                          const campaign = await ctx.createCampaign({
+                            name: "Token Fee Campaign",
+                            tokenClaimFee: TokenClaimFee.BASIS_POINTS,
+                          });
+
+                          await expectToThrow(ctx.claim({ campaign }), "TreasuryAtaMissing");
+                        });
+                      });
+
+                      describe("when the fixed fee exceeds the claimed amount", () => {
+                        it("should fail", async () => {
+                          const campaign = await ctx.createCampaign({
+                            name: "Token Fee Campaign",
+                            tokenClaimFee: TokenClaimFee.fixed(Amount.CLAIM.add(BN_1)),
+                          });
+
+                          await expectToThrow(
+                            ctx.claim({ campaign, treasuryAta: ctx.treasuryAtaAddress() }),
+                            "TokenClaimFeeExceedsAmount",
+                          );
+                        });
+                      });
+
+                      describe("when the treasury's ATA is provided", () => {
+                        it("should deduct the fee from the claimed amount", async () => {
+                          const campaign = await ctx.createCampaign({
+                            name: "Token Fee Campaign",
+                            tokenClaimFee: TokenClaimFee.BASIS_POINTS,
+                          });
+                          const recipient = ctx.recipient.keys.publicKey;
+                          const recipientAtaBalanceBefore = await getATABalanceMint(
+                            ctx.banksClient,
+                            recipient,
+                            ctx.usdc,
+                          );
+                          const treasuryLamportsBefore = await ctx.getTreasuryLamports();
+
+                          await ctx.claim({ campaign, treasuryAta: ctx.treasuryAtaAddress() });
+
+                          // 1% of the claimed amount goes to the treasury's ATA.
+                          const expectedFee = Amount.CLAIM.divn(100);
+                          const recipientAtaBalanceAfter = await getATABalanceMint(
+                            ctx.banksClient,
+                            recipient,
+                            ctx.usdc,
+                          );
+                          assertEqBn(
+                            recipientAtaBalanceAfter,
+                            recipientAtaBalanceBefore.add(Amount.CLAIM).sub(expectedFee),
+                          );
+                          assertEqBn(
+                            await getATABalance(ctx.banksClient, ctx.treasuryAtaAddress()),
+                            expectedFee,
+                          );
+
+                          // No claim fee is charged in SOL.
+                          assertEqBn(await ctx.getTreasuryLamports(), treasuryLamportsBefore);
+                        });
+                      });
+                    });
+
                     describe("given token 2022 standard", () => {
                       it("should claim the airdrop", async () => {
                         // Test the claim.
@@ -172,9 +408,10 @@ async function testClaim(
   tokenMint = ctx.usdc,
   tokenProgram = ProgramId.TOKEN,
   recipientAtaExists = true,
+  claimMode = ClaimMode.RECEIPT,
 ): Promise<void> {
   // Assert that the claim was not made yet.
-  assert.isFalse(await hasClaimed());
+  assert.isFalse(await ctx.hasClaimed({ campaign, claimMode }));
 
   // Get the Campaign's data before claiming
   const campaignDataBefore = await ctx.fetchCampaignData(campaign);
@@ -198,13 +435,22 @@ async function testClaim(
     airdropTokenProgram: tokenProgram,
     campaign: campaign,
     claimerKeys: claimer,
+    claimMode,
   });
 
   const campaignDataAfter = await ctx.fetchCampaignData(campaign);
   assertEqBn(campaignDataAfter.firstClaimTime, Time.GENESIS);
 
-  // Assert that the claim has been made
-  assert.isTrue(await hasClaimed(campaign));
+  // Assert that the claim statistics have been updated
+  assert.equal(campaignDataAfter.claimCount, campaignDataBefore.claimCount + 1);
+  assertEqBn(campaignDataAfter.lastClaimTime, Time.GENESIS);
+  assertEqBn(
+    campaignDataAfter.totalClaimedAmount,
+    campaignDataBefore.totalClaimedAmount.add(Amount.CLAIM),
+  );
+
+  // Assert that the claim has been made. Implicitly tests the `has_claimed` Ix works.
+  assert.isTrue(await ctx.hasClaimed({ campaign, claimMode }));
 
   const campaignAtaBalanceAfter = await getATABalanceMint(ctx.banksClient, campaign, tokenMint);
 
@@ -232,14 +478,3 @@ async function testClaim(
   // Assert that the Treasury has been credited with the claim fee.
   assertEqBn(treasuryLamportsAfter, treasuryLamportsBefore.add(expectedFee));
 }
-
-// Implicitly tests the `has_claimed` Ix works.
-async function hasClaimed(campaign = ctx.defaultCampaign): Promise<boolean> {
-  return await ctx.merkleInstant.methods
-    .hasClaimed(ctx.defaultIndex)
-    .accounts({
-      campaign: campaign,
-    })
-    .signers([ctx.defaultBankrunPayer])
-    .view();
-}
@@ -0,0 +1,145 @@
+import type { BN } from "@coral-xyz/anchor";
+import type { PublicKey } from "@solana/web3.js";
+import { beforeEach, describe, it } from "vitest";
+import { BN_1 } from "../../../lib/constants";
+import { getATABalanceMint } from "../../common/anchor-bankrun";
+import { assertEqBn } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+import { Amount, ClaimMode, LeafFormat } from "../utils/defaults";
+
+let ctx: MerkleInstantTestContext;
+let campaign: PublicKey;
+
+describe("claimCumulative", () => {
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+  });
+
+  describe("given not cumulative claim mode", () => {
+    it("should fail", async () => {
+      await expectToThrow(ctx.claimCumulative(), "CumulativeClaimNotSupported");
+    });
+  });
+
+  describe("given cumulative claim mode", () => {
+    beforeEach(async () => {
+      campaign = await ctx.createCampaign({
+        claimMode: ClaimMode.CUMULATIVE,
+        name: "Cumulative Campaign",
+      });
+    });
+
+    describe("when a one-shot claim is made", () => {
+      it("should fail", async () => {
+        await expectToThrow(ctx.claim({ campaign }), "CumulativeClaimRequired");
+      });
+    });
+
+    describe("when the merkle proof is invalid", () => {
+      it("should fail", async () => {
+        await expectToThrow(
+          ctx.claimCumulative({ campaign, cumulativeAmount: Amount.CLAIM.sub(BN_1) }),
+          "InvalidMerkleProof",
+        );
+      });
+    });
+
+    describe("when the merkle proof is valid", () => {
+      describe("when the cumulative amount has already been claimed", () => {
+        it("should fail", async () => {
+          await ctx.claimCumulative({ campaign });
+          await expectToThrow(ctx.claimCumulative({ campaign }), "NothingToClaim");
+        });
+      });
+
+      describe("when the cumulative amount has not been claimed", () => {
+        it("should pay out the increase of the cumulative amount", async () => {
+          // Claim the cumulative amount of the first Merkle tree
+          await testClaimCumulative(Amount.CLAIM, Amount.CLAIM);
+
+          // Post a new Merkle tree, with the cumulative amounts doubled
+          const newCumulativeAmount = Amount.CLAIM.muln(2);
+          await ctx.updateMerkleRoot({
+            campaign,
+            merkleRoot: ctx.cumulativeMerkleRoot(newCumulativeAmount),
+          });
+
+          // Claim the difference
+          await testClaimCumulative(newCumulativeAmount, Amount.CLAIM);
+        });
+
+        describe("when the recipient has not claimed from the previous Merkle tree", () => {
+          it("should pay out the full cumulative amount", async () => {
+            const newCumulativeAmount = Amount.CLAIM.muln(2);
+            await ctx.updateMerkleRoot({
+              campaign,
+              merkleRoot: ctx.cumulativeMerkleRoot(newCumulativeAmount),
+            });
+
+            await testClaimCumulative(newCumulativeAmount, newCumulativeAmount);
+          });
+        });
+
+        describe("when the new Merkle tree decreases the cumulative amount", () => {
+          it("should fail", async () => {
+            await testClaimCumulative(Amount.CLAIM, Amount.CLAIM);
+
+            // Post a new Merkle tree, with the cumulative amounts below the amount already claimed
+            const newCumulativeAmount = Amount.CLAIM.sub(BN_1);
+            await ctx.updateMerkleRoot({
+              campaign,
+              merkleRoot: ctx.cumulativeMerkleRoot(newCumulativeAmount),
+            });
+
+            await expectToThrow(
+              ctx.claimCumulative({ campaign, cumulativeAmount: newCumulativeAmount }),
+              "NothingToClaim",
+            );
+          });
+        });
+
+        describe("given jito leaf format", () => {
+          it("should pay out the cumulative amount", async () => {
+            campaign = await ctx.createCampaign({
+              claimMode: ClaimMode.CUMULATIVE,
+              leafFormat: LeafFormat.JITO,
+              name: "Jito Campaign",
+            });
+
+            await testClaimCumulative(Amount.CLAIM, Amount.CLAIM, LeafFormat.JITO);
+          });
+        });
+      });
+    });
+  });
+});
+
+async function testClaimCumulative(
+  cumulativeAmount: BN,
+  expectedPayout: BN,
+  leafFormat = LeafFormat.SABLIER,
+) {
+  const recipientAtaBalanceBefore = await getATABalanceMint(
+    ctx.banksClient,
+    ctx.recipient.keys.publicKey,
+    ctx.usdc,
+  );
+
+  await ctx.claimCumulative({ campaign, cumulativeAmount, leafFormat });
+
+  // Assert that the recipient received the payout
+  const recipientAtaBalanceAfter = await getATABalanceMint(
+    ctx.banksClient,
+    ctx.recipient.keys.publicKey,
+    ctx.usdc,
+  );
+  assertEqBn(recipientAtaBalanceAfter, recipientAtaBalanceBefore.add(expectedPayout));
+
+  // Assert that the amount claimed so far has been updated
+  const cumulativeClaim = await ctx.merkleInstant.account.cumulativeClaim.fetch(
+    ctx.cumulativeClaimAddress(campaign),
+  );
+  assertEqBn(cumulativeClaim.claimedAmount, cumulativeAmount);
+}
@@ -0,0 +1,123 @@
+import type { PublicKey } from "@solana/web3.js";
+import { assert, beforeEach, describe, it } from "vitest";
+import { BN_1, ProgramId } from "../../../lib/constants";
+import { deriveATAAddress, getATABalanceMint, transfer } from "../../common/anchor-bankrun";
+import { assertAccountNotExists, assertEqBn } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+import { Amount, Campaign } from "../utils/defaults";
+
+const AMOUNTS = [Amount.CLAIM, Amount.CLAIM.muln(2)];
+const START_TIME = Campaign.START_TIME.addn(1000);
+
+let ctx: MerkleInstantTestContext;
+let campaign: PublicKey;
+
+describe("claimMultiToken", () => {
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+
+    // Create a campaign airdropping USDC and DAI, and fund it with DAI
+    campaign = await ctx.createCampaign({
+      merkleRoot: ctx.multiTokenMerkleRoot(AMOUNTS),
+      name: "Multi-Token Campaign",
+      startTime: START_TIME,
+    });
+    await ctx.addAirdropToken({ campaign });
+    await transfer(
+      ctx.banksClient,
+      ctx.campaignCreator.keys,
+      ctx.campaignCreator.daiATA,
+      deriveATAAddress(ctx.dai, campaign, ProgramId.TOKEN_2022),
+      ctx.campaignCreator.keys.publicKey,
+      Amount.AGGREGATE,
+      [],
+      ProgramId.TOKEN_2022,
+    );
+
+    await ctx.timeTravelTo(START_TIME);
+  });
+
+  describe("when the main airdrop token is claimed alone", () => {
+    it("should fail", async () => {
+      await expectToThrow(ctx.claim({ campaign }), "MultiTokenClaimRequired");
+    });
+  });
+
+  describe("when an amount is missing", () => {
+    it("should fail", async () => {
+      await expectToThrow(
+        ctx.claimMultiToken({ amounts: [Amount.CLAIM], campaign }),
+        "AirdropTokensMismatch",
+      );
+    });
+  });
+
+  describe("when the merkle proof is invalid", () => {
+    it("should fail", async () => {
+      await expectToThrow(
+        ctx.claimMultiToken({ amounts: [Amount.CLAIM, Amount.CLAIM.sub(BN_1)], campaign }),
+        "InvalidMerkleProof",
+      );
+    });
+  });
+
+  describe("when the accounts of the additional token are missing", () => {
+    it("should fail", async () => {
+      await expectToThrow(
+        ctx.claimMultiToken({ additionalTokens: [], amounts: AMOUNTS, campaign }),
+        "InvalidRemainingAccounts",
+      );
+    });
+  });
+
+  describe("when the claim is valid", () => {
+    it("should claim all the airdrop tokens", async () => {
+      const recipient = ctx.recipient.keys.publicKey;
+      const usdcBalanceBefore = await getATABalanceMint(ctx.banksClient, recipient, ctx.usdc);
+      const daiBalanceBefore = await getATABalanceMint(ctx.banksClient, recipient, ctx.dai);
+
+      await ctx.claimMultiToken({ amounts: AMOUNTS, campaign });
+
+      // Assert that the claim has been made
+      assert.isTrue(await ctx.hasClaimed({ campaign }));
+
+      // Assert that the recipient received both tokens
+      const usdcBalanceAfter = await getATABalanceMint(ctx.banksClient, recipient, ctx.usdc);
+      const daiBalanceAfter = await getATABalanceMint(ctx.banksClient, recipient, ctx.dai);
+      assertEqBn(usdcBalanceAfter, usdcBalanceBefore.add(AMOUNTS[0]));
+      assertEqBn(daiBalanceAfter, daiBalanceBefore.add(AMOUNTS[1]));
+
+      // Assert that the amounts claimed of every airdrop token are tracked
+      const campaignData = await ctx.fetchCampaignData(campaign);
+      assertEqBn(campaignData.totalClaimedAmount, AMOUNTS[0]);
+      assertEqBn(campaignData.additionalTotalClaimedAmounts[0], AMOUNTS[1]);
+    });
+  });
+
+  describe("when the campaign is closed", () => {
+    it("should close the campaign's ATAs of all the airdrop tokens", async () => {
+      const dai = { mint: ctx.dai, program: ProgramId.TOKEN_2022 };
+
+      // Claw back both tokens after the expiration
+      await ctx.timeTravelTo(Campaign.EXPIRATION_TIME);
+      await ctx.clawback({ amount: Amount.AGGREGATE, campaign });
+      await ctx.clawback({
+        airdropTokenMint: ctx.dai,
+        airdropTokenProgram: ProgramId.TOKEN_2022,
+        amount: Amount.AGGREGATE,
+        campaign,
+      });
+
+      await ctx.closeCampaign({ additionalTokens: [dai], campaign });
+
+      await assertAccountNotExists(ctx, campaign, "Campaign");
+      await assertAccountNotExists(
+        ctx,
+        deriveATAAddress(ctx.dai, campaign, ProgramId.TOKEN_2022),
+        "Campaign's DAI ATA",
+      );
+    });
+  });
+});
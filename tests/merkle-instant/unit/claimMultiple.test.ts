@@ -0,0 +1,89 @@
+import { assert, beforeEach, describe, it } from "vitest";
+import { BN_1 } from "../../../lib/constants";
+import { getATABalanceMint } from "../../common/anchor-bankrun";
+import { assertEqBn } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+import { Amount, ClaimMode } from "../utils/defaults";
+
+const INDEXES = [0, 1, 2, 3];
+
+let ctx: MerkleInstantTestContext;
+
+describe("claimMultiple", () => {
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+  });
+
+  describe("when there are no leaves", () => {
+    it("should fail", async () => {
+      await expectToThrow(ctx.claimMultiple({ indexes: [] }), "NoLeavesToClaim");
+    });
+  });
+
+  describe("when there are leaves", () => {
+    describe("when a merkle proof is invalid", () => {
+      it("should fail", async () => {
+        await expectToThrow(
+          ctx.claimMultiple({ amount: Amount.CLAIM.sub(BN_1) }),
+          "InvalidMerkleProof",
+        );
+      });
+    });
+
+    describe("when a leaf has already been claimed", () => {
+      it("should fail", async () => {
+        await ctx.claim();
+        await expectToThrow(ctx.claimMultiple(), "AlreadyClaimed");
+      });
+    });
+
+    describe("when no leaf has been claimed", () => {
+      describe("given receipt claim mode", () => {
+        it("should claim all the leaves", async () => {
+          await testClaimMultiple();
+        });
+      });
+
+      describe("given bitmap claim mode", () => {
+        it("should claim all the leaves", async () => {
+          const campaign = await ctx.createCampaign({
+            claimMode: ClaimMode.BITMAP,
+            name: "Bitmap Campaign",
+          });
+          await ctx.createClaimBitmap({ campaign });
+
+          await testClaimMultiple(campaign, ClaimMode.BITMAP);
+        });
+      });
+    });
+  });
+});
+
+async function testClaimMultiple(campaign = ctx.defaultCampaign, claimMode = ClaimMode.RECEIPT) {
+  const treasuryLamportsBefore = await ctx.getTreasuryLamports();
+  const campaignAtaBalanceBefore = await getATABalanceMint(ctx.banksClient, campaign, ctx.usdc);
+
+  await ctx.claimMultiple({ campaign, claimMode, indexes: INDEXES });
+
+  // Assert that all the leaves have been claimed
+  for (const index of INDEXES) {
+    assert.isTrue(await ctx.hasClaimed({ campaign, claimMode, index }));
+  }
+
+  // Assert that the campaign statistics account for all the leaves
+  const campaignData = await ctx.fetchCampaignData(campaign);
+  const totalAmount = Amount.CLAIM.muln(INDEXES.length);
+  assert.equal(campaignData.claimCount, INDEXES.length);
+  assertEqBn(campaignData.totalClaimedAmount, totalAmount);
+
+  // Assert that the campaign's ATA balance decreased by the total amount
+  const campaignAtaBalanceAfter = await getATABalanceMint(ctx.banksClient, campaign, ctx.usdc);
+  assertEqBn(campaignAtaBalanceAfter, campaignAtaBalanceBefore.sub(totalAmount));
+
+  // Assert that the claim fee has been charged once
+  const treasuryLamportsAfter = await ctx.getTreasuryLamports();
+  assertEqBn(treasuryLamportsAfter, treasuryLamportsBefore.add(await ctx.claimFeeInLamports()));
+}
+
@@ -0,0 +1,118 @@
+import { assert, beforeEach, describe, it } from "vitest";
+import { BN_1 } from "../../../lib/constants";
+import { getATABalanceMint } from "../../common/anchor-bankrun";
+import { assertEqBn } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+import { Amount, ClaimMode } from "../utils/defaults";
+import { getRoot } from "../utils/merkle";
+
+const INDEXES = [0, 1, 2, 3];
+const LARGE_BATCH_SIZE = 12;
+
+let ctx: MerkleInstantTestContext;
+
+describe("claimMultipleWithMultiproof", () => {
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+  });
+
+  describe("when there are no leaves", () => {
+    it("should fail", async () => {
+      await expectToThrow(ctx.claimMultipleWithMultiproof({ indexes: [] }), "NoLeavesToClaim");
+    });
+  });
+
+  describe("when there are leaves", () => {
+    describe("when the multiproof is invalid", () => {
+      it("should fail", async () => {
+        await expectToThrow(
+          ctx.claimMultipleWithMultiproof({ amount: Amount.CLAIM.sub(BN_1) }),
+          "InvalidMerkleProof",
+        );
+      });
+    });
+
+    describe("when a leaf has already been claimed", () => {
+      it("should fail", async () => {
+        await ctx.claim();
+        await expectToThrow(ctx.claimMultipleWithMultiproof(), "AlreadyClaimed");
+      });
+    });
+
+    describe("when no leaf has been claimed", () => {
+      describe("when only some leaves are claimed", () => {
+        it("should claim the leaves", async () => {
+          await testClaimMultipleWithMultiproof([1, 2]);
+        });
+      });
+
+      describe("when all the leaves are claimed", () => {
+        it("should claim the leaves", async () => {
+          await testClaimMultipleWithMultiproof(INDEXES);
+        });
+      });
+
+      describe("given bitmap claim mode and a large batch", () => {
+        it("should claim the leaves", async () => {
+          // The leaves share the same recipient, so that the batch fits in a single transaction
+          const treeLeaves = Array.from({ length: LARGE_BATCH_SIZE }, (_, index) => ({
+            amount: Amount.CLAIM,
+            index,
+            recipient: ctx.recipient.keys.publicKey,
+          }));
+          const indexes = treeLeaves.map((leaf) => leaf.index);
+          const campaign = await ctx.createCampaign({
+            claimMode: ClaimMode.BITMAP,
+            merkleRoot: getRoot(treeLeaves),
+            name: "Large Batch Campaign",
+          });
+          await ctx.createClaimBitmap({ campaign });
+
+          await ctx.claimMultipleWithMultiproof({
+            campaign,
+            claimMode: ClaimMode.BITMAP,
+            indexes,
+            treeLeaves,
+          });
+
+          // Assert that all the leaves have been claimed
+          for (const index of indexes) {
+            assert.isTrue(await ctx.hasClaimed({ campaign, claimMode: ClaimMode.BITMAP, index }));
+          }
+          const campaignData = await ctx.fetchCampaignData(campaign);
+          assert.equal(campaignData.claimCount, LARGE_BATCH_SIZE);
+        });
+      });
+    });
+  });
+});
+
+async function testClaimMultipleWithMultiproof(indexes: number[]) {
+  const treasuryLamportsBefore = await ctx.getTreasuryLamports();
+  const campaignAtaBalanceBefore = await getATABalanceMint(
+    ctx.banksClient,
+    ctx.defaultCampaign,
+    ctx.usdc,
+  );
+
+  await ctx.claimMultipleWithMultiproof({ indexes });
+
+  // Assert that all the leaves have been claimed
+  for (const index of indexes) {
+    assert.isTrue(await ctx.hasClaimed({ index }));
+  }
+
+  // Assert that the campaign's ATA balance decreased by the total amount
+  const campaignAtaBalanceAfter = await getATABalanceMint(
+    ctx.banksClient,
+    ctx.defaultCampaign,
+    ctx.usdc,
+  );
+  assertEqBn(campaignAtaBalanceAfter, campaignAtaBalanceBefore.sub(Amount.CLAIM.muln(indexes.length)));
+
+  // Assert that the claim fee has been charged once
+  const treasuryLamportsAfter = await ctx.getTreasuryLamports();
+  assertEqBn(treasuryLamportsAfter, treasuryLamportsBefore.add(await ctx.claimFeeInLamports()));
+}
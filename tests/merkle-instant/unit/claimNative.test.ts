@@ -0,0 +1,96 @@
+import { NATIVE_MINT } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { assert, beforeEach, describe, it } from "vitest";
+import { BN_1, ProgramId } from "../../../lib/constants";
+import { createATA } from "../../common/anchor-bankrun";
+import { assertEqBn } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+import { Amount, Campaign } from "../utils/defaults";
+
+let ctx: MerkleInstantTestContext;
+let campaign: PublicKey;
+
+describe("claimNative", () => {
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+  });
+
+  describe("when the campaign does not airdrop native SOL", () => {
+    it("should fail", async () => {
+      await expectToThrow(ctx.claimNative(), "CampaignNotNative");
+    });
+  });
+
+  describe("when the campaign airdrops native SOL", () => {
+    describe("when the claim is made via the SPL instruction", () => {
+      it("should fail", async () => {
+        // Anyone can create the campaign's ATA for wrapped SOL
+        campaign = await ctx.createNativeCampaign();
+        const payer = ctx.defaultBankrunPayer;
+        await createATA(ctx.banksClient, payer, NATIVE_MINT, campaign, ProgramId.TOKEN);
+
+        await expectToThrow(
+          ctx.claim({ airdropTokenMint: NATIVE_MINT, campaign }),
+          "CampaignIsNative",
+        );
+      });
+    });
+
+    describe("when the campaign balance is insufficient", () => {
+      it("should fail", async () => {
+        // Fund the campaign without the buffer kept on top of the rent-exempt minimum
+        campaign = await ctx.createNativeCampaign({ fundingAmount: Amount.CLAIM });
+        await expectToThrow(ctx.claimNative({ campaign }), "InsufficientNativeBalance");
+      });
+    });
+
+    describe("when the campaign balance is sufficient", () => {
+      beforeEach(async () => {
+        campaign = await ctx.createNativeCampaign();
+      });
+
+      describe("when the merkle proof is invalid", () => {
+        it("should fail", async () => {
+          await expectToThrow(
+            ctx.claimNative({ amount: Amount.CLAIM.sub(BN_1), campaign }),
+            "InvalidMerkleProof",
+          );
+        });
+      });
+
+      describe("when the merkle proof is valid", () => {
+        describe("given campaign expired", () => {
+          it("should fail", async () => {
+            await ctx.timeTravelTo(Campaign.EXPIRATION_TIME);
+            await expectToThrow(ctx.claimNative({ campaign }), "CampaignExpired");
+          });
+        });
+
+        describe("given campaign not expired", () => {
+          it("should transfer the SOL to the recipient", async () => {
+            const recipientLamportsBefore = await ctx.getLamportsOf(ctx.recipient.keys.publicKey);
+            const campaignLamportsBefore = await ctx.getLamportsOf(campaign);
+
+            // Claim on behalf of the recipient, so that the recipient does not pay for the transaction
+            await ctx.claimNative({ campaign, claimerKeys: ctx.campaignCreator.keys });
+
+            // Assert that the claim has been made
+            assert.isTrue(await ctx.hasClaimed({ campaign }));
+
+            // Assert that the SOL has been transferred from the campaign to the recipient
+            const recipientLamportsAfter = await ctx.getLamportsOf(ctx.recipient.keys.publicKey);
+            const campaignLamportsAfter = await ctx.getLamportsOf(campaign);
+            assertEqBn(recipientLamportsAfter, recipientLamportsBefore.add(Amount.CLAIM));
+            assertEqBn(campaignLamportsAfter, campaignLamportsBefore.sub(Amount.CLAIM));
+
+            // Assert that the campaign statistics have been updated
+            const campaignData = await ctx.fetchCampaignData(campaign);
+            assertEqBn(campaignData.totalClaimedAmount, Amount.CLAIM);
+          });
+        });
+      });
+    });
+  });
+});
@@ -0,0 +1,97 @@
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { assert, beforeEach, describe, it } from "vitest";
+import { BN_1 } from "../../../lib/constants";
+import { getATABalanceMint } from "../../common/anchor-bankrun";
+import { assertEqBn } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+import { Amount, EligibilityKind } from "../utils/defaults";
+
+let ctx: MerkleInstantTestContext;
+
+describe("claimTo", () => {
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+  });
+
+  describe("when the signer is not the recipient", () => {
+    it("should fail", async () => {
+      await expectToThrow(ctx.claimTo({ recipientKeys: ctx.eve.keys }), "InvalidMerkleProof");
+    });
+  });
+
+  describe("when the campaign is token-gated", () => {
+    let campaign: PublicKey;
+
+    beforeEach(async () => {
+      campaign = await ctx.createCampaign({
+        eligibilityRule: { key: ctx.dai, kind: EligibilityKind.MINT, minAmount: BN_1 },
+        name: "Token-gated Campaign",
+      });
+    });
+
+    describe("when the recipient's token account is missing", () => {
+      it("should fail", async () => {
+        await expectToThrow(ctx.claimTo({ campaign }), "InvalidRemainingAccounts");
+      });
+    });
+
+    describe("when the recipient is not eligible", () => {
+      it("should fail", async () => {
+        await expectToThrow(
+          ctx.claimTo({ campaign, eligibilityAccounts: [ctx.recipient.usdcATA] }),
+          "NotEligible",
+        );
+      });
+    });
+
+    describe("when the recipient is eligible", () => {
+      it("should claim the airdrop to the destination", async () => {
+        const destination = Keypair.generate().publicKey;
+
+        await ctx.claimTo({ campaign, destination, eligibilityAccounts: [ctx.recipient.daiATA] });
+
+        assert.isTrue(await ctx.hasClaimed({ campaign }));
+        const destinationAtaBalance = await getATABalanceMint(ctx.banksClient, destination, ctx.usdc);
+        assertEqBn(destinationAtaBalance, Amount.CLAIM);
+      });
+    });
+  });
+
+  describe("when the signer is the recipient", () => {
+    describe("when the merkle proof is invalid", () => {
+      it("should fail", async () => {
+        await expectToThrow(ctx.claimTo({ amount: Amount.CLAIM.sub(BN_1) }), "InvalidMerkleProof");
+      });
+    });
+
+    describe("when the merkle proof is valid", () => {
+      it("should claim the airdrop to the destination", async () => {
+        const destination = Keypair.generate().publicKey;
+        const recipientAtaBalanceBefore = await getATABalanceMint(
+          ctx.banksClient,
+          ctx.recipient.keys.publicKey,
+          ctx.usdc,
+        );
+
+        await ctx.claimTo({ destination });
+
+        // Assert that the claim has been made
+        assert.isTrue(await ctx.hasClaimed());
+
+        // Assert that the destination received the airdrop
+        const destinationAtaBalance = await getATABalanceMint(ctx.banksClient, destination, ctx.usdc);
+        assertEqBn(destinationAtaBalance, Amount.CLAIM);
+
+        // Assert that the recipient's ATA balance did not change
+        const recipientAtaBalanceAfter = await getATABalanceMint(
+          ctx.banksClient,
+          ctx.recipient.keys.publicKey,
+          ctx.usdc,
+        );
+        assertEqBn(recipientAtaBalanceAfter, recipientAtaBalanceBefore);
+      });
+    });
+  });
+});
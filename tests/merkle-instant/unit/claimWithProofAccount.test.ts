@@ -0,0 +1,81 @@
+import { ANCHOR_ERROR__ACCOUNT_NOT_INITIALIZED as ACCOUNT_NOT_INITIALIZED } from "@coral-xyz/anchor-errors";
+import { assert, beforeEach, describe, it } from "vitest";
+import { BN_1 } from "../../../lib/constants";
+import { getATABalanceMint } from "../../common/anchor-bankrun";
+import { assertAccountNotExists, assertEqBn, assertLteBn } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+import { Amount } from "../utils/defaults";
+
+let ctx: MerkleInstantTestContext;
+
+describe("claimWithProofAccount", () => {
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+  });
+
+  describe("given proof buffer not written", () => {
+    it("should fail", async () => {
+      await expectToThrow(ctx.claimWithProofAccount(), ACCOUNT_NOT_INITIALIZED);
+    });
+  });
+
+  describe("given proof buffer written", () => {
+    beforeEach(async () => {
+      // Write the proof one element at a time, as for proofs too large to fit in a single transaction
+      for (const proofElement of ctx.defaultMerkleProof) {
+        await ctx.writeProofBuffer({ proofElements: [proofElement] });
+      }
+    });
+
+    describe("when the merkle proof is invalid", () => {
+      it("should fail", async () => {
+        await expectToThrow(
+          ctx.claimWithProofAccount({ amount: Amount.CLAIM.sub(BN_1) }),
+          "InvalidMerkleProof",
+        );
+      });
+    });
+
+    describe("when the merkle proof is valid", () => {
+      it("should claim the airdrop and close the proof buffer", async () => {
+        const campaignAtaBalanceBefore = await getATABalanceMint(
+          ctx.banksClient,
+          ctx.defaultCampaign,
+          ctx.usdc,
+        );
+
+        await ctx.claimWithProofAccount();
+
+        // Assert that the claim has been made
+        assert.isTrue(await ctx.hasClaimed());
+
+        const campaignAtaBalanceAfter = await getATABalanceMint(
+          ctx.banksClient,
+          ctx.defaultCampaign,
+          ctx.usdc,
+        );
+        assertEqBn(campaignAtaBalanceAfter, campaignAtaBalanceBefore.sub(Amount.CLAIM));
+
+        // Assert that the proof buffer has been closed
+        await assertAccountNotExists(ctx, ctx.proofBufferAddress(), "Proof Buffer");
+      });
+    });
+
+    describe("when the claimer closes the proof buffer", () => {
+      it("should refund the rent of the proof buffer", async () => {
+        const proofBuffer = ctx.proofBufferAddress();
+        const claimerLamportsBefore = await ctx.getLamportsOf(ctx.recipient.keys.publicKey);
+
+        await ctx.closeProofBuffer();
+
+        await assertAccountNotExists(ctx, proofBuffer, "Proof Buffer");
+
+        // Assert that the claimer has been refunded the rent, which is greater than the transaction fee
+        const claimerLamportsAfter = await ctx.getLamportsOf(ctx.recipient.keys.publicKey);
+        assertLteBn(claimerLamportsBefore, claimerLamportsAfter);
+      });
+    });
+  });
+});
@@ -0,0 +1,95 @@
+import { Keypair } from "@solana/web3.js";
+import { assert, beforeEach, describe, it } from "vitest";
+import { BN_1 } from "../../../lib/constants";
+import { getATABalanceMint } from "../../common/anchor-bankrun";
+import { assertEqBn } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+import { Amount, Time } from "../utils/defaults";
+
+let ctx: MerkleInstantTestContext;
+
+describe("claimWithSig", () => {
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+  });
+
+  describe("when the merkle proof is invalid", () => {
+    it("should fail", async () => {
+      await expectToThrow(
+        ctx.claimWithSig({ amount: Amount.CLAIM.sub(BN_1) }),
+        "InvalidMerkleProof",
+      );
+    });
+  });
+
+  describe("when the merkle proof is valid", () => {
+    describe("when the signature has expired", () => {
+      it("should fail", async () => {
+        await expectToThrow(
+          ctx.claimWithSig({ signatureExpiry: Time.GENESIS.sub(BN_1) }),
+          "SignatureExpired",
+        );
+      });
+    });
+
+    describe("when the signature has not expired", () => {
+      describe("when the signature verification is missing", () => {
+        it("should fail", async () => {
+          await expectToThrow(ctx.claimWithSig({ withSignature: false }), "InvalidSignature");
+        });
+      });
+
+      describe("when the signer is not the recipient", () => {
+        it("should fail", async () => {
+          await expectToThrow(ctx.claimWithSig({ signerKeys: ctx.eve.keys }), "InvalidSignature");
+        });
+      });
+
+      describe("when the signed destination is not the destination", () => {
+        it("should fail", async () => {
+          await expectToThrow(
+            ctx.claimWithSig({
+              destination: ctx.eve.keys.publicKey,
+              signedDestination: ctx.recipient.keys.publicKey,
+            }),
+            "InvalidSignature",
+          );
+        });
+      });
+
+      describe("when the signature is valid", () => {
+        it("should claim the airdrop to the destination", async () => {
+          const destination = Keypair.generate().publicKey;
+          const campaignAtaBalanceBefore = await getATABalanceMint(
+            ctx.banksClient,
+            ctx.defaultCampaign,
+            ctx.usdc,
+          );
+
+          // Claim with the campaign creator acting as the relayer
+          await ctx.claimWithSig({ destination });
+
+          // Assert that the claim has been made
+          assert.isTrue(await ctx.hasClaimed());
+
+          // Assert that the destination received the airdrop
+          const destinationAtaBalance = await getATABalanceMint(
+            ctx.banksClient,
+            destination,
+            ctx.usdc,
+          );
+          assertEqBn(destinationAtaBalance, Amount.CLAIM);
+
+          const campaignAtaBalanceAfter = await getATABalanceMint(
+            ctx.banksClient,
+            ctx.defaultCampaign,
+            ctx.usdc,
+          );
+          assertEqBn(campaignAtaBalanceAfter, campaignAtaBalanceBefore.sub(Amount.CLAIM));
+        });
+      });
+    });
+  });
+});
@@ -5,12 +5,12 @@ import {
 } from "@coral-xyz/anchor-errors";
 import { PublicKey } from "@solana/web3.js";
 import { beforeAll, beforeEach, describe, it } from "vitest";
-import { ProgramId, ZERO } from "../../../lib/constants";
+import { BN_1, ProgramId, ZERO } from "../../../lib/constants";
 import { createATAAndFund, deriveATAAddress, getATABalanceMint } from "../../common/anchor-bankrun";
 import { assertAccountExists, assertAccountNotExists, assertEqBn } from "../../common/assertions";
 import { MerkleInstantTestContext } from "../context";
 import { expectToThrow } from "../utils/assertions";
-import { Amount, Campaign } from "../utils/defaults";
+import { Amount, Campaign, Time } from "../utils/defaults";
 
 let ctx: MerkleInstantTestContext;
 
@@ -89,6 +89,19 @@ describe("clawback", () => {
               });
             });
 
+            describe("given zero grace period", () => {
+              it("should fail", async () => {
+                const campaign = await ctx.createCampaign({
+                  gracePeriod: ZERO,
+                  name: "No Grace Period",
+                });
+                await ctx.claim({ campaign });
+                await ctx.timeTravelTo(Time.GENESIS.add(BN_1));
+
+                await expectToThrow(ctx.clawback({ campaign }), "ClawbackNotAllowed");
+              });
+            });
+
             describe("given grace period passed", () => {
               beforeEach(async () => {
                 // Time travel to the end of the grace period
@@ -188,6 +201,7 @@ async function testClawback({
     campaign,
     airdropTokenMint,
   );
+  const campaignDataBefore = await ctx.fetchCampaignData(campaign);
   const clawbackRecipientAtaBalanceBefore: BN = clawbackRecipientAtaExists
     ? await getATABalanceMint(ctx.banksClient, clawbackRecipient, airdropTokenMint)
     : ZERO;
@@ -209,6 +223,13 @@ async function testClawback({
   // Assert that the campaign token balance has decreased as expected
   assertEqBn(campaignAtaBalanceBefore, campaignAtaBalanceAfter.add(Amount.CLAWBACK));
 
+  // Assert that the total clawed back amount has increased as expected
+  const campaignDataAfter = await ctx.fetchCampaignData(campaign);
+  assertEqBn(
+    campaignDataAfter.totalClawedBack,
+    campaignDataBefore.totalClawedBack.add(Amount.CLAWBACK),
+  );
+
   const clawbackRecipientAtaBalanceAfter = await getATABalanceMint(
     ctx.banksClient,
     clawbackRecipient,
@@ -0,0 +1,68 @@
+import { ANCHOR_ERROR__CONSTRAINT_ADDRESS as CONSTRAINT_ADDRESS } from "@coral-xyz/anchor-errors";
+import { NATIVE_MINT } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { beforeEach, describe, it } from "vitest";
+import { ProgramId } from "../../../lib/constants";
+import { createATA } from "../../common/anchor-bankrun";
+import { assertEqBn } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+import { Amount } from "../utils/defaults";
+
+let ctx: MerkleInstantTestContext;
+let campaign: PublicKey;
+
+describe("clawbackNative", () => {
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+    campaign = await ctx.createNativeCampaign();
+  });
+
+  describe("when the signer is not the campaign creator", () => {
+    it("should fail", async () => {
+      await expectToThrow(ctx.clawbackNative({ campaign, signer: ctx.eve.keys }), CONSTRAINT_ADDRESS);
+    });
+  });
+
+  describe("when the signer is the campaign creator", () => {
+    describe("when the clawback is made via the SPL instruction", () => {
+      it("should fail", async () => {
+        // Anyone can create the campaign's ATA for wrapped SOL
+        const payer = ctx.defaultBankrunPayer;
+        await createATA(ctx.banksClient, payer, NATIVE_MINT, campaign, ProgramId.TOKEN);
+
+        await expectToThrow(
+          ctx.clawback({ airdropTokenMint: NATIVE_MINT, campaign }),
+          "CampaignIsNative",
+        );
+      });
+    });
+
+    describe("when the amount exceeds the campaign balance", () => {
+      it("should fail", async () => {
+        await expectToThrow(
+          ctx.clawbackNative({ amount: Amount.AGGREGATE.addn(1), campaign }),
+          "InsufficientNativeBalance",
+        );
+      });
+    });
+
+    describe("when the amount does not exceed the campaign balance", () => {
+      it("should claw back the SOL", async () => {
+        const recipient = ctx.clawbackRecipient.keys.publicKey;
+        const recipientLamportsBefore = await ctx.getLamportsOf(recipient);
+
+        await ctx.clawbackNative({ amount: Amount.AGGREGATE, campaign });
+
+        // Assert that the SOL has been transferred to the clawback recipient
+        const recipientLamportsAfter = await ctx.getLamportsOf(recipient);
+        assertEqBn(recipientLamportsAfter, recipientLamportsBefore.add(Amount.AGGREGATE));
+
+        // Assert that the campaign statistics have been updated
+        const campaignData = await ctx.fetchCampaignData(campaign);
+        assertEqBn(campaignData.totalClawedBack, Amount.AGGREGATE);
+      });
+    });
+  });
+});
@@ -0,0 +1,115 @@
+import { ANCHOR_ERROR__CONSTRAINT_ADDRESS as CONSTRAINT_ADDRESS } from "@coral-xyz/anchor-errors";
+import type { PublicKey } from "@solana/web3.js";
+import { beforeEach, describe, it } from "vitest";
+import { ProgramId } from "../../../lib/constants";
+import { deriveATAAddress } from "../../common/anchor-bankrun";
+import { assertAccountExists, assertAccountNotExists, assertLteBn } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+import { Amount, Campaign, ClaimMode } from "../utils/defaults";
+
+let ctx: MerkleInstantTestContext;
+
+describe("closeCampaign", () => {
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+  });
+
+  describe("when the signer is not the campaign creator", () => {
+    it("should fail", async () => {
+      await expectToThrow(ctx.closeCampaign({ signer: ctx.eve.keys }), CONSTRAINT_ADDRESS);
+    });
+  });
+
+  describe("when the signer is the campaign creator", () => {
+    describe("given campaign not expired", () => {
+      it("should fail", async () => {
+        await expectToThrow(ctx.closeCampaign(), "CampaignNotExpired");
+      });
+    });
+
+    describe("given campaign expired", () => {
+      beforeEach(async () => {
+        await ctx.timeTravelTo(Campaign.EXPIRATION_TIME);
+      });
+
+      describe("when the campaign ATA is not empty", () => {
+        it("should fail", async () => {
+          await expectToThrow(ctx.closeCampaign(), "CampaignAtaNotEmpty");
+        });
+      });
+
+      describe("when the campaign ATA is empty", () => {
+        describe("given receipt claim mode", () => {
+          it("should close the campaign", async () => {
+            await ctx.clawback({ amount: Amount.AGGREGATE });
+            await testCloseCampaign();
+          });
+
+          it("should retire the campaign address", async () => {
+            await ctx.clawback({ amount: Amount.AGGREGATE });
+            await ctx.closeCampaign();
+            await expectToThrow(ctx.createCampaign(), "CampaignAddressRetired");
+          });
+        });
+
+        describe("given bitmap claim mode", () => {
+          let campaign: PublicKey;
+          let claimBitmap: PublicKey;
+
+          beforeEach(async () => {
+            // Create the campaign before the expiration so that it can be funded
+            await ctx.timeTravelTo(Campaign.START_TIME);
+            campaign = await ctx.createCampaign({
+              claimMode: ClaimMode.BITMAP,
+              name: "Bitmap Campaign",
+            });
+            await ctx.createClaimBitmap({ campaign });
+            claimBitmap = ctx.claimBitmapAddress(campaign);
+
+            await ctx.timeTravelTo(Campaign.EXPIRATION_TIME);
+            await ctx.clawback({ amount: Amount.AGGREGATE, campaign });
+          });
+
+          describe("when a claim bitmap belongs to another campaign", () => {
+            it("should fail", async () => {
+              await ctx.clawback({ amount: Amount.AGGREGATE });
+              await expectToThrow(
+                ctx.closeCampaign({ claimBitmaps: [claimBitmap] }),
+                "InvalidClaimBitmap",
+              );
+            });
+          });
+
+          describe("when the claim bitmaps belong to the campaign", () => {
+            it("should close the campaign and the claim bitmaps", async () => {
+              await testCloseCampaign(campaign, [claimBitmap]);
+              await assertAccountNotExists(ctx, claimBitmap, "Claim Bitmap");
+            });
+          });
+        });
+      });
+    });
+  });
+});
+
+async function testCloseCampaign(campaign = ctx.defaultCampaign, claimBitmaps: PublicKey[] = []) {
+  const campaignAta = deriveATAAddress(ctx.usdc, campaign, ProgramId.TOKEN);
+  await assertAccountExists(ctx, campaignAta, "Campaign's ATA");
+
+  const creatorLamportsBefore = await ctx.getLamportsOf(ctx.campaignCreator.keys.publicKey);
+  const campaignLamports = await ctx.getLamportsOf(campaign);
+
+  await ctx.closeCampaign({ campaign, claimBitmaps });
+
+  // Assert that the Campaign and its ATA have been closed, and that the campaign address has been retired
+  await assertAccountNotExists(ctx, campaign, "Campaign");
+  await assertAccountNotExists(ctx, campaignAta, "Campaign's ATA");
+  await assertAccountExists(ctx, ctx.campaignTombstoneAddress(campaign), "Campaign Tombstone");
+
+  // Assert that the creator has been refunded the rent. We use `<=` because the creator also pays the transaction
+  // fee and the rent of the tombstone, which are lower than the rent of the campaign's ATA.
+  const creatorLamportsAfter = await ctx.getLamportsOf(ctx.campaignCreator.keys.publicKey);
+  assertLteBn(creatorLamportsBefore.add(campaignLamports), creatorLamportsAfter);
+}
@@ -0,0 +1,99 @@
+import { beforeEach, describe, it } from "vitest";
+import { assertAccountExists, assertAccountNotExists, assertEqBn } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+import { Amount, Campaign } from "../utils/defaults";
+
+let ctx: MerkleInstantTestContext;
+
+describe("closeClaimReceipts", () => {
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+
+    // Claim on behalf of the recipient, so that the campaign creator pays for the claim receipt
+    await ctx.claim({ claimerKeys: ctx.campaignCreator.keys });
+  });
+
+  describe("given campaign not closed", () => {
+    it("should fail", async () => {
+      await expectToThrow(
+        ctx.closeClaimReceipts({ claimers: [ctx.campaignCreator.keys.publicKey] }),
+        "CampaignNotClosed",
+      );
+    });
+  });
+
+  describe("given campaign closed", () => {
+    beforeEach(async () => {
+      await ctx.timeTravelTo(Campaign.EXPIRATION_TIME);
+      await ctx.clawback({ amount: Amount.AGGREGATE.sub(Amount.CLAIM) });
+      await ctx.closeCampaign();
+    });
+
+    describe("when the claimer does not match the claim receipt", () => {
+      it("should fail", async () => {
+        await expectToThrow(ctx.closeClaimReceipts(), "InvalidClaimer");
+      });
+    });
+
+    describe("when the claimer matches the claim receipt", () => {
+      it("should close the claim receipt", async () => {
+        const claimReceipt = ctx.claimReceiptAddress();
+        await assertAccountExists(ctx, claimReceipt, "Claim Receipt");
+
+        const claimerLamportsBefore = await ctx.getLamportsOf(ctx.campaignCreator.keys.publicKey);
+        const claimReceiptLamports = await ctx.getLamportsOf(claimReceipt);
+
+        // Close the claim receipt, with the transaction paid by the recipient
+        await ctx.closeClaimReceipts({ claimers: [ctx.campaignCreator.keys.publicKey] });
+
+        // Assert that the claim receipt has been closed
+        await assertAccountNotExists(ctx, claimReceipt, "Claim Receipt");
+
+        // Assert that the claimer has been refunded the rent of the claim receipt
+        const claimerLamportsAfter = await ctx.getLamportsOf(ctx.campaignCreator.keys.publicKey);
+        assertEqBn(claimerLamportsAfter, claimerLamportsBefore.add(claimReceiptLamports));
+      });
+    });
+
+    describe("given a legacy claim receipt", () => {
+      const legacyIndex = 1;
+
+      beforeEach(async () => {
+        await ctx.simulateLegacyClaimReceipt({ index: legacyIndex });
+      });
+
+      describe("when the refund recipient is not the treasury", () => {
+        it("should fail", async () => {
+          await expectToThrow(
+            ctx.closeClaimReceipts({
+              claimers: [ctx.recipient.keys.publicKey],
+              indexes: [legacyIndex],
+            }),
+            "InvalidClaimer",
+          );
+        });
+      });
+
+      describe("when the refund recipient is the treasury", () => {
+        it("should close the legacy claim receipt", async () => {
+          const claimReceipt = ctx.claimReceiptAddress(ctx.defaultCampaign, legacyIndex);
+          await assertAccountExists(ctx, claimReceipt, "Claim Receipt");
+
+          const treasuryLamportsBefore = await ctx.getTreasuryLamports();
+          const claimReceiptLamports = await ctx.getLamportsOf(claimReceipt);
+
+          await ctx.closeClaimReceipts({ claimers: [ctx.treasuryAddress], indexes: [legacyIndex] });
+
+          // Assert that the legacy claim receipt has been closed
+          await assertAccountNotExists(ctx, claimReceipt, "Claim Receipt");
+
+          // Assert that the treasury has been refunded the rent of the legacy claim receipt
+          const treasuryLamportsAfter = await ctx.getTreasuryLamports();
+          assertEqBn(treasuryLamportsAfter, treasuryLamportsBefore.add(claimReceiptLamports));
+        });
+      });
+    });
+  });
+});
@@ -0,0 +1,74 @@
+import { NATIVE_MINT } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { beforeEach, describe, it } from "vitest";
+import { ProgramId } from "../../../lib/constants";
+import { createATA } from "../../common/anchor-bankrun";
+import { assertAccountExists, assertAccountNotExists, assertLteBn } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+import { Amount, Campaign } from "../utils/defaults";
+
+let ctx: MerkleInstantTestContext;
+let campaign: PublicKey;
+
+describe("closeNativeCampaign", () => {
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+    campaign = await ctx.createNativeCampaign();
+  });
+
+  describe("when the campaign does not airdrop native SOL", () => {
+    it("should fail", async () => {
+      await ctx.timeTravelTo(Campaign.EXPIRATION_TIME);
+      await expectToThrow(ctx.closeNativeCampaign(), "CampaignNotNative");
+    });
+  });
+
+  describe("when the campaign airdrops native SOL", () => {
+    describe("when the campaign is closed via the SPL instruction", () => {
+      it("should fail", async () => {
+        // Anyone can create the campaign's ATA for wrapped SOL
+        const payer = ctx.defaultBankrunPayer;
+        await createATA(ctx.banksClient, payer, NATIVE_MINT, campaign, ProgramId.TOKEN);
+        await ctx.timeTravelTo(Campaign.EXPIRATION_TIME);
+
+        await expectToThrow(
+          ctx.closeCampaign({ airdropTokenMint: NATIVE_MINT, campaign }),
+          "CampaignIsNative",
+        );
+      });
+    });
+
+    describe("given campaign not expired", () => {
+      it("should fail", async () => {
+        await expectToThrow(ctx.closeNativeCampaign({ campaign }), "CampaignNotExpired");
+      });
+    });
+
+    describe("given campaign expired", () => {
+      it("should close the campaign and transfer its balance to the campaign creator", async () => {
+        await ctx.timeTravelTo(Campaign.EXPIRATION_TIME);
+        const creatorLamportsBefore = await ctx.getLamportsOf(ctx.campaignCreator.keys.publicKey);
+
+        await ctx.closeNativeCampaign({ campaign });
+
+        // Assert that the campaign has been closed, and that the campaign address has been retired
+        await assertAccountNotExists(ctx, campaign, "Campaign");
+        const campaignTombstone = ctx.campaignTombstoneAddress(campaign);
+        await assertAccountExists(ctx, campaignTombstone, "Campaign Tombstone");
+
+        // Assert that the creator received the unclaimed SOL. We use `<=` because the creator also pays the transaction
+        // fee and the rent of the tombstone, which are lower than the buffer and the rent of the campaign.
+        const creatorLamportsAfter = await ctx.getLamportsOf(ctx.campaignCreator.keys.publicKey);
+        assertLteBn(creatorLamportsBefore.add(Amount.AGGREGATE), creatorLamportsAfter);
+      });
+
+      it("should retire the campaign address", async () => {
+        await ctx.timeTravelTo(Campaign.EXPIRATION_TIME);
+        await ctx.closeNativeCampaign({ campaign });
+        await expectToThrow(ctx.createNativeCampaign(), "CampaignAddressRetired");
+      });
+    });
+  });
+});
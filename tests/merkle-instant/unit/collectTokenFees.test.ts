@@ -0,0 +1,72 @@
+import {
+  ANCHOR_ERROR__ACCOUNT_NOT_INITIALIZED as ACCOUNT_NOT_INITIALIZED,
+  ANCHOR_ERROR__CONSTRAINT_ADDRESS as CONSTRAINT_ADDRESS,
+} from "@coral-xyz/anchor-errors";
+import { beforeAll, beforeEach, describe, it } from "vitest";
+import { ZERO } from "../../../lib/constants";
+import { getATABalance, getATABalanceMint } from "../../common/anchor-bankrun";
+import { assertEqBn } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+
+let ctx: MerkleInstantTestContext;
+
+describe("collectTokenFees", () => {
+  describe("when the program is not initialized", () => {
+    beforeAll(async () => {
+      ctx = new MerkleInstantTestContext();
+      await ctx.setUpMerkleInstant({
+        initProgram: false,
+      });
+    });
+
+    it("should fail", async () => {
+      await expectToThrow(ctx.collectTokenFees(), ACCOUNT_NOT_INITIALIZED);
+    });
+  });
+
+  describe("when the program is initialized", () => {
+    beforeEach(async () => {
+      ctx = new MerkleInstantTestContext();
+      await ctx.setUpMerkleInstant();
+    });
+
+    describe("when signer is not the authorized fee collector", () => {
+      it("should fail", async () => {
+        await ctx.simulateTokenFeeGeneration();
+
+        await expectToThrow(ctx.collectTokenFees({ signer: ctx.eve.keys }), CONSTRAINT_ADDRESS);
+      });
+    });
+
+    describe("when signer is the authorized fee collector", () => {
+      describe("given no token fees accumulated", () => {
+        it("should fail", async () => {
+          await ctx.simulateTokenFeeGeneration(ZERO);
+
+          await expectToThrow(ctx.collectTokenFees(), "CantCollectZeroFees");
+        });
+      });
+
+      describe("given accumulated token fees", () => {
+        it("should collect the token fees", async () => {
+          const fees = await ctx.simulateTokenFeeGeneration();
+          const feeRecipientBalanceBefore = await getFeeRecipientBalance();
+
+          // Collect the token fees
+          await ctx.collectTokenFees();
+
+          // Assert that the treasury's ATA has been emptied.
+          assertEqBn(await getATABalance(ctx.banksClient, ctx.treasuryAtaAddress()), ZERO);
+
+          // Assert that the recipient balance has been updated correctly.
+          assertEqBn(await getFeeRecipientBalance(), feeRecipientBalanceBefore.add(fees));
+        });
+      });
+    });
+  });
+});
+
+async function getFeeRecipientBalance() {
+  return await getATABalanceMint(ctx.banksClient, ctx.recipient.keys.publicKey, ctx.usdc);
+}
@@ -1,6 +1,8 @@
 import { beforeAll, beforeEach, describe, it } from "vitest";
+import { BN_1 } from "../../../lib/constants";
 import { MerkleInstantTestContext } from "../context";
 import { assertEqCampaignData, expectToThrow } from "../utils/assertions";
+import { Campaign, ClaimMode, LeafFormat, TokenClaimFee } from "../utils/defaults";
 
 let ctx: MerkleInstantTestContext;
 
@@ -33,8 +35,76 @@ describe("createCampaign", () => {
     });
 
     describe("when the campaign does not exist", () => {
-      it("should create the campaign", async () => {
-        await testCreateCampaign(ctx);
+      describe("when the grace period exceeds the maximum", () => {
+        it("should fail", async () => {
+          await expectToThrow(
+            ctx.createCampaign({
+              gracePeriod: Campaign.MAX_GRACE_PERIOD.add(BN_1),
+              name: "Test Campaign",
+            }),
+            "GracePeriodTooLong",
+          );
+        });
+      });
+
+      describe("when the grace period does not exceed the maximum", () => {
+        describe("when the jito leaf format is used without cumulative claims", () => {
+          it("should fail", async () => {
+            await expectToThrow(
+              ctx.createCampaign({ leafFormat: LeafFormat.JITO, name: "Test Campaign" }),
+              "LeafFormatNotSupported",
+            );
+          });
+        });
+
+        describe("when the leaf format is supported by the claim mode", () => {
+          describe("when the token claim fee exceeds the maximum", () => {
+            it("should fail", async () => {
+              await expectToThrow(
+                ctx.createCampaign({
+                  name: "Test Campaign",
+                  tokenClaimFee: { basisPoints: { 0: TokenClaimFee.MAX_BPS + 1 } },
+                }),
+                "TokenClaimFeeTooHigh",
+              );
+            });
+          });
+
+          describe("when the token claim fee is used with cumulative claims", () => {
+            it("should fail", async () => {
+              await expectToThrow(
+                ctx.createCampaign({
+                  claimMode: ClaimMode.CUMULATIVE,
+                  name: "Test Campaign",
+                  tokenClaimFee: TokenClaimFee.BASIS_POINTS,
+                }),
+                "TokenClaimFeeNotSupported",
+              );
+            });
+          });
+
+          describe("when the token claim fee is valid", () => {
+            it("should create the campaign", async () => {
+              const name = "Test Campaign";
+              const campaign = await ctx.createCampaign({
+                name,
+                tokenClaimFee: TokenClaimFee.BASIS_POINTS,
+              });
+              const expectedCampaignData = {
+                ...ctx.defaultCampaignData(),
+                name,
+                tokenClaimFee: TokenClaimFee.BASIS_POINTS,
+              };
+              assertEqCampaignData(await ctx.fetchCampaignData(campaign), expectedCampaignData);
+            });
+          });
+
+          describe("when the token claim fee is not set", () => {
+            it("should create the campaign", async () => {
+              await testCreateCampaign(ctx);
+            });
+          });
+        });
       });
     });
   });
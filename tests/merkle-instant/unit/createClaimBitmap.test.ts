@@ -0,0 +1,71 @@
+import type { Keypair, PublicKey } from "@solana/web3.js";
+import { assert, beforeEach, describe, it } from "vitest";
+import { assertAccountExists } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+import { ClaimMode } from "../utils/defaults";
+
+let ctx: MerkleInstantTestContext;
+
+describe("createClaimBitmap", () => {
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+  });
+
+  describe("when the campaign tracks claims with receipts", () => {
+    it("should fail", async () => {
+      await expectToThrow(ctx.createClaimBitmap(), "ClaimBitmapNotSupported");
+    });
+  });
+
+  describe("when the campaign tracks claims in bitmaps", () => {
+    let campaign: PublicKey;
+
+    beforeEach(async () => {
+      campaign = await ctx.createCampaign({
+        claimMode: ClaimMode.BITMAP,
+        name: "Bitmap Campaign",
+      });
+    });
+
+    describe("when the claim bitmap already exists", () => {
+      it("should fail", async () => {
+        await ctx.createClaimBitmap({ campaign });
+
+        await expectToThrow(ctx.createClaimBitmap({ campaign }), 0x0);
+      });
+    });
+
+    describe("when the claim bitmap doesn't exist", () => {
+      describe("when the signer is the campaign creator", () => {
+        it("should create the claim bitmap", async () => {
+          await testCreateClaimBitmap(campaign);
+        });
+      });
+
+      describe("when the signer is not the campaign creator", () => {
+        it("should create the claim bitmap", async () => {
+          await testCreateClaimBitmap(campaign, ctx.recipient.keys);
+
+          // Assert that the recipient can claim once the claim bitmap is allocated
+          await ctx.createClaimBitmap({ campaign, signer: ctx.recipient.keys });
+          await ctx.claim({ campaign, claimMode: ClaimMode.BITMAP });
+          assert.isTrue(await ctx.hasClaimed({ campaign, claimMode: ClaimMode.BITMAP }));
+        });
+      });
+    });
+  });
+});
+
+async function testCreateClaimBitmap(campaign: PublicKey, signer?: Keypair) {
+  await ctx.createClaimBitmap({ campaign, chunkIndex: 1, signer });
+
+  const claimBitmap = ctx.claimBitmapAddress(campaign, 1);
+  await assertAccountExists(ctx, claimBitmap, "Claim Bitmap");
+
+  const claimBitmapData = await ctx.merkleInstant.account.claimBitmap.fetch(claimBitmap);
+  assert.isTrue(claimBitmapData.campaign.equals(campaign));
+  assert.isTrue(claimBitmapData.bits.every((byte) => byte === 0));
+  assert.isFalse(await ctx.hasClaimed({ campaign, claimMode: ClaimMode.BITMAP }));
+}
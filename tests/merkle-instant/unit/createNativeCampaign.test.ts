@@ -0,0 +1,37 @@
+import { NATIVE_MINT } from "@solana/spl-token";
+import { beforeEach, describe, it } from "vitest";
+import { MerkleInstantTestContext } from "../context";
+import { assertEqCampaignData, expectToThrow } from "../utils/assertions";
+import { ClaimMode } from "../utils/defaults";
+
+let ctx: MerkleInstantTestContext;
+
+describe("createNativeCampaign", () => {
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+  });
+
+  describe("given cumulative claim mode", () => {
+    it("should fail", async () => {
+      await expectToThrow(
+        ctx.createNativeCampaign({ claimMode: ClaimMode.CUMULATIVE }),
+        "NativeClaimModeNotSupported",
+      );
+    });
+  });
+
+  describe("given receipt claim mode", () => {
+    it("should create the campaign", async () => {
+      const campaign = await ctx.createNativeCampaign();
+
+      const actualCampaignData = await ctx.fetchCampaignData(campaign);
+      const expectedCampaignData = {
+        ...ctx.defaultCampaignData(),
+        airdropTokenMint: NATIVE_MINT,
+        isNative: true,
+      };
+      assertEqCampaignData(actualCampaignData, expectedCampaignData);
+    });
+  });
+});
@@ -0,0 +1,66 @@
+import { ANCHOR_ERROR__CONSTRAINT_ADDRESS as CONSTRAINT_ADDRESS } from "@coral-xyz/anchor-errors";
+import { beforeEach, describe, it } from "vitest";
+import { BN_1, ZERO } from "../../../lib/constants";
+import { assertEqBn } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+import { Campaign, Time } from "../utils/defaults";
+
+let ctx: MerkleInstantTestContext;
+
+describe("extendExpiration", () => {
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+  });
+
+  describe("when the signer is not the campaign creator", () => {
+    it("should fail", async () => {
+      await expectToThrow(ctx.extendExpiration({ signer: ctx.eve.keys }), CONSTRAINT_ADDRESS);
+    });
+  });
+
+  describe("when the signer is the campaign creator", () => {
+    describe("given campaign expired", () => {
+      it("should fail", async () => {
+        await ctx.timeTravelTo(Campaign.EXPIRATION_TIME);
+        await expectToThrow(ctx.extendExpiration(), "CampaignExpired");
+      });
+    });
+
+    describe("given campaign not expired", () => {
+      describe("when the new expiration time does not respect the minimum notice", () => {
+        it("should fail", async () => {
+          const newExpirationTime = Time.GENESIS.add(Campaign.MIN_EXPIRATION_NOTICE).sub(BN_1);
+          await expectToThrow(
+            ctx.extendExpiration({ newExpirationTime }),
+            "ExpirationTimeTooSoon",
+          );
+        });
+      });
+
+      describe("when the new expiration time is zero", () => {
+        it("should remove the expiration", async () => {
+          await ctx.extendExpiration({ newExpirationTime: ZERO });
+
+          const campaignData = await ctx.fetchCampaignData();
+          assertEqBn(campaignData.expirationTime, ZERO);
+        });
+      });
+
+      describe("when the new expiration time respects the minimum notice", () => {
+        it("should update the expiration time", async () => {
+          const newExpirationTime = Campaign.EXPIRATION_TIME.add(Campaign.MIN_EXPIRATION_NOTICE);
+          await ctx.extendExpiration({ newExpirationTime });
+
+          const campaignData = await ctx.fetchCampaignData();
+          assertEqBn(campaignData.expirationTime, newExpirationTime);
+
+          // Assert that the campaign can still be claimed after the old expiration time
+          await ctx.timeTravelTo(Campaign.EXPIRATION_TIME);
+          await ctx.claim();
+        });
+      });
+    });
+  });
+});
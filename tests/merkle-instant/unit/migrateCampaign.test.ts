@@ -0,0 +1,78 @@
+import {
+  ANCHOR_ERROR__ACCOUNT_DID_NOT_DESERIALIZE as ACCOUNT_DID_NOT_DESERIALIZE,
+  ANCHOR_ERROR__ACCOUNT_DISCRIMINATOR_MISMATCH as ACCOUNT_DISCRIMINATOR_MISMATCH,
+} from "@coral-xyz/anchor-errors";
+import type { PublicKey } from "@solana/web3.js";
+import { assert, beforeEach, describe, it } from "vitest";
+import { getATABalanceMint } from "../../common/anchor-bankrun";
+import { assertEqBn } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+import { Amount, Campaign, ClaimMode } from "../utils/defaults";
+import { getRoot } from "../utils/merkle";
+
+let ctx: MerkleInstantTestContext;
+
+describe("migrateCampaign", () => {
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+  });
+
+  describe("when the account is not a campaign", () => {
+    it("should fail", async () => {
+      await expectToThrow(
+        ctx.migrateCampaign({ campaign: ctx.treasuryAddress }),
+        ACCOUNT_DISCRIMINATOR_MISMATCH,
+      );
+    });
+  });
+
+  describe("when the campaign has already been migrated", () => {
+    it("should fail", async () => {
+      await expectToThrow(ctx.migrateCampaign(), "CampaignAlreadyMigrated");
+    });
+  });
+
+  describe("when the campaign has not been migrated", () => {
+    let campaign: PublicKey;
+
+    beforeEach(async () => {
+      campaign = await ctx.simulateLegacyCampaign();
+    });
+
+    it("should block claims", async () => {
+      await expectToThrow(ctx.claim({ campaign }), ACCOUNT_DID_NOT_DESERIALIZE);
+    });
+
+    it("should migrate the campaign", async () => {
+      await ctx.migrateCampaign({ campaign, signer: ctx.eve.keys });
+
+      // The existing fields are preserved, and the seeds of the campaign address are kept
+      const campaignData = await ctx.fetchCampaignData(campaign);
+      assert.deepEqual(campaignData.merkleRoot, getRoot(ctx.leaves));
+      assertEqBn(campaignData.expirationTime, Campaign.EXPIRATION_TIME);
+      const legacySeeds = campaignData.legacySeeds;
+      assert.isNotNull(legacySeeds);
+      assert.deepEqual(legacySeeds.merkleRoot, getRoot(ctx.leaves));
+      assertEqBn(legacySeeds.expirationTime, Campaign.EXPIRATION_TIME);
+      assert.deepEqual(campaignData.claimMode, ClaimMode.RECEIPT);
+      assertEqBn(campaignData.gracePeriod, Campaign.GRACE_PERIOD);
+    });
+
+    it("should sign the claims with the legacy seeds", async () => {
+      await ctx.migrateCampaign({ campaign });
+
+      // Update the expiration time, which is part of the legacy seeds
+      await ctx.extendExpiration({ campaign });
+
+      const recipient = ctx.recipient.keys.publicKey;
+      const balanceBefore = await getATABalanceMint(ctx.banksClient, recipient, ctx.usdc);
+      await ctx.claim({ campaign });
+      const balanceAfter = await getATABalanceMint(ctx.banksClient, recipient, ctx.usdc);
+
+      assert.isTrue(await ctx.hasClaimed({ campaign }));
+      assertEqBn(balanceAfter, balanceBefore.add(Amount.CLAIM));
+    });
+  });
+});
@@ -0,0 +1,64 @@
+import {
+  ANCHOR_ERROR__ACCOUNT_DID_NOT_DESERIALIZE as ACCOUNT_DID_NOT_DESERIALIZE,
+  ANCHOR_ERROR__ACCOUNT_DISCRIMINATOR_MISMATCH as ACCOUNT_DISCRIMINATOR_MISMATCH,
+} from "@coral-xyz/anchor-errors";
+import { beforeAll, beforeEach, describe, it } from "vitest";
+import { assertEqTreasury } from "../../common/assertions";
+import type { Treasury } from "../../common/types";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+
+let ctx: MerkleInstantTestContext;
+
+describe("migrateTreasury", () => {
+  describe("when the program is not initialized", () => {
+    beforeAll(async () => {
+      ctx = new MerkleInstantTestContext();
+      await ctx.setUpMerkleInstant({
+        initProgram: false,
+      });
+    });
+
+    it("should fail", async () => {
+      await expectToThrow(ctx.migrateTreasury(), ACCOUNT_DISCRIMINATOR_MISMATCH);
+    });
+  });
+
+  describe("when the program is initialized", () => {
+    beforeEach(async () => {
+      ctx = new MerkleInstantTestContext();
+      await ctx.setUpMerkleInstant();
+    });
+
+    describe("when the treasury has already been migrated", () => {
+      it("should fail", async () => {
+        await expectToThrow(ctx.migrateTreasury(), "TreasuryAlreadyMigrated");
+      });
+    });
+
+    describe("when the treasury has not been migrated", () => {
+      let treasury: Treasury;
+
+      beforeEach(async () => {
+        treasury = await ctx.treasuryView();
+        await ctx.simulateLegacyTreasury();
+      });
+
+      it("should block campaign creation and claims", async () => {
+        await expectToThrow(
+          ctx.createCampaign({ name: "Legacy Campaign" }),
+          ACCOUNT_DID_NOT_DESERIALIZE,
+        );
+        await expectToThrow(ctx.claim(), ACCOUNT_DID_NOT_DESERIALIZE);
+      });
+
+      it("should migrate the treasury", async () => {
+        await ctx.migrateTreasury({ signer: ctx.eve.keys });
+
+        // The existing fields are preserved, and the new ones are set to their defaults
+        assertEqTreasury(await ctx.treasuryView(), treasury);
+        await ctx.claim();
+      });
+    });
+  });
+});
@@ -0,0 +1,71 @@
+import {
+  ANCHOR_ERROR__ACCOUNT_NOT_INITIALIZED as ACCOUNT_NOT_INITIALIZED,
+  ANCHOR_ERROR__CONSTRAINT_ADDRESS as CONSTRAINT_ADDRESS,
+} from "@coral-xyz/anchor-errors";
+import { beforeAll, beforeEach, describe, it } from "vitest";
+import { assertEqTreasury } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+
+let ctx: MerkleInstantTestContext;
+
+describe("pause", () => {
+  describe("when the program is not initialized", () => {
+    beforeAll(async () => {
+      ctx = new MerkleInstantTestContext();
+      await ctx.setUpMerkleInstant({
+        initProgram: false,
+      });
+    });
+
+    it("should fail", async () => {
+      await expectToThrow(ctx.pause(), ACCOUNT_NOT_INITIALIZED);
+    });
+  });
+
+  describe("when the program is initialized", () => {
+    beforeEach(async () => {
+      ctx = new MerkleInstantTestContext();
+      await ctx.setUpMerkleInstant();
+    });
+
+    describe("when signer is not the authorized fee collector", () => {
+      it("should fail", async () => {
+        await expectToThrow(ctx.pause({ signer: ctx.eve.keys }), CONSTRAINT_ADDRESS);
+      });
+    });
+
+    describe("when signer is the authorized fee collector", () => {
+      describe("when the program is already paused", () => {
+        it("should fail", async () => {
+          await ctx.pause();
+          await expectToThrow(ctx.pause(), "ProgramAlreadyPaused");
+        });
+      });
+
+      describe("when the program is not paused", () => {
+        it("should pause the program", async () => {
+          await ctx.pause();
+
+          const treasury = await ctx.treasuryView();
+          assertEqTreasury(treasury, { ...treasury, isPaused: true });
+        });
+
+        it("should block campaign creation", async () => {
+          await ctx.pause();
+          await expectToThrow(ctx.createCampaign({ name: "Paused Campaign" }), "ProgramIsPaused");
+        });
+
+        it("should block claims", async () => {
+          await ctx.pause();
+          await expectToThrow(ctx.claim(), "ProgramIsPaused");
+        });
+
+        it("should not block clawbacks", async () => {
+          await ctx.pause();
+          await ctx.clawback();
+        });
+      });
+    });
+  });
+});
@@ -0,0 +1,110 @@
+import {
+  ANCHOR_ERROR__ACCOUNT_NOT_INITIALIZED as ACCOUNT_NOT_INITIALIZED,
+  ANCHOR_ERROR__CONSTRAINT_ADDRESS as CONSTRAINT_ADDRESS,
+} from "@coral-xyz/anchor-errors";
+import { beforeAll, beforeEach, describe, it } from "vitest";
+import { assertEqTreasury } from "../../common/assertions";
+import type { FeeShare } from "../../common/types";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+
+let ctx: MerkleInstantTestContext;
+
+describe("setFeeSplit", () => {
+  describe("when the program is not initialized", () => {
+    beforeAll(async () => {
+      ctx = new MerkleInstantTestContext();
+      await ctx.setUpMerkleInstant({
+        initProgram: false,
+      });
+    });
+
+    it("should fail", async () => {
+      await expectToThrow(ctx.setFeeSplit(), ACCOUNT_NOT_INITIALIZED);
+    });
+  });
+
+  describe("when the program is initialized", () => {
+    beforeEach(async () => {
+      ctx = new MerkleInstantTestContext();
+      await ctx.setUpMerkleInstant();
+    });
+
+    describe("when signer is not the authorized fee collector", () => {
+      it("should fail", async () => {
+        await expectToThrow(
+          ctx.setFeeSplit({ feeSplit: defaultFeeSplit(), signer: ctx.eve.keys }),
+          CONSTRAINT_ADDRESS,
+        );
+      });
+    });
+
+    describe("when signer is the authorized fee collector", () => {
+      describe("when the fee split has too many recipients", () => {
+        it("should fail", async () => {
+          const feeSplit = Array.from({ length: 11 }, () => ({
+            bps: 1_000,
+            recipient: ctx.recipient.keys.publicKey,
+          }));
+
+          await expectToThrow(ctx.setFeeSplit({ feeSplit }), "FeeSplitTooManyRecipients");
+        });
+      });
+
+      describe("when the shares do not sum to 10,000 basis points", () => {
+        it("should fail", async () => {
+          const feeSplit = [{ bps: 9_999, recipient: ctx.recipient.keys.publicKey }];
+
+          await expectToThrow(ctx.setFeeSplit({ feeSplit }), "InvalidFeeSplitShares");
+        });
+      });
+
+      describe("when a share is zero", () => {
+        it("should fail", async () => {
+          const feeSplit = [...defaultFeeSplit(), { bps: 0, recipient: ctx.eve.keys.publicKey }];
+
+          await expectToThrow(ctx.setFeeSplit({ feeSplit }), "InvalidFeeSplitShares");
+        });
+      });
+
+      describe("when a recipient is duplicated", () => {
+        it("should fail", async () => {
+          const recipient = ctx.recipient.keys.publicKey;
+          const feeSplit = [
+            { bps: 5_000, recipient },
+            { bps: 5_000, recipient },
+          ];
+
+          await expectToThrow(ctx.setFeeSplit({ feeSplit }), "FeeSplitDuplicateRecipient");
+        });
+      });
+
+      describe("when the fee split is valid", () => {
+        it("should set the fee split", async () => {
+          const feeSplit = defaultFeeSplit();
+          await ctx.setFeeSplit({ feeSplit });
+
+          const treasury = await ctx.treasuryView();
+          assertEqTreasury(treasury, { ...treasury, feeSplit });
+        });
+      });
+
+      describe("when the fee split is empty", () => {
+        it("should clear the fee split", async () => {
+          await ctx.setFeeSplit({ feeSplit: defaultFeeSplit() });
+          await ctx.setFeeSplit({ feeSplit: [] });
+
+          const treasury = await ctx.treasuryView();
+          assertEqTreasury(treasury, { ...treasury, feeSplit: [] });
+        });
+      });
+    });
+  });
+});
+
+function defaultFeeSplit(): FeeShare[] {
+  return [
+    { bps: 3_000, recipient: ctx.recipient.keys.publicKey },
+    { bps: 7_000, recipient: ctx.eve.keys.publicKey },
+  ];
+}
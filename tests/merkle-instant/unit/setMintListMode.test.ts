@@ -0,0 +1,94 @@
+import {
+  ANCHOR_ERROR__ACCOUNT_NOT_INITIALIZED as ACCOUNT_NOT_INITIALIZED,
+  ANCHOR_ERROR__CONSTRAINT_ADDRESS as CONSTRAINT_ADDRESS,
+} from "@coral-xyz/anchor-errors";
+import { beforeAll, beforeEach, describe, it } from "vitest";
+import { assertEqTreasury } from "../../common/assertions";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+import { MintListMode } from "../utils/defaults";
+
+let ctx: MerkleInstantTestContext;
+
+describe("setMintListMode", () => {
+  describe("when the program is not initialized", () => {
+    beforeAll(async () => {
+      ctx = new MerkleInstantTestContext();
+      await ctx.setUpMerkleInstant({
+        initProgram: false,
+      });
+    });
+
+    it("should fail", async () => {
+      await expectToThrow(ctx.setMintListMode(), ACCOUNT_NOT_INITIALIZED);
+    });
+  });
+
+  describe("when the program is initialized", () => {
+    beforeEach(async () => {
+      ctx = new MerkleInstantTestContext();
+      await ctx.setUpMerkleInstant();
+    });
+
+    describe("when signer is not the authorized fee collector", () => {
+      it("should fail", async () => {
+        await expectToThrow(ctx.setMintListMode({ signer: ctx.eve.keys }), CONSTRAINT_ADDRESS);
+      });
+    });
+
+    describe("when signer is the authorized fee collector", () => {
+      it("should set the mint list mode", async () => {
+        await ctx.setMintListMode({ mintListMode: MintListMode.DENYLIST });
+
+        const treasury = await ctx.treasuryView();
+        assertEqTreasury(treasury, { ...treasury, mintListMode: MintListMode.DENYLIST });
+      });
+
+      describe("when the mode is allowlist", () => {
+        beforeEach(async () => {
+          await ctx.setMintListMode({ mintListMode: MintListMode.ALLOWLIST });
+        });
+
+        describe("when the airdrop token is not in the mint list", () => {
+          it("should fail the campaign creation", async () => {
+            await expectToThrow(ctx.createCampaign({ name: "Unlisted Mint" }), "MintNotAllowed");
+          });
+        });
+
+        describe("when the airdrop token is in the mint list", () => {
+          it("should create the campaign", async () => {
+            await ctx.addToMintList();
+            await ctx.createCampaign({ name: "Listed Mint" });
+          });
+        });
+      });
+
+      describe("when the mode is denylist", () => {
+        beforeEach(async () => {
+          await ctx.setMintListMode({ mintListMode: MintListMode.DENYLIST });
+        });
+
+        describe("when the airdrop token is in the mint list", () => {
+          it("should fail the campaign creation", async () => {
+            await ctx.addToMintList();
+            await expectToThrow(ctx.createCampaign({ name: "Listed Mint" }), "MintNotAllowed");
+          });
+        });
+
+        describe("when the airdrop token is not in the mint list", () => {
+          it("should create the campaign", async () => {
+            await ctx.createCampaign({ name: "Unlisted Mint" });
+          });
+        });
+      });
+
+      describe("when the mode is open", () => {
+        it("should create the campaign even if the airdrop token is in the mint list", async () => {
+          await ctx.addToMintList();
+          await ctx.setMintListMode({ mintListMode: MintListMode.OPEN });
+          await ctx.createCampaign({ name: "Listed Mint" });
+        });
+      });
+    });
+  });
+});
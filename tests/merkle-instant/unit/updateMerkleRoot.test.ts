@@ -0,0 +1,100 @@
+import { ANCHOR_ERROR__CONSTRAINT_ADDRESS as CONSTRAINT_ADDRESS } from "@coral-xyz/anchor-errors";
+import { assert, beforeEach, describe, it } from "vitest";
+import { BN_1 } from "../../../lib/constants";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+import { Campaign, ClaimMode } from "../utils/defaults";
+
+const NEW_MERKLE_ROOT = Array.from({ length: 32 }, (_, i) => i);
+const NEW_IPFS_CID = "bafkreihq2ztbxjfggm3lbo2lhcgsy6syc5f3dj7ygx4uc3lq5n6n2yzhhu";
+
+let ctx: MerkleInstantTestContext;
+
+describe("updateMerkleRoot", () => {
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+  });
+
+  describe("when the signer is not the campaign creator", () => {
+    it("should fail", async () => {
+      await expectToThrow(ctx.updateMerkleRoot({ signer: ctx.eve.keys }), CONSTRAINT_ADDRESS);
+    });
+  });
+
+  describe("when the signer is the campaign creator", () => {
+    describe("when the IPFS CID is too long", () => {
+      it("should fail", async () => {
+        await expectToThrow(
+          ctx.updateMerkleRoot({ ipfsCid: `${NEW_IPFS_CID}0`, merkleRoot: NEW_MERKLE_ROOT }),
+          "IpfsCidTooLong",
+        );
+      });
+    });
+
+    describe("given campaign expired", () => {
+      it("should fail", async () => {
+        const campaign = await ctx.createCampaign({
+          claimMode: ClaimMode.CUMULATIVE,
+          name: "Cumulative Campaign",
+        });
+        await ctx.timeTravelTo(Campaign.EXPIRATION_TIME);
+
+        await expectToThrow(
+          ctx.updateMerkleRoot({ campaign, merkleRoot: NEW_MERKLE_ROOT }),
+          "CampaignExpired",
+        );
+      });
+    });
+
+    describe("given campaign not started", () => {
+      it("should update the Merkle root", async () => {
+        await ctx.timeTravelTo(Campaign.START_TIME.sub(BN_1));
+        await testUpdateMerkleRoot();
+      });
+    });
+
+    describe("given campaign started", () => {
+      describe("when a claim has been made", () => {
+        describe("given cumulative claim mode", () => {
+          it("should update the Merkle root", async () => {
+            const campaign = await ctx.createCampaign({
+              claimMode: ClaimMode.CUMULATIVE,
+              name: "Cumulative Campaign",
+            });
+            await ctx.claimCumulative({ campaign });
+
+            await testUpdateMerkleRoot(campaign);
+          });
+        });
+
+        describe("given receipt claim mode", () => {
+          it("should fail", async () => {
+            await ctx.claim();
+            await expectToThrow(
+              ctx.updateMerkleRoot({ merkleRoot: NEW_MERKLE_ROOT }),
+              "MerkleRootUpdateNotAllowed",
+            );
+          });
+        });
+      });
+
+      describe("when no claim has been made", () => {
+        it("should update the Merkle root", async () => {
+          await testUpdateMerkleRoot();
+
+          // Assert that the proofs of the old Merkle tree are no longer valid
+          await expectToThrow(ctx.claim(), "InvalidMerkleProof");
+        });
+      });
+    });
+  });
+});
+
+async function testUpdateMerkleRoot(campaign = ctx.defaultCampaign) {
+  await ctx.updateMerkleRoot({ campaign, ipfsCid: NEW_IPFS_CID, merkleRoot: NEW_MERKLE_ROOT });
+
+  const campaignData = await ctx.fetchCampaignData(campaign);
+  assert.deepEqual(campaignData.merkleRoot, NEW_MERKLE_ROOT);
+  assert.equal(campaignData.ipfsCid, NEW_IPFS_CID);
+}
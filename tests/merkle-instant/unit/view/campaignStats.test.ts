@@ -0,0 +1,95 @@
+import type { BN } from "@coral-xyz/anchor";
+import { NATIVE_MINT } from "@solana/spl-token";
+import { assert, beforeEach, describe, expect, it } from "vitest";
+import { ProgramId, ZERO } from "../../../../lib/constants";
+import { assertEqBn } from "../../../common/assertions";
+import { MerkleInstantTestContext } from "../../context";
+import { Amount, Time } from "../../utils/defaults";
+
+describe("campaignStats", () => {
+  let ctx: MerkleInstantTestContext;
+
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+  });
+
+  describe("given no claim", () => {
+    it("should return the initial statistics", async () => {
+      const stats = await ctx.campaignStats();
+
+      assertEqBn(stats.aggregateAmount as BN, Amount.AGGREGATE);
+      assertEqBn(stats.campaignAtaBalance, Amount.AGGREGATE);
+      assert.equal(stats.claimCount, 0);
+      assertEqBn(stats.lastClaimTime, ZERO);
+      assertEqBn(stats.totalClaimedAmount, ZERO);
+      assertEqBn(stats.totalClawedBack, ZERO);
+      assertEqBn(stats.unclaimedAmount as BN, Amount.AGGREGATE);
+    });
+  });
+
+  describe("given a claim and a clawback", () => {
+    it("should return the updated statistics", async () => {
+      await ctx.claim();
+      await ctx.clawback();
+
+      const stats = await ctx.campaignStats();
+      const expectedBalance = Amount.AGGREGATE.sub(Amount.CLAIM).sub(Amount.CLAWBACK);
+
+      assertEqBn(stats.aggregateAmount as BN, Amount.AGGREGATE);
+      assertEqBn(stats.campaignAtaBalance, expectedBalance);
+      assert.equal(stats.claimCount, 1);
+      assertEqBn(stats.lastClaimTime, Time.GENESIS);
+      assertEqBn(stats.totalClaimedAmount, Amount.CLAIM);
+      assertEqBn(stats.totalClawedBack, Amount.CLAWBACK);
+      assertEqBn(stats.unclaimedAmount as BN, Amount.AGGREGATE.sub(Amount.CLAIM));
+    });
+  });
+
+  describe("given a migrated campaign", () => {
+    it("should return an unknown aggregate amount", async () => {
+      const campaign = await ctx.simulateLegacyCampaign();
+      await ctx.migrateCampaign({ campaign });
+
+      const stats = await ctx.campaignStats({ campaign });
+
+      assert.isNull(stats.aggregateAmount);
+      assertEqBn(stats.campaignAtaBalance, Amount.AGGREGATE);
+      assert.isNull(stats.unclaimedAmount);
+    });
+  });
+
+  describe("given a native campaign", () => {
+    describe("when the campaign's ATA is not provided", () => {
+      it("should return the SOL available for claims", async () => {
+        const campaign = await ctx.createNativeCampaign();
+
+        const stats = await ctx.campaignStats({
+          airdropTokenMint: NATIVE_MINT,
+          campaign,
+          campaignAta: null,
+        });
+        assertEqBn(stats.campaignAtaBalance, Amount.AGGREGATE);
+      });
+    });
+  });
+
+  describe("given an SPL campaign", () => {
+    describe("when the campaign's ATA is not provided", () => {
+      it("should fail", async () => {
+        await expect(ctx.campaignStats({ campaignAta: null })).rejects.toThrow("CampaignAtaMissing");
+      });
+    });
+  });
+
+  describe("given the token 2022 campaign", () => {
+    it("should return the campaign ATA balance", async () => {
+      const stats = await ctx.campaignStats({
+        airdropTokenMint: ctx.dai,
+        airdropTokenProgram: ProgramId.TOKEN_2022,
+        campaign: ctx.defaultCampaignToken2022,
+      });
+      assertEqBn(stats.campaignAtaBalance, Amount.AGGREGATE);
+    });
+  });
+});
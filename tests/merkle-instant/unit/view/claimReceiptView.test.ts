@@ -0,0 +1,43 @@
+import { PublicKey } from "@solana/web3.js";
+import { beforeEach, describe, it } from "vitest";
+import { ZERO } from "../../../../lib/constants";
+import { assertEqBn, assertEqPublicKey } from "../../../common/assertions";
+import { MerkleInstantTestContext } from "../../context";
+import { Amount, Time } from "../../utils/defaults";
+
+describe("claimReceiptView", () => {
+  let ctx: MerkleInstantTestContext;
+
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+  });
+
+  describe("given a claim", () => {
+    it("should return the claim details", async () => {
+      await ctx.claim({ claimerKeys: ctx.eve.keys });
+
+      const receipt = await ctx.claimReceiptView();
+
+      assertEqBn(receipt.amount, Amount.CLAIM);
+      assertEqBn(receipt.claimTime, Time.GENESIS);
+      assertEqPublicKey(receipt.claimer, ctx.eve.keys.publicKey);
+      assertEqBn(receipt.feeInLamports, await ctx.claimFeeInLamports());
+      assertEqPublicKey(receipt.recipient, ctx.recipient.keys.publicKey);
+    });
+  });
+
+  describe("given a legacy claim receipt", () => {
+    it("should return zero claim details", async () => {
+      await ctx.simulateLegacyClaimReceipt();
+
+      const receipt = await ctx.claimReceiptView();
+
+      assertEqBn(receipt.amount, ZERO);
+      assertEqBn(receipt.claimTime, ZERO);
+      assertEqPublicKey(receipt.claimer, PublicKey.default);
+      assertEqBn(receipt.feeInLamports, ZERO);
+      assertEqPublicKey(receipt.recipient, PublicKey.default);
+    });
+  });
+});
@@ -0,0 +1,48 @@
+import { assert, beforeEach, describe, it } from "vitest";
+import { MerkleInstantTestContext } from "../../context";
+import { ClaimMode } from "../../utils/defaults";
+
+describe("claimedBitmapOf", () => {
+  let ctx: MerkleInstantTestContext;
+
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+  });
+
+  describe("given receipt claim mode", () => {
+    describe("given no claim", () => {
+      it("should return an empty bitset", async () => {
+        assert.deepEqual(await ctx.claimedBitmapOf(), [0]);
+      });
+    });
+
+    describe("given a claim", () => {
+      it("should set the bit of the claimed index", async () => {
+        await ctx.claim();
+        assert.deepEqual(await ctx.claimedBitmapOf(), [0b0001]);
+      });
+    });
+
+    describe("given a range starting after the claimed index", () => {
+      it("should not set the bit of the claimed index", async () => {
+        await ctx.claim();
+        assert.deepEqual(await ctx.claimedBitmapOf({ count: 3, startIndex: 1 }), [0]);
+      });
+    });
+  });
+
+  describe("given bitmap claim mode", () => {
+    it("should set the bit of the claimed index", async () => {
+      const campaign = await ctx.createCampaign({
+        claimMode: ClaimMode.BITMAP,
+        name: "Bitmap Campaign",
+      });
+      await ctx.createClaimBitmap({ campaign });
+      await ctx.claim({ campaign, claimMode: ClaimMode.BITMAP });
+
+      const claimedBitmap = await ctx.claimedBitmapOf({ campaign, claimMode: ClaimMode.BITMAP });
+      assert.deepEqual(claimedBitmap, [0b0001]);
+    });
+  });
+});
@@ -3,7 +3,7 @@ import { beforeEach, describe, expect, it } from "vitest";
 import { ProgramId } from "../../../../lib/constants";
 import { assertEqTreasury } from "../../../common/assertions";
 import { MerkleInstantTestContext } from "../../context";
-import { Seed } from "../../utils/defaults";
+import { MintListMode, Seed } from "../../utils/defaults";
 
 describe("treasuryView", () => {
   let ctx: MerkleInstantTestContext;
@@ -35,6 +35,10 @@ describe("treasuryView", () => {
         chainlinkProgram: ProgramId.CHAINLINK_PROGRAM,
         chainlinkSolUsdFeed: ProgramId.CHAINLINK_SOL_USD_FEED,
         feeCollector: ctx.feeCollector.keys.publicKey,
+        feeSplit: [],
+        isPaused: false,
+        mintListMode: MintListMode.OPEN,
+        owedFees: [],
       };
       assertEqTreasury(actualTreasury, expectedTreasury);
     });
@@ -0,0 +1,37 @@
+import { assert, beforeEach, describe, it } from "vitest";
+import { MerkleInstantTestContext } from "../context";
+import { expectToThrow } from "../utils/assertions";
+
+const MAX_MERKLE_PROOF_LENGTH = 32;
+
+let ctx: MerkleInstantTestContext;
+
+describe("writeProofBuffer", () => {
+  beforeEach(async () => {
+    ctx = new MerkleInstantTestContext();
+    await ctx.setUpMerkleInstant();
+  });
+
+  describe("when the proof exceeds the maximum length", () => {
+    it("should fail", async () => {
+      const proofElements = Array.from({ length: MAX_MERKLE_PROOF_LENGTH + 1 }, () =>
+        ctx.defaultMerkleProof[0],
+      );
+      await expectToThrow(ctx.writeProofBuffer({ proofElements }), "ProofTooLong");
+    });
+  });
+
+  describe("when the proof does not exceed the maximum length", () => {
+    it("should append the proof elements across transactions", async () => {
+      const [first, ...rest] = ctx.defaultMerkleProof;
+
+      await ctx.writeProofBuffer({ proofElements: [first] });
+      await ctx.writeProofBuffer({ proofElements: rest });
+
+      const proofBuffer = await ctx.merkleInstant.account.proofBuffer.fetch(ctx.proofBufferAddress());
+      assert.deepEqual(proofBuffer.campaign, ctx.defaultCampaign);
+      assert.deepEqual(proofBuffer.claimer, ctx.recipient.keys.publicKey);
+      assert.deepEqual(proofBuffer.proof, ctx.defaultMerkleProof);
+    });
+  });
+});
@@ -16,13 +16,27 @@ export function expectToThrow(
 }
 
 export function assertEqCampaignData(a: CampaignData, b: CampaignData) {
+  assert.deepEqual(a.additionalAirdropTokenMints, b.additionalAirdropTokenMints);
+  assert.deepEqual(a.additionalTotalClaimedAmounts, b.additionalTotalClaimedAmounts);
+  assertEqBn(a.aggregateAmount, b.aggregateAmount);
   assertEqPublicKey(a.airdropTokenMint, b.airdropTokenMint);
   assertEqPublicKey(a.creator, b.creator);
   assertEqBn(a.campaignStartTime, b.campaignStartTime);
+  assert.equal(a.claimCount, b.claimCount);
+  assert.deepEqual(a.claimMode, b.claimMode);
+  assert.deepEqual(a.eligibilityRule, b.eligibilityRule);
   assertEqBn(a.expirationTime, b.expirationTime);
   assertEqBn(a.firstClaimTime, b.firstClaimTime);
+  assertEqBn(a.gracePeriod, b.gracePeriod);
   assert.equal(a.ipfsCid, b.ipfsCid);
+  assert.equal(a.isNative, b.isNative);
+  assertEqBn(a.lastClaimTime, b.lastClaimTime);
+  assert.deepEqual(a.leafFormat, b.leafFormat);
+  assert.deepEqual(a.legacySeeds, b.legacySeeds);
   assert.equal(a.merkleRoot.length, b.merkleRoot.length);
   assert.deepEqual(a.merkleRoot, b.merkleRoot);
   assert.equal(a.name, b.name);
+  assert.deepEqual(a.tokenClaimFee, b.tokenClaimFee);
+  assertEqBn(a.totalClaimedAmount, b.totalClaimedAmount);
+  assertEqBn(a.totalClawedBack, b.totalClawedBack);
 }
@@ -2,10 +2,15 @@ import { BN } from "@coral-xyz/anchor";
 import dayjs from "dayjs";
 import { usdc } from "../../../lib/convertors";
 
+// Must be kept in sync with `CLAIMS_PER_BITMAP` in the program constants.
+export const CLAIMS_PER_BITMAP = 65_536;
+
 export namespace Amount {
   export const AGGREGATE = usdc(10_000);
   export const CLAIM = usdc(100);
   export const CLAWBACK = usdc(1000);
+  // Must be kept in sync with the buffer deducted in `safe_collectible_amount`.
+  export const NATIVE_BUFFER = new BN(1_000_000); // 0.001 SOL
 }
 
 export namespace Time {
@@ -18,11 +23,49 @@ export namespace Campaign {
   export const START_TIME = Time.GENESIS;
   export const EXPIRATION_TIME = new BN(dayjs().add(10, "days").unix());
   export const IPFS_CID = "bafkreiecpwdhvkmw4y6iihfndk7jhwjas3m5htm7nczovt6m37mucwgsrq";
-  const GRACE_PERIOD_SECONDS = new BN(7 * 24 * 60 * 60 + 1);
-  export const POST_GRACE_PERIOD = Time.GENESIS.add(GRACE_PERIOD_SECONDS);
+  export const MIN_EXPIRATION_NOTICE = new BN(7 * 24 * 60 * 60);
+  export const GRACE_PERIOD = new BN(7 * 24 * 60 * 60);
+  export const MAX_GRACE_PERIOD = new BN(90 * 24 * 60 * 60);
+  export const POST_GRACE_PERIOD = Time.GENESIS.add(GRACE_PERIOD).addn(1);
+}
+
+export namespace ClaimMode {
+  export const BITMAP = { bitmap: {} };
+  export const CUMULATIVE = { cumulative: {} };
+  export const RECEIPT = { receipt: {} };
+}
+
+export namespace EligibilityKind {
+  export const COLLECTION = { collection: {} };
+  export const MINT = { mint: {} };
+}
+
+export namespace LeafFormat {
+  export const JITO = { jito: {} };
+  export const SABER = { saber: {} };
+  export const SABLIER = { sablier: {} };
+}
+
+export namespace TokenClaimFee {
+  // Must be kept in sync with `MAX_TOKEN_CLAIM_FEE_BPS` in the program constants.
+  export const MAX_BPS = 1_000;
+  export const BASIS_POINTS = { basisPoints: { 0: 100 } }; // 1%
+  export const fixed = (amount: BN) => ({ fixed: { 0: amount } });
+}
+
+export namespace MintListMode {
+  export const ALLOWLIST = { allowlist: {} };
+  export const DENYLIST = { denylist: {} };
+  export const OPEN = { open: {} };
 }
 
 export namespace Seed {
   export const CAMPAIGN = Buffer.from("campaign");
+  export const CAMPAIGN_TOMBSTONE = Buffer.from("campaign_tombstone");
+  export const CLAIM_BITMAP = Buffer.from("claim_bitmap");
+  export const CLAIM_RECEIPT = Buffer.from("claim_receipt");
+  export const CUMULATIVE_CLAIM = Buffer.from("cumulative_claim");
+  export const MINT_LIST_ENTRY = Buffer.from("mint_list_entry");
+  export const PROOF_BUFFER = Buffer.from("proof_buffer");
   export const TREASURY = Buffer.from("treasury");
 }
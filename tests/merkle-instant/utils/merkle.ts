@@ -1,8 +1,10 @@
+import { createHash } from "node:crypto";
 import type { BN } from "@coral-xyz/anchor";
 import type { PublicKey } from "@solana/web3.js";
 import keccak256 from "keccak256";
 import { MerkleTree } from "merkletreejs";
 import { toBigInt } from "../../../lib/helpers";
+import { LeafFormat } from "./defaults";
 
 export type LeafData = {
   index: number;
@@ -10,17 +12,75 @@ export type LeafData = {
   amount: BN;
 };
 
-export function getProof(leaves: LeafData[], targetLeaf: LeafData): number[][] {
-  const tree = buildTree(leaves);
+export type MultiTokenLeafData = {
+  index: number;
+  recipient: PublicKey;
+  amounts: BN[];
+};
+
+export function getMultiTokenProof(leaves: MultiTokenLeafData[], targetLeaf: MultiTokenLeafData): number[][] {
+  const tree = new MerkleTree(leaves.map(computeMultiTokenLeaf), keccak256, { sortPairs: true });
+  return tree.getProof(computeMultiTokenLeaf(targetLeaf)).map((p) => Array.from(p.data));
+}
+
+export function getMultiTokenRoot(leaves: MultiTokenLeafData[]): number[] {
+  const tree = new MerkleTree(leaves.map(computeMultiTokenLeaf), keccak256, { sortPairs: true });
+  return Array.from(tree.getRoot());
+}
 
-  const targetHash = computeLeaf(targetLeaf);
+export function getProof(
+  leaves: LeafData[],
+  targetLeaf: LeafData,
+  leafFormat: object = LeafFormat.SABLIER,
+): number[][] {
+  const tree = buildTree(leaves, leafFormat);
+
+  const targetHash = computeLeaf(targetLeaf, leafFormat);
   const proofBuffers = tree.getProof(targetHash).map((p) => p.data);
 
   return proofBuffers.map((buf) => Array.from(buf));
 }
 
-export function getRoot(leaves: LeafData[]): number[] {
-  const tree = buildTree(leaves);
+/**
+ * Generates an OpenZeppelin-compatible multiproof for the target leaves. The returned leaves are sorted by their
+ * position in the tree, which is the order expected by the program.
+ */
+export function getMultiProof(
+  leaves: LeafData[],
+  targetLeaves: LeafData[],
+): { leaves: LeafData[]; proof: number[][]; proofFlags: boolean[] } {
+  const layers = buildTree(leaves).getLayers();
+  const sortedLeaves = [...targetLeaves].sort((a, b) => positionOf(leaves, a) - positionOf(leaves, b));
+
+  const proof: number[][] = [];
+  const proofFlags: boolean[] = [];
+
+  // Walk up the tree, consuming the known nodes of each layer in the same order as `multiProofVerify`
+  let known = sortedLeaves.map((leaf) => positionOf(leaves, leaf));
+  for (const layer of layers.slice(0, -1)) {
+    const parents: number[] = [];
+    for (let i = 0; i < known.length; i++) {
+      const sibling = known[i] ^ 1;
+      if (sibling >= layer.length) {
+        throw new Error("Multiproofs are only supported for balanced trees");
+      }
+      if (known[i + 1] === sibling) {
+        proofFlags.push(true);
+        i++;
+      } else {
+        proofFlags.push(false);
+        proof.push(Array.from(layer[sibling]));
+      }
+      parents.push(known[i] >> 1);
+    }
+    known = parents;
+  }
+
+  return { leaves: sortedLeaves, proof, proofFlags };
+}
+
+export function getRoot(leaves: LeafData[], leafFormat: object = LeafFormat.SABLIER): number[] {
+  const tree = buildTree(leaves, leafFormat);
   return Array.from(tree.getRoot());
 }
 
@@ -28,20 +88,44 @@ export function getRoot(leaves: LeafData[]): number[] {
 /*                               INTERNAL LOGIC                               */
 /* -------------------------------------------------------------------------- */
 
-function buildTree(leaves: LeafData[]): MerkleTree {
-  const hashedLeaves = leaves.map(computeLeaf);
+function buildTree(leaves: LeafData[], leafFormat: object = LeafFormat.SABLIER): MerkleTree {
+  const hashedLeaves = leaves.map((leaf) => computeLeaf(leaf, leafFormat));
+
+  // The Jito tooling hashes the pairs with sha256, prefixed with 0x01
+  if ("jito" in leafFormat) {
+    return new MerkleTree(hashedLeaves, sha256, {
+      concatenator: (buffers) => Buffer.concat([Buffer.from([1]), ...buffers]),
+      sortPairs: true,
+    });
+  }
   return new MerkleTree(hashedLeaves, keccak256, { sortPairs: true });
 }
 
-function computeLeaf(leafData: LeafData): Buffer {
-  const indexBytes = Buffer.alloc(4);
-  indexBytes.writeUInt32LE(leafData.index);
+function positionOf(leaves: LeafData[], target: LeafData): number {
+  return leaves.findIndex((leaf) => leaf.index === target.index);
+}
 
+function computeLeaf(leafData: LeafData, leafFormat: object = LeafFormat.SABLIER): Buffer {
   const recipientBytes = leafData.recipient.toBuffer(); // 32 bytes
 
   const amountBytes = Buffer.alloc(8);
   amountBytes.writeBigUInt64LE(toBigInt(leafData.amount));
 
+  if ("saber" in leafFormat) {
+    const indexBytes = Buffer.alloc(8);
+    indexBytes.writeBigUInt64LE(BigInt(leafData.index));
+    return Buffer.from(keccak256(Buffer.concat([indexBytes, recipientBytes, amountBytes])));
+  }
+
+  if ("jito" in leafFormat) {
+    // The whole amount is unlocked, so the locked amount is zero
+    const node = sha256(Buffer.concat([recipientBytes, amountBytes, Buffer.alloc(8)]));
+    return sha256(Buffer.concat([Buffer.from([0]), node]));
+  }
+
+  const indexBytes = Buffer.alloc(4);
+  indexBytes.writeUInt32LE(leafData.index);
+
   const leafBytes = Buffer.concat([indexBytes, recipientBytes, amountBytes]);
 
   const firstHash = Buffer.from(keccak256(leafBytes));
@@ -49,3 +133,21 @@ function computeLeaf(leafData: LeafData): Buffer {
 
   return finalHash;
 }
+
+function computeMultiTokenLeaf(leafData: MultiTokenLeafData): Buffer {
+  const indexBytes = Buffer.alloc(4);
+  indexBytes.writeUInt32LE(leafData.index);
+
+  const amountsBytes = leafData.amounts.map((amount) => {
+    const amountBytes = Buffer.alloc(8);
+    amountBytes.writeBigUInt64LE(toBigInt(amount));
+    return amountBytes;
+  });
+
+  const leafBytes = Buffer.concat([indexBytes, leafData.recipient.toBuffer(), ...amountsBytes]);
+  return Buffer.from(keccak256(Buffer.from(keccak256(leafBytes))));
+}
+
+function sha256(data: Buffer): Buffer {
+  return createHash("sha256").update(data).digest();
+}